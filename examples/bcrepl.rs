@@ -2,36 +2,32 @@ use ckc_rs::cards::binary_card::{BinaryCard, BC64};
 use ckc_rs::cards::five::Five;
 use ckc_rs::cards::seven::Seven;
 use ckc_rs::cards::two::Two;
-use csv::Reader;
-use fudd::analysis::store::holdem::bcm::{BinaryCardMap, SimpleBinaryCardMap};
+use fudd::analysis::store::holdem::bcm::{BinaryCardMap, BinaryCardMapFile};
 use fudd::types::arrays::five_card::FiveCard;
 use fudd::types::arrays::Vectorable;
 use fudd::types::playing_cards::PlayingCards;
 use fudd::types::poker_cards::PokerCards;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
-use std::fs::File;
 use std::io;
 use std::io::Write;
+use std::path::Path;
 use wincounter::{Win, Wins};
 
+const BCM_PATH: &str = "logs/bcm.bin";
+
 lazy_static! {
-    static ref BC_RANK: HashMap<BinaryCard, SimpleBinaryCardMap> = {
-        let mut m = HashMap::new();
-        let file_path = "logs/bcm.csv";
-        let file = File::open(file_path).unwrap();
-        let mut rdr = Reader::from_reader(file);
-
-        for result in rdr.deserialize() {
-            let bcm: BinaryCardMap = result.unwrap();
-            m.insert(bcm.bc, SimpleBinaryCardMap::from(bcm));
-        }
-        m
-    };
+    static ref BC_RANK: BinaryCardMapFile = BinaryCardMap::open(BCM_PATH).unwrap();
 }
 
 /// cargo run --example bcrepl
+///
+/// The first run generates `logs/bcm.bin`, which takes a while and is around 1.3 GB.
 fn main() {
+    if !Path::new(BCM_PATH).exists() {
+        println!("Generating {}...", BCM_PATH);
+        BinaryCardMap::generate_binary(BCM_PATH).unwrap();
+    }
+
     loop {
         read_input();
     }
@@ -82,12 +78,12 @@ fn grind(hero: Two, villain: Two, remaining: PlayingCards) -> Wins {
         let hero7 = BinaryCard::from_seven(Seven::new(hero, five));
         let villain7 = BinaryCard::from_seven(Seven::new(villain, five));
 
-        let hero_rank = BC_RANK.get(&hero7).unwrap();
-        let villain_rank = BC_RANK.get(&villain7).unwrap();
+        let hero_rank = BC_RANK.lookup(hero7);
+        let villain_rank = BC_RANK.lookup(villain7);
 
-        if hero_rank.rank < villain_rank.rank {
+        if hero_rank < villain_rank {
             wins.add_win(Win::FIRST);
-        } else if villain_rank.rank < hero_rank.rank {
+        } else if villain_rank < hero_rank {
             wins.add_win(Win::SECOND);
         } else {
             wins.add_win(Win::FIRST | Win::SECOND);
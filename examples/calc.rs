@@ -2,6 +2,7 @@ use clap::Parser;
 use fudd::games::holdem::board::Board;
 use fudd::games::holdem::seats::Seats;
 use fudd::games::holdem::table::Table;
+use rayon::ThreadPoolBuilder;
 use std::time::Instant;
 
 extern crate log;
@@ -21,6 +22,10 @@ struct Args {
 
     #[clap(short = 'e', long)]
     eval_deal: bool,
+
+    /// Times the exhaustive odds on a thread pool this size against doing them one at a time.
+    #[clap(short = 't', long)]
+    threads: Option<usize>,
 }
 
 /// `cargo run --example calc -- -d "4♥ 3♥ A♦ J♣ 8♦ 8♣" -b "T♥ J♠ J♥ 3♦"`
@@ -53,29 +58,36 @@ struct Args {
 /// cargo run --example calc -- -d "8♦ 5♦ K♦ J♥ 2♠ 2♥" -b "9♥ 2♦ K♥ 4♥ J♠" HSP S09E05 Tilly/Hultman
 /// cargo run --example calc -- -d "J♥ J♦ A♠ K♦ T♣ 9♣" -b "7♦ K♠ 2♥ 7♣ A♦" HSP S09E05 Liu/Tilly/Menon
 ///
+/// Comparing the parallel odds, from the deal as well as the flop, with the sequential ones:
+/// cargo run --release --example calc -- -d "A♣ 4♠ K♥ 6♥ K♦ T♥" -b "7♠ 3♦ A♠ 4♦" -e -t 8
+///
 fn main() {
     let start = Instant::now();
     env_logger::init();
     let args = Args::parse();
 
     let mut table = Table::default();
-    let seats = Seats::from_index(Box::leak(args.dealt.into_boxed_str()));
-    let board = Board::from_index(Box::leak(args.board.into_boxed_str()));
+    let seats = args.dealt.parse::<Seats>();
+    let board = args.board.parse::<Board>();
 
     match seats {
         Ok(s) => table.players = s,
-        Err(e) => println!("{:?}", e),
+        Err(e) => println!("{}", e),
     }
 
     match board {
         Ok(b) => table.board = b,
-        Err(e) => println!("{:?}", e),
+        Err(e) => println!("{}", e),
     }
 
     if args.eval_deal {
         table.play_out_deal();
     }
 
+    if let Some(threads) = args.threads {
+        compare(&table, threads, args.eval_deal);
+    }
+
     if args.nuts {
         table.play_out_detailed();
     } else {
@@ -90,3 +102,36 @@ fn main() {
     println!("\nCommand:");
     println!("{}", table.format_calc());
 }
+
+fn compare(table: &Table, threads: usize, eval_deal: bool) {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+
+    if eval_deal {
+        let now = Instant::now();
+        table.chances_at_deal();
+        let sequential = now.elapsed();
+        let now = Instant::now();
+        table.chances_at_deal_in(&pool);
+        println!(
+            "Deal: {:?} sequential, {:?} on {} threads",
+            sequential,
+            now.elapsed(),
+            threads
+        );
+    }
+
+    let now = Instant::now();
+    table.chances_at_flop();
+    let sequential = now.elapsed();
+    let now = Instant::now();
+    table.chances_at_flop_in(&pool);
+    println!(
+        "Flop: {:?} sequential, {:?} on {} threads\n",
+        sequential,
+        now.elapsed(),
+        threads
+    );
+}
@@ -0,0 +1,66 @@
+use clap::Parser;
+use fudd::analysis::store::holdem::heads_up_csv::HeadsUpCsv;
+use fudd::games::holdem::heads_up::HeadsUp;
+use std::path::Path;
+
+/// Calculates the preflop odds of every suit isomorphic heads up matchup into a CSV file.
+/// It takes a long time, but can be stopped at any point and will resume from where it left
+/// off the next time it's run.
+///
+/// `cargo run --release --example heads_up_csv`
+///
+/// Once some of it has been written, any matchup can be looked up with:
+///
+/// `cargo run --release --example heads_up_csv -- -c "A♦ A♣ 7♠ 7♥"`
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(short = 'p', long, default_value = HeadsUpCsv::PATH)]
+    path: String,
+
+    #[clap(short = 'c', long)]
+    hands: Option<String>,
+}
+
+fn main() {
+    let now = std::time::Instant::now();
+    let args = Args::parse();
+
+    match args.hands {
+        Some(hands) => lookup(&args.path, &hands),
+        None => generate(&args.path),
+    }
+
+    println!("Elapsed: {:.2?}", now.elapsed());
+}
+
+fn generate(path: &str) {
+    if let Some(parent) = Path::new(path).parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    match HeadsUpCsv::generate(path) {
+        Ok(count) => println!("Added {count} matchups to {path}"),
+        Err(e) => println!("{e}"),
+    }
+}
+
+fn lookup(path: &str, hands: &str) {
+    let hup: HeadsUp = match hands.parse() {
+        Ok(hup) => hup,
+        Err(e) => {
+            println!("{e}");
+            return;
+        }
+    };
+    let store = match HeadsUpCsv::load(path) {
+        Ok(store) => store,
+        Err(e) => {
+            println!("{e}");
+            return;
+        }
+    };
+    match store.get(&hup) {
+        Some(odds) => println!("{odds}"),
+        None => println!("{hup} hasn't been calculated yet"),
+    }
+}
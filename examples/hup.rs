@@ -6,6 +6,7 @@ use simplelog::*;
 use std::fs::File;
 
 use clap::Parser;
+use fudd::types::suit_permutation::SuitIsomorphic;
 
 // "6♠ 6♥ 5♦ 5♣" -b "9♣ 6♦ 5♥ 5♠ 8♠" HSP THE hand Negreanu/Hansen
 // First:  79.73% (1365284) || 79.71% (1364802) // stats from https://tools.timodenk.com/poker-odds-pre-flop
@@ -107,7 +108,7 @@ fn main() {
     let now = std::time::Instant::now();
 
     let args = Args::parse();
-    let hands = Box::leak(args.hands.into_boxed_str());
+    let hands = args.hands;
 
     if args.log {
         let filename = format!("logs/{}.log", hands);
@@ -127,9 +128,20 @@ fn main() {
         .unwrap();
     }
 
-    let hup = fudd::games::holdem::heads_up::HeadsUp::from_index(hands);
+    let hup: fudd::games::holdem::heads_up::HeadsUp = match hands.parse() {
+        Ok(hup) => hup,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
 
     println!("Calculating: {}", hands);
+    println!(
+        "Plays the same as {}, along with {} other matchups",
+        hup.to_canonical(),
+        hup.multiplicity() - 1
+    );
 
     let odds = hup.odds_preflop();
 
@@ -24,9 +24,9 @@ fn main() {
     env_logger::init();
     let args = Args::parse();
 
-    let hero = HoleCards::from_index(Box::leak(args.hero.into_boxed_str()));
-    let villain = HoleCards::from_index(Box::leak(args.villain.into_boxed_str()));
-    // let board = Board::from_index(Box::leak(args.board.into_boxed_str())).unwrap();
+    let hero = HoleCards::from_index(&args.hero);
+    let villain = HoleCards::from_index(&args.villain);
+    // let board = Board::from_index(&args.board).unwrap();
 
     let table = Table::seat(2);
     table.take(PlayingCard::from(hero.get_first_card()));
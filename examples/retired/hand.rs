@@ -1,7 +1,6 @@
 use clap::Parser;
 use fudd::analysis::eval::Eval;
 use fudd::types::arrays::five_card::FiveCard;
-use fudd::util::str_from_string;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -19,8 +18,7 @@ fn main() {
     env_logger::init();
     let args = Args::parse();
 
-    let s = str_from_string(args.hand);
-    let hand = FiveCard::try_from(s);
+    let hand = FiveCard::try_from(args.hand.as_str());
     match hand {
         Ok(h) => println!("{}", Eval::from(h)),
         Err(e) => println!("{:?}", e),
@@ -51,7 +51,7 @@ fn main() {
     env_logger::init();
     let args = Args::parse();
 
-    let index = Box::leak(args.index.into_boxed_str());
+    let index = args.index.as_str();
 
     if index.is_empty() {
         if args.number < 2 {
@@ -0,0 +1,272 @@
+use crate::analysis::low::LowRank;
+use crate::types::arrays::four_card::FourCard;
+use crate::types::U32Card;
+use ckc_rs::PokerCard;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+/// The value of a hand played for [Badugi](https://en.wikipedia.org/wiki/Badugi), which is the
+/// lowest set of cards, aces low, that are all different ranks and all different suits.
+///
+/// A hand with more cards in its badugi beats one with fewer, no matter what they are, so any
+/// four card badugi beats every three card one, and so on down, which means that hands of
+/// different sizes can be compared directly. Between badugis of the same size, the one with
+/// the lowest high card wins, and then the next highest, and so on.
+///
+/// Like `LowRank`, the better hand compares greater, and the default, which represents no hand
+/// at all, is less than every other `BadugiRank`. The key is the number of cards missing from a
+/// four card badugi, followed by four bits for each of the ranks in it from high to low.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BadugiRank(u32);
+
+impl BadugiRank {
+    pub const MAX_CARDS: usize = 4;
+
+    /// Returns the best `BadugiRank` that can be made out of the cards, or the default if
+    /// there aren't any.
+    #[must_use]
+    pub fn from_cards(cards: &[U32Card]) -> BadugiRank {
+        BadugiRank::best_badugi(cards).0
+    }
+
+    /// Returns the number of cards that make up the badugi.
+    #[must_use]
+    pub fn cards(&self) -> usize {
+        self.ranks().len()
+    }
+
+    /// Returns true if it's a full four card badugi.
+    #[must_use]
+    pub fn is_badugi(&self) -> bool {
+        self.cards() == BadugiRank::MAX_CARDS
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    //region private functions
+
+    /// Returns the best rank, along with the cards that make it, trying the largest
+    /// combinations first, since any badugi with more cards beats every one with fewer.
+    fn best_badugi(cards: &[U32Card]) -> (BadugiRank, Vec<U32Card>) {
+        let cards: Vec<U32Card> = cards
+            .iter()
+            .copied()
+            .filter(|card| !card.is_blank())
+            .collect();
+        for size in (1..=BadugiRank::MAX_CARDS.min(cards.len())).rev() {
+            let best = cards
+                .iter()
+                .copied()
+                .combinations(size)
+                .filter(|combo| BadugiRank::is_rainbow(combo))
+                .map(|combo| (BadugiRank::from_rainbow(&combo), combo))
+                .max_by_key(|(rank, _)| *rank);
+            if let Some(best) = best {
+                return best;
+            }
+        }
+        (BadugiRank::default(), Vec::new())
+    }
+
+    /// True if none of the cards share a rank or a suit.
+    fn is_rainbow(cards: &[U32Card]) -> bool {
+        cards.iter().map(|card| card & 0xF000).all_unique()
+            && cards.iter().map(|card| (card >> 8) & 0xF).all_unique()
+    }
+
+    fn from_rainbow(cards: &[U32Card]) -> BadugiRank {
+        let mut key = u32::try_from(BadugiRank::MAX_CARDS - cards.len()).unwrap_or_default();
+        let ranks = cards
+            .iter()
+            .map(|card| LowRank::ace_low_rank(*card))
+            .sorted_by_key(|rank| Reverse(*rank))
+            .collect::<Vec<u32>>();
+        for i in 0..BadugiRank::MAX_CARDS {
+            key = (key << 4) | ranks.get(i).copied().unwrap_or_default();
+        }
+        BadugiRank(key)
+    }
+
+    fn ranks(self) -> Vec<u32> {
+        (0..BadugiRank::MAX_CARDS)
+            .rev()
+            .map(|i| (self.0 >> (i * 4)) & 0xF)
+            .filter(|rank| *rank > 0)
+            .collect()
+    }
+
+    //endregion
+}
+
+impl fmt::Display for BadugiRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_none() {
+            return write!(f, "No Hand");
+        }
+        let ranks = self
+            .ranks()
+            .iter()
+            .map(|rank| match rank {
+                1 => 'A',
+                10 => 'T',
+                11 => 'J',
+                12 => 'Q',
+                13 => 'K',
+                _ => char::from_digit(*rank, 10).unwrap_or('_'),
+            })
+            .join("-");
+        write!(f, "{ranks}")
+    }
+}
+
+impl PartialOrd<Self> for BadugiRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The lower the key the better the hand, unless there isn't one.
+impl Ord for BadugiRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_none(), other.is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => other.0.cmp(&self.0),
+        }
+    }
+}
+
+/// `BadugiEval` pairs a `FourCard` hand with its `BadugiRank`, sorted with the cards that
+/// make up the badugi first, from high to low, followed by any that don't play.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BadugiEval {
+    pub rank: BadugiRank,
+    pub hand: FourCard,
+}
+
+impl BadugiEval {
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.rank.is_none()
+    }
+}
+
+impl fmt::Display for BadugiEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hand, self.rank)
+    }
+}
+
+impl From<FourCard> for BadugiEval {
+    fn from(four_cards: FourCard) -> Self {
+        let (rank, mut playing) = BadugiRank::best_badugi(&four_cards.to_arr());
+        playing.sort_by_key(|card| Reverse(LowRank::ace_low_rank(*card)));
+        let mut hand = four_cards.to_arr();
+        for (i, card) in playing
+            .iter()
+            .chain(hand.iter().filter(|card| !playing.contains(card)))
+            .copied()
+            .collect::<Vec<U32Card>>()
+            .into_iter()
+            .enumerate()
+        {
+            hand[i] = card;
+        }
+        BadugiEval {
+            rank,
+            hand: FourCard::from(hand),
+        }
+    }
+}
+
+impl PartialOrd<Self> for BadugiEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BadugiEval {
+    fn cmp(&self, other: &BadugiEval) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_badugi_tests {
+    use super::*;
+    use crate::types::arrays::Vectorable;
+    use crate::types::poker_cards::PokerCards;
+    use rstest::rstest;
+
+    fn badugi(index: &'static str) -> BadugiEval {
+        BadugiEval::from(FourCard::try_from(index).unwrap())
+    }
+
+    #[rstest]
+    #[case("4♠ 3♥ 2♦ A♣", "4-3-2-A", 4)]
+    #[case("K♠ Q♥ J♦ T♣", "K-Q-J-T", 4)]
+    #[case("4♠ 3♠ 2♦ A♣", "3-2-A", 3)]
+    #[case("A♠ A♥ 2♦ 3♣", "3-2-A", 3)]
+    #[case("A♠ 2♠ 3♥ 3♦", "3-A", 2)]
+    #[case("A♠ K♠ Q♠ J♠", "A", 1)]
+    fn display(#[case] index: &'static str, #[case] expected: &str, #[case] cards: usize) {
+        let eval = badugi(index);
+
+        assert_eq!(expected, eval.rank.to_string());
+        assert_eq!(cards, eval.rank.cards());
+    }
+
+    #[test]
+    fn display__no_hand() {
+        assert_eq!("No Hand", BadugiRank::default().to_string());
+    }
+
+    #[test]
+    fn from__sorts_hand() {
+        assert_eq!("4♠ 3♥ 2♦ A♣ 4-3-2-A", badugi("A♣ 3♥ 4♠ 2♦").to_string());
+        assert_eq!("3♣ 2♦ A♥ A♠ 3-2-A", badugi("A♠ A♥ 2♦ 3♣").to_string());
+    }
+
+    #[test]
+    fn is_badugi() {
+        assert!(badugi("K♠ Q♥ J♦ T♣").rank.is_badugi());
+        assert!(!badugi("4♠ 3♠ 2♦ A♣").rank.is_badugi());
+        assert!(!BadugiRank::default().is_badugi());
+    }
+
+    #[rstest]
+    #[case("4♠ 3♥ 2♦ A♣", "5♠ 3♥ 2♦ A♣")]
+    #[case("5♠ 3♥ 2♦ A♣", "5♠ 4♥ 2♦ A♣")]
+    #[case("K♠ Q♥ J♦ T♣", "3♠ 2♠ A♥ 4♦")]
+    #[case("4♠ 3♠ 2♦ A♣", "5♠ 4♠ 3♦ 2♣")]
+    #[case("K♠ Q♥ J♦ J♣", "A♠ 2♠ 3♥ 3♦")]
+    #[case("A♠ 2♠ 3♥ 3♦", "A♠ 2♠ 3♠ 4♠")]
+    fn ordering(#[case] better: &'static str, #[case] worse: &'static str) {
+        assert!(badugi(better) > badugi(worse));
+    }
+
+    #[test]
+    fn ordering__ties() {
+        assert_eq!(
+            Ordering::Equal,
+            badugi("4♠ 3♥ 2♦ A♣").cmp(&badugi("4♥ 3♠ 2♣ A♦"))
+        );
+        assert!(badugi("A♠ K♠ Q♠ J♠") > BadugiEval::default());
+    }
+
+    #[test]
+    fn from_cards() {
+        let cards = PokerCards::try_from("K♠ 2♥ 7♦ 2♣ A♠ 4♦").unwrap().to_vec();
+
+        assert_eq!("4-2-A", BadugiRank::from_cards(&cards).to_string());
+        assert_eq!("K", BadugiRank::from_cards(&cards[..1]).to_string());
+        assert!(BadugiRank::from_cards(&[]).is_none());
+    }
+}
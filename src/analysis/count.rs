@@ -1,21 +1,21 @@
-use std::cell::Cell;
 use std::cmp::Ordering;
 use std::fmt;
+use std::sync::atomic::{self, AtomicUsize};
 
-/// `Count` compromises a single `Cell` holding an unsigned integer that can be
-/// easily incremented and decremented. Used to count specific wins by a player
-/// so that odds can be calculated.
-#[derive(Clone, Debug)]
-pub struct Count(Cell<usize>);
+/// `Count` compromises a single `AtomicUsize` holding an unsigned integer that can be
+/// easily incremented and decremented, even from more than one thread at a time. Used to
+/// count specific wins by a player so that odds can be calculated.
+#[derive(Debug)]
+pub struct Count(AtomicUsize);
 
 impl Count {
     #[must_use]
     pub fn new() -> Count {
-        Count(Cell::new(0))
+        Count(AtomicUsize::new(0))
     }
 
     pub fn get(&self) -> usize {
-        self.0.get()
+        self.0.load(atomic::Ordering::SeqCst)
     }
 
     pub fn increment(&self) -> usize {
@@ -26,19 +26,27 @@ impl Count {
         self.minus(1)
     }
 
-    #[allow(clippy::cast_possible_wrap)]
+    /// Takes the number away from the count, stopping at zero.
     pub fn minus(&self, number: usize) -> usize {
-        if (self.get() as isize - number as isize) < 1 {
-            self.0.set(0);
-        } else {
-            self.0.set(self.0.get() - number);
-        }
-        self.0.get()
+        let before = self
+            .0
+            .fetch_update(
+                atomic::Ordering::SeqCst,
+                atomic::Ordering::SeqCst,
+                |count| Some(count.saturating_sub(number)),
+            )
+            .unwrap_or_default();
+        before.saturating_sub(number)
     }
 
     pub fn plus(&self, add: usize) -> usize {
-        self.0.set(self.0.get() + add);
-        self.0.get()
+        self.0.fetch_add(add, atomic::Ordering::SeqCst) + add
+    }
+}
+
+impl Clone for Count {
+    fn clone(&self) -> Self {
+        Count(AtomicUsize::new(self.get()))
     }
 }
 
@@ -50,7 +58,7 @@ impl Default for Count {
 
 impl fmt::Display for Count {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0.get())
+        write!(f, "{}", self.get())
     }
 }
 
@@ -0,0 +1,312 @@
+use crate::types::arrays::five_card::FiveCard;
+use crate::types::U32Card;
+use ckc_rs::hand_rank::HandRankName;
+use ckc_rs::PokerCard;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+/// The value of a five card hand played for
+/// [deuce-to-seven low](https://en.wikipedia.org/wiki/Lowball_(poker)#Deuce-to-seven_low),
+/// also known as Kansas City lowball, where aces are always high, and straights and flushes
+/// count against the hand, so that the worst high hand is the best low.
+///
+/// Like `LowRank`, the better hand compares greater, and the default, which represents no hand
+/// at all, is less than every other `DeuceToSevenRank`. The key is made the same way as well:
+/// the `HandRankName` of the hand in the top bits, where a high card is the best, followed by
+/// four bits for each distinct rank, ordered by how many there are of it and then from high to
+/// low, with the deuce at 2 and the ace at 14.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DeuceToSevenRank(u32);
+
+impl DeuceToSevenRank {
+    /// Returns the `DeuceToSevenRank` of the five cards, or the default if any of them are blank.
+    #[must_use]
+    pub fn from_five(cards: &[U32Card; 5]) -> DeuceToSevenRank {
+        if cards.iter().any(PokerCard::is_blank) {
+            return DeuceToSevenRank::default();
+        }
+        let mut counts = [0_u8; 15];
+        for card in cards {
+            counts[DeuceToSevenRank::ace_high_rank(*card) as usize] += 1;
+        }
+        let groups: Vec<(u8, u32)> = (2..15_u32)
+            .filter(|rank| counts[*rank as usize] > 0)
+            .map(|rank| (counts[rank as usize], rank))
+            .sorted_by_key(|group| Reverse(*group))
+            .collect();
+        let is_flush = cards.iter().fold(0xF000, |suits, card| suits & card) != 0;
+        let is_straight = groups.len() == 5 && groups[0].1 - groups[4].1 == 4;
+        let category = match groups.iter().map(|group| group.0).collect::<Vec<u8>>()[..] {
+            [1, 1, 1, 1, 1] => match (is_straight, is_flush) {
+                (true, true) => 8,
+                (false, true) => 5,
+                (true, false) => 4,
+                (false, false) => 0,
+            },
+            [2, 1, 1, 1] => 1,
+            [2, 2, 1] => 2,
+            [3, 1, 1] => 3,
+            [3, 2] => 6,
+            _ => 7,
+        };
+        let mut key = category;
+        for i in 0..5 {
+            key = (key << 4) | groups.get(i).map_or(0, |group| group.1);
+        }
+        DeuceToSevenRank(key)
+    }
+
+    /// The value of the card when aces are high, from 2 for a deuce up to 14 for an ace.
+    #[must_use]
+    pub fn ace_high_rank(card: U32Card) -> u32 {
+        ((card >> 8) & 0xF) + 2
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the `HandRankName` of the hand when it's played for high, except for `A-5-4-3-2`,
+    /// which is just ace high, since aces don't play low in deuce-to-seven.
+    #[must_use]
+    pub fn name(&self) -> HandRankName {
+        if self.is_none() {
+            return HandRankName::Invalid;
+        }
+        match self.category() {
+            0 => HandRankName::HighCard,
+            1 => HandRankName::Pair,
+            2 => HandRankName::TwoPair,
+            3 => HandRankName::ThreeOfAKind,
+            4 => HandRankName::Straight,
+            5 => HandRankName::Flush,
+            6 => HandRankName::FullHouse,
+            7 => HandRankName::FourOfAKind,
+            _ => HandRankName::StraightFlush,
+        }
+    }
+
+    fn category(self) -> u32 {
+        self.0 >> 20
+    }
+
+    fn ranks(self) -> Vec<u32> {
+        (0..5)
+            .rev()
+            .map(|i| (self.0 >> (i * 4)) & 0xF)
+            .filter(|rank| *rank > 0)
+            .collect()
+    }
+}
+
+impl fmt::Display for DeuceToSevenRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.name() {
+            HandRankName::Invalid => return write!(f, "No Hand"),
+            HandRankName::HighCard => "",
+            HandRankName::Pair => "Pair ",
+            HandRankName::TwoPair => "Two Pair ",
+            HandRankName::ThreeOfAKind => "Three of a Kind ",
+            HandRankName::Straight => "Straight ",
+            HandRankName::Flush => "Flush ",
+            HandRankName::FullHouse => "Full House ",
+            HandRankName::FourOfAKind => "Four of a Kind ",
+            HandRankName::StraightFlush => "Straight Flush ",
+        };
+        let ranks = self
+            .ranks()
+            .iter()
+            .map(|rank| match rank {
+                10 => 'T',
+                11 => 'J',
+                12 => 'Q',
+                13 => 'K',
+                14 => 'A',
+                _ => char::from_digit(*rank, 10).unwrap_or('_'),
+            })
+            .join("-");
+        write!(f, "{name}{ranks}")
+    }
+}
+
+impl PartialOrd<Self> for DeuceToSevenRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The lower the key the better the low, unless there isn't one.
+impl Ord for DeuceToSevenRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_none(), other.is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => other.0.cmp(&self.0),
+        }
+    }
+}
+
+/// `DeuceToSevenEval` is the deuce-to-seven counterpart of `LowEval`, pairing `FiveCards`
+/// with their `DeuceToSevenRank`, sorted the same way the rank reads.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DeuceToSevenEval {
+    pub rank: DeuceToSevenRank,
+    pub hand: FiveCard,
+}
+
+impl DeuceToSevenEval {
+    /// Returns the best deuce-to-seven low out of every five card combination of the cards, or
+    /// the default `DeuceToSevenEval` if there are less than five of them.
+    #[must_use]
+    pub fn best(cards: &[U32Card]) -> DeuceToSevenEval {
+        cards
+            .iter()
+            .combinations(5)
+            .map(|five| DeuceToSevenEval::from([*five[0], *five[1], *five[2], *five[3], *five[4]]))
+            .max()
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.rank.is_none()
+    }
+}
+
+impl fmt::Display for DeuceToSevenEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hand, self.rank)
+    }
+}
+
+impl From<[U32Card; 5]> for DeuceToSevenEval {
+    fn from(array: [U32Card; 5]) -> Self {
+        let rank = DeuceToSevenRank::from_five(&array);
+        let mut hand = array;
+        let counts = hand.map(|card| {
+            array
+                .iter()
+                .filter(|other| {
+                    DeuceToSevenRank::ace_high_rank(**other)
+                        == DeuceToSevenRank::ace_high_rank(card)
+                })
+                .count()
+        });
+        let mut sorted: Vec<(usize, U32Card)> = counts.into_iter().zip(hand).collect();
+        sorted
+            .sort_by_key(|(count, card)| Reverse((*count, DeuceToSevenRank::ace_high_rank(*card))));
+        for (i, (_, card)) in sorted.into_iter().enumerate() {
+            hand[i] = card;
+        }
+        DeuceToSevenEval {
+            rank,
+            hand: FiveCard::from(hand),
+        }
+    }
+}
+
+impl From<FiveCard> for DeuceToSevenEval {
+    fn from(five_cards: FiveCard) -> Self {
+        DeuceToSevenEval::from(five_cards.to_arr())
+    }
+}
+
+impl PartialOrd<Self> for DeuceToSevenEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DeuceToSevenEval {
+    fn cmp(&self, other: &DeuceToSevenEval) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_deuce_to_seven_tests {
+    use super::*;
+    use crate::types::arrays::Evaluable;
+    use crate::types::arrays::Vectorable;
+    use crate::types::poker_cards::PokerCards;
+    use rstest::rstest;
+
+    fn low(index: &'static str) -> DeuceToSevenEval {
+        DeuceToSevenEval::from(FiveCard::try_from(index).unwrap())
+    }
+
+    #[rstest]
+    #[case("7♠ 5♥ 4♦ 3♣ 2♠", "7-5-4-3-2")]
+    #[case("2♠ 3♥ 4♦ 5♣ A♠", "A-5-4-3-2")]
+    #[case("K♠ Q♥ J♦ T♣ 8♠", "K-Q-J-T-8")]
+    #[case("7♠ 7♥ 4♦ 3♣ 2♠", "Pair 7-4-3-2")]
+    #[case("6♠ 5♥ 4♦ 3♣ 2♠", "Straight 6-5-4-3-2")]
+    #[case("7♠ 5♠ 4♠ 3♠ 2♠", "Flush 7-5-4-3-2")]
+    #[case("7♠ 6♠ 5♠ 4♠ 3♠", "Straight Flush 7-6-5-4-3")]
+    fn display(#[case] index: &'static str, #[case] expected: &str) {
+        assert_eq!(expected, low(index).rank.to_string());
+    }
+
+    #[test]
+    fn display__no_hand() {
+        assert_eq!("No Hand", DeuceToSevenRank::default().to_string());
+    }
+
+    #[test]
+    fn from__sorts_hand() {
+        assert_eq!("7♠ 5♥ 4♦ 3♣ 2♠", low("2♠ 5♥ 7♠ 3♣ 4♦").hand.to_string());
+        assert_eq!("7♥ 7♠ 4♦ 3♣ 2♠", low("2♠ 7♥ 3♣ 4♦ 7♠").hand.to_string());
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(HandRankName::HighCard, low("2♠ 3♥ 4♦ 5♣ A♠").rank.name());
+        assert_eq!(HandRankName::Flush, low("2♠ 3♠ 4♠ 5♠ A♠").rank.name());
+        assert_eq!(HandRankName::Invalid, DeuceToSevenRank::default().name());
+    }
+
+    /// Apart from the ace to five straight, which plays as ace high, the order is the
+    /// reverse of the `HandRank` for the hand.
+    #[rstest]
+    #[case("7♠ 5♥ 4♦ 3♣ 2♠", "8♠ 5♥ 4♦ 3♣ 2♠")]
+    #[case("8♠ 5♥ 4♦ 3♣ 2♠", "8♠ 6♥ 4♦ 3♣ 2♠")]
+    #[case("K♠ Q♥ J♦ T♣ 8♠", "A♠ 5♥ 4♦ 3♣ 2♠")]
+    #[case("A♠ K♥ Q♦ J♣ 9♠", "2♠ 2♥ 4♦ 3♣ 5♠")]
+    #[case("A♠ A♥ K♦ Q♣ J♠", "2♠ 2♥ 3♦ 3♣ 4♠")]
+    #[case("A♠ A♥ A♦ K♣ Q♠", "6♠ 5♥ 4♦ 3♣ 2♠")]
+    #[case("A♠ K♥ Q♦ J♣ T♠", "7♠ 5♠ 4♠ 3♠ 2♠")]
+    #[case("A♠ K♠ Q♠ J♠ 9♠", "2♠ 2♥ 2♦ 3♣ 3♠")]
+    fn ordering(#[case] better: &'static str, #[case] worse: &'static str) {
+        assert!(low(better) > low(worse));
+        if !worse.starts_with('A') {
+            assert!(
+                FiveCard::try_from(better).unwrap().hand_rank()
+                    < FiveCard::try_from(worse).unwrap().hand_rank()
+            );
+        }
+    }
+
+    #[test]
+    fn ordering__ties() {
+        assert_eq!(
+            Ordering::Equal,
+            low("7♠ 5♥ 4♦ 3♣ 2♠").cmp(&low("7♥ 5♦ 4♣ 3♠ 2♥"))
+        );
+        assert!(low("7♠ 5♥ 4♦ 3♣ 2♠") > DeuceToSevenEval::default());
+    }
+
+    #[test]
+    fn best() {
+        let cards = PokerCards::try_from("A♠ 7♥ 5♦ 4♣ 3♠ 2♦ 2♣")
+            .unwrap()
+            .to_vec();
+
+        assert_eq!("7-5-4-3-2", DeuceToSevenEval::best(&cards).rank.to_string());
+        assert!(DeuceToSevenEval::best(&cards[..4]).is_none());
+    }
+}
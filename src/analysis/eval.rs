@@ -194,10 +194,10 @@ impl TryFrom<&PokerCards> for Eval {
     }
 }
 
-impl TryFrom<&'static str> for Eval {
+impl TryFrom<&str> for Eval {
     type Error = HandError;
 
-    fn try_from(index: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(index: &str) -> Result<Self, Self::Error> {
         match FiveCard::try_from(index) {
             Ok(five_cards) => Ok(Eval::from(five_cards)),
             Err(e) => Err(e),
@@ -0,0 +1,311 @@
+use crate::analysis::Evaluate;
+use crate::types::arrays::five_card::FiveCard;
+use crate::types::U32Card;
+use ckc_rs::hand_rank::{HandRank, HandRankValue};
+use ckc_rs::PokerCard;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use log::warn;
+use std::error::Error;
+use std::fs;
+
+lazy_static! {
+    /// The shared `Lookup7Card` table, generated in memory the first time it's used.
+    pub static ref LOOKUP_7CARD: Lookup7Card = Lookup7Card::generate();
+}
+
+/// Table driven evaluator for seven card hands, returning the same `HandRank` as evaluating
+/// all 21 five card combinations through `ckc_rs`, but with a single lookup.
+///
+/// With seven cards there can't be both a flush and a full house or better, so if five or more
+/// of the cards share a suit, the hand's value depends only on the ranks of those suited cards,
+/// and is looked up by their 13 bit rank mask in `flushes`. Otherwise, suits don't matter, and the
+/// value is looked up in `ranks` by a perfect hash of how many cards there are of each rank.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lookup7Card {
+    flushes: Vec<HandRankValue>,
+    ranks: Vec<HandRankValue>,
+}
+
+impl Lookup7Card {
+    /// Number of distinct ways seven cards can be spread across the 13 ranks.
+    pub const RANK_COMBINATIONS: usize = 49_205;
+    pub const FLUSH_MASKS: usize = 1 << 13;
+
+    const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+    const SUITS: [u32; 4] = [0x1000, 0x2000, 0x4000, 0x8000];
+
+    /// Builds the tables from scratch by brute forcing every rank combination and flush.
+    #[must_use]
+    pub fn generate() -> Lookup7Card {
+        let mut flushes = vec![0; Lookup7Card::FLUSH_MASKS];
+        for (mask, value) in flushes.iter_mut().enumerate() {
+            if (5..=7).contains(&mask.count_ones()) {
+                let cards: Vec<U32Card> = (0..13)
+                    .filter(|rank| mask & (1 << rank) != 0)
+                    .map(|rank| Lookup7Card::card(rank, 0))
+                    .collect();
+                *value = Lookup7Card::brute_force(&cards);
+            }
+        }
+
+        let mut ranks = vec![0; Lookup7Card::RANK_COMBINATIONS];
+        let mut counts = [0_u8; 13];
+        Lookup7Card::generate_ranks(&mut ranks, &mut counts, 0, 7);
+
+        Lookup7Card { flushes, ranks }
+    }
+
+    /// Reads the tables from the passed in path, or generates them and tries to write them
+    /// there if they can't be read. Nothing is ever written to disk unless this is called.
+    #[must_use]
+    pub fn cached(path: &str) -> Lookup7Card {
+        if let Ok(lookup) = Lookup7Card::read(path) {
+            return lookup;
+        }
+        let lookup = Lookup7Card::generate();
+        if let Err(e) = lookup.write(path) {
+            warn!("Unable to cache seven card lookup table at {path}: {e}");
+        }
+        lookup
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or is the wrong size.
+    pub fn read(path: &str) -> Result<Lookup7Card, Box<dyn Error>> {
+        let bytes = fs::read(path)?;
+        if bytes.len() != (Lookup7Card::FLUSH_MASKS + Lookup7Card::RANK_COMBINATIONS) * 2 {
+            return Err(format!("{path} isn't a seven card lookup table").into());
+        }
+        let mut values = bytes
+            .chunks_exact(2)
+            .map(|b| HandRankValue::from_le_bytes([b[0], b[1]]));
+        Ok(Lookup7Card {
+            flushes: values.by_ref().take(Lookup7Card::FLUSH_MASKS).collect(),
+            ranks: values.collect(),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn write(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let bytes: Vec<u8> = self
+            .flushes
+            .iter()
+            .chain(self.ranks.iter())
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Returns the `HandRank` of the best five cards out of the seven, or `None` if any of
+    /// them are blank or repeated.
+    #[must_use]
+    pub fn hand_rank(&self, cards: &[U32Card; 7]) -> Option<HandRank> {
+        let mut seen: u64 = 0;
+        let mut counts = [0_u8; 13];
+        let mut suits = [0_u32; 4];
+
+        for card in cards {
+            if card.is_blank() {
+                return None;
+            }
+            let rank = ((card >> 8) & 0xF) as usize;
+            let suit = (card.get_suit_flag() >> 12).trailing_zeros() as usize;
+            if rank > 12 || suit > 3 {
+                return None;
+            }
+            seen |= 1 << (rank * 4 + suit);
+            counts[rank] += 1;
+            suits[suit] |= 1 << rank;
+        }
+        if seen.count_ones() != 7 {
+            return None;
+        }
+
+        let value = match suits.iter().find(|mask| mask.count_ones() >= 5) {
+            Some(mask) => self.flushes[*mask as usize],
+            None => self.ranks[Lookup7Card::rank_index(&counts)],
+        };
+        Some(HandRank::from(value))
+    }
+
+    //region private functions
+
+    /// Builds the `U32Card` for the rank, deuce being 0, and suit, clubs being 0.
+    #[allow(clippy::cast_possible_truncation)]
+    fn card(rank: usize, suit: usize) -> U32Card {
+        (1 << (16 + rank))
+            | Lookup7Card::SUITS[suit]
+            | ((rank as u32) << 8)
+            | Lookup7Card::PRIMES[rank]
+    }
+
+    /// Best `HandRankValue` out of every five card combination of the cards.
+    fn brute_force(cards: &[U32Card]) -> HandRankValue {
+        cards
+            .iter()
+            .combinations(5)
+            .map(|five| {
+                Evaluate::five_cards(FiveCard::from([
+                    *five[0], *five[1], *five[2], *five[3], *five[4],
+                ]))
+            })
+            .max()
+            .unwrap_or_default()
+            .value
+    }
+
+    /// Walks every way of spreading the remaining cards over the ranks from `rank` up,
+    /// dealing the cards out across the suits so that none of them make a flush.
+    fn generate_ranks(
+        ranks: &mut [HandRankValue],
+        counts: &mut [u8; 13],
+        rank: usize,
+        remaining: u8,
+    ) {
+        if rank == 13 {
+            if remaining == 0 {
+                let mut cards: Vec<U32Card> = Vec::new();
+                for (r, count) in counts.iter().enumerate() {
+                    for _ in 0..*count {
+                        cards.push(Lookup7Card::card(r, cards.len() % 4));
+                    }
+                }
+                ranks[Lookup7Card::rank_index(counts)] = Lookup7Card::brute_force(&cards);
+            }
+            return;
+        }
+        for count in 0..=remaining.min(4) {
+            counts[rank] = count;
+            Lookup7Card::generate_ranks(ranks, counts, rank + 1, remaining - count);
+        }
+        counts[rank] = 0;
+    }
+
+    /// Perfect hash of the per rank card counts, numbering every combination in the
+    /// order that `generate_ranks` walks them, from 0 to `RANK_COMBINATIONS - 1`.
+    fn rank_index(counts: &[u8; 13]) -> usize {
+        let mut index = 0;
+        let mut remaining = 7;
+        for (rank, count) in counts.iter().enumerate() {
+            for skipped in 0..*count {
+                index += RANK_WAYS[rank + 1][(remaining - skipped) as usize];
+            }
+            remaining -= count;
+        }
+        index
+    }
+
+    //endregion
+}
+
+lazy_static! {
+    /// `RANK_WAYS[rank][n]` is the number of ways to spread n cards across the ranks from
+    /// `rank` up, with no more than four of any one rank.
+    static ref RANK_WAYS: [[usize; 8]; 14] = {
+        let mut ways = [[0; 8]; 14];
+        ways[13][0] = 1;
+        for rank in (0..13).rev() {
+            for n in 0..8 {
+                ways[rank][n] = (0..=n.min(4)).map(|count| ways[rank + 1][n - count]).sum();
+            }
+        }
+        ways
+    };
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_lookup_7card_tests {
+    use super::*;
+    use crate::types::arrays::seven_card::SevenCard;
+    use crate::types::arrays::Evaluable;
+    use crate::types::playing_cards::PlayingCards;
+    use crate::types::poker_deck::PokerDeck;
+    use ckc_rs::CardNumber;
+    use rstest::rstest;
+
+    #[test]
+    fn rank_ways() {
+        assert_eq!(Lookup7Card::RANK_COMBINATIONS, RANK_WAYS[0][7]);
+    }
+
+    #[test]
+    fn card() {
+        for card in PokerDeck::iter() {
+            let rank = ((card >> 8) & 0xF) as usize;
+            let suit = (card.get_suit_flag() >> 12).trailing_zeros() as usize;
+            assert_eq!(*card, Lookup7Card::card(rank, suit));
+        }
+    }
+
+    #[rstest]
+    #[case("A♠ K♠ Q♠ J♠ T♠ 2♥ 3♦")]
+    #[case("A♠ A♥ A♦ A♣ K♠ K♥ 2♦")]
+    #[case("A♠ A♥ A♦ K♣ K♠ K♥ 2♦")]
+    #[case("A♠ 9♠ 7♠ 5♠ 3♠ 2♠ 4♥")]
+    #[case("6♠ 5♥ 4♦ 3♣ 2♠ A♥ K♦")]
+    #[case("9♠ 8♥ 7♦ 6♣ 5♠ 4♥ 3♦")]
+    #[case("A♠ A♥ K♦ K♣ Q♠ Q♥ 2♦")]
+    #[case("J♠ 9♥ 7♦ 5♣ 4♠ 3♥ 2♦")]
+    fn hand_rank(#[case] index: &'static str) {
+        let seven = SevenCard::try_from(index).unwrap();
+
+        assert_eq!(
+            Some(seven.evals().best().rank),
+            LOOKUP_7CARD.hand_rank(&seven.to_arr())
+        );
+    }
+
+    #[test]
+    fn hand_rank__random_deals() {
+        for _ in 0..2_000 {
+            let seven = SevenCard::try_from(&PlayingCards::deck_shuffled().draw(7)).unwrap();
+
+            assert_eq!(
+                Some(seven.evals().best().rank),
+                LOOKUP_7CARD.hand_rank(&seven.to_arr())
+            );
+        }
+    }
+
+    #[test]
+    fn hand_rank__invalid() {
+        let blank = SevenCard::from([CardNumber::BLANK; 7]);
+        let duplicate = SevenCard::try_from("A♠ A♠ K♦ K♣ Q♠ Q♥ 2♦").unwrap();
+
+        assert!(LOOKUP_7CARD.hand_rank(&blank.to_arr()).is_none());
+        assert!(LOOKUP_7CARD.hand_rank(&duplicate.to_arr()).is_none());
+    }
+
+    #[test]
+    fn read_and_write() {
+        let path = std::env::temp_dir().join("fudd_lookup_7card_read_and_write.bin");
+        let path = path.to_str().unwrap();
+        LOOKUP_7CARD.write(path).unwrap();
+
+        assert_eq!(*LOOKUP_7CARD, Lookup7Card::read(path).unwrap());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn cached() {
+        let path = std::env::temp_dir().join("fudd_lookup_7card_cached.bin");
+        let path = path.to_str().unwrap();
+        let _ = fs::remove_file(path);
+
+        assert_eq!(*LOOKUP_7CARD, Lookup7Card::cached(path));
+        assert_eq!(*LOOKUP_7CARD, Lookup7Card::read(path).unwrap());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn read__invalid() {
+        assert!(Lookup7Card::read("data/hu_nash_equilibrium/holdemresources_hu_push.csv").is_err());
+        assert!(Lookup7Card::read("data/missing.bin").is_err());
+    }
+}
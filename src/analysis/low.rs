@@ -0,0 +1,310 @@
+use crate::types::arrays::five_card::FiveCard;
+use crate::types::U32Card;
+use ckc_rs::PokerCard;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+/// The value of a five card hand played for
+/// [ace-to-five low](https://en.wikipedia.org/wiki/Lowball_(poker)#Ace-to-five_low), where
+/// aces are low, and straights and flushes don't count against the hand.
+///
+/// Like `HandRank`, the better hand compares greater, and the default, which represents no low
+/// at all, is less than every other `LowRank`. Under the hood the rank is a key where the lower
+/// the number the better the low: the pattern of pairs in the top bits, followed by four bits
+/// for each distinct rank, ordered by how many there are of it and then from high to low.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LowRank(u32);
+
+impl LowRank {
+    pub const EIGHT: u32 = 8;
+
+    /// Returns the `LowRank` of the five cards, or the default if any of them are blank.
+    #[must_use]
+    pub fn from_five(cards: &[U32Card; 5]) -> LowRank {
+        if cards.iter().any(PokerCard::is_blank) {
+            return LowRank::default();
+        }
+        let mut counts = [0_u8; 14];
+        for card in cards {
+            counts[LowRank::ace_low_rank(*card) as usize] += 1;
+        }
+        let groups: Vec<(u8, u32)> = (1..14_u32)
+            .filter(|rank| counts[*rank as usize] > 0)
+            .map(|rank| (counts[rank as usize], rank))
+            .sorted_by_key(|group| Reverse(*group))
+            .collect();
+        let pattern = match groups.iter().map(|group| group.0).collect::<Vec<u8>>()[..] {
+            [1, 1, 1, 1, 1] => 0,
+            [2, 1, 1, 1] => 1,
+            [2, 2, 1] => 2,
+            [3, 1, 1] => 3,
+            [3, 2] => 4,
+            _ => 5,
+        };
+        let mut key = pattern;
+        for i in 0..5 {
+            key = (key << 4) | groups.get(i).map_or(0, |group| group.1);
+        }
+        LowRank(key)
+    }
+
+    /// The value of the card when aces are low, from 1 for an ace up to 13 for a king.
+    #[must_use]
+    pub fn ace_low_rank(card: U32Card) -> u32 {
+        (((card >> 8) & 0xF) + 1) % 13 + 1
+    }
+
+    /// Returns true if the hand has five different ranks, none of them higher than an eight.
+    #[must_use]
+    pub fn is_eight_or_better(&self) -> bool {
+        self.is_qualified(LowRank::EIGHT)
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the hand has five different ranks, none of them higher than `high`.
+    #[must_use]
+    pub fn is_qualified(&self, high: u32) -> bool {
+        !self.is_none() && self.pattern() == 0 && self.ranks()[0] <= high
+    }
+
+    /// Returns the `LowRank` if it's eight or better, and no low otherwise.
+    #[must_use]
+    pub fn eight_or_better(self) -> LowRank {
+        if self.is_eight_or_better() {
+            self
+        } else {
+            LowRank::default()
+        }
+    }
+
+    fn pattern(self) -> u32 {
+        self.0 >> 20
+    }
+
+    fn ranks(self) -> Vec<u32> {
+        (0..5)
+            .rev()
+            .map(|i| (self.0 >> (i * 4)) & 0xF)
+            .filter(|rank| *rank > 0)
+            .collect()
+    }
+}
+
+impl fmt::Display for LowRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_none() {
+            return write!(f, "No Low");
+        }
+        let ranks = self
+            .ranks()
+            .iter()
+            .map(|rank| match rank {
+                1 => 'A',
+                10 => 'T',
+                11 => 'J',
+                12 => 'Q',
+                13 => 'K',
+                _ => char::from_digit(*rank, 10).unwrap_or('_'),
+            })
+            .join("-");
+        write!(f, "{ranks}")
+    }
+}
+
+impl PartialOrd<Self> for LowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The lower the key the better the low, unless there isn't one.
+impl Ord for LowRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_none(), other.is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => other.0.cmp(&self.0),
+        }
+    }
+}
+
+/// `LowEval` is the low counterpart of `Eval`, pairing `FiveCards` with their ace-to-five
+/// `LowRank`, sorted from the highest card down to the ace.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LowEval {
+    pub rank: LowRank,
+    pub hand: FiveCard,
+}
+
+impl LowEval {
+    /// Returns the best ace-to-five low out of every five card combination of the cards, or
+    /// the default `LowEval` if there are less than five of them.
+    #[must_use]
+    pub fn best(cards: &[U32Card]) -> LowEval {
+        cards
+            .iter()
+            .combinations(5)
+            .map(|five| LowEval::from([*five[0], *five[1], *five[2], *five[3], *five[4]]))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the `LowEval` if it's eight or better, and the default otherwise.
+    #[must_use]
+    pub fn eight_or_better(self) -> LowEval {
+        if self.rank.is_eight_or_better() {
+            self
+        } else {
+            LowEval::default()
+        }
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.rank.is_none()
+    }
+}
+
+impl fmt::Display for LowEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hand, self.rank)
+    }
+}
+
+impl From<[U32Card; 5]> for LowEval {
+    fn from(array: [U32Card; 5]) -> Self {
+        let rank = LowRank::from_five(&array);
+        let mut hand = array;
+        let counts = hand.map(|card| {
+            array
+                .iter()
+                .filter(|other| LowRank::ace_low_rank(**other) == LowRank::ace_low_rank(card))
+                .count()
+        });
+        let mut sorted: Vec<(usize, U32Card)> = counts.into_iter().zip(hand).collect();
+        sorted.sort_by_key(|(count, card)| Reverse((*count, LowRank::ace_low_rank(*card))));
+        for (i, (_, card)) in sorted.into_iter().enumerate() {
+            hand[i] = card;
+        }
+        LowEval {
+            rank,
+            hand: FiveCard::from(hand),
+        }
+    }
+}
+
+impl From<FiveCard> for LowEval {
+    fn from(five_cards: FiveCard) -> Self {
+        LowEval::from(five_cards.to_arr())
+    }
+}
+
+impl PartialOrd<Self> for LowEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowEval {
+    fn cmp(&self, other: &LowEval) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_low_tests {
+    use super::*;
+    use crate::types::arrays::Vectorable;
+    use crate::types::poker_cards::PokerCards;
+    use rstest::rstest;
+
+    fn low(index: &'static str) -> LowEval {
+        LowEval::from(FiveCard::try_from(index).unwrap())
+    }
+
+    #[rstest]
+    #[case("5♠ 4♥ 3♦ 2♣ A♠", "5-4-3-2-A")]
+    #[case("A♠ 2♥ 3♦ 4♣ 6♠", "6-4-3-2-A")]
+    #[case("8♠ 6♥ 4♦ 2♣ A♠", "8-6-4-2-A")]
+    #[case("K♠ Q♥ J♦ T♣ 9♠", "K-Q-J-T-9")]
+    #[case("A♠ A♥ 3♦ 2♣ 5♠", "A-5-3-2")]
+    #[case("2♠ 2♥ 3♦ 3♣ 5♠", "3-2-5")]
+    fn display(#[case] index: &'static str, #[case] expected: &str) {
+        assert_eq!(expected, low(index).rank.to_string());
+    }
+
+    #[test]
+    fn display__no_low() {
+        assert_eq!("No Low", LowRank::default().to_string());
+    }
+
+    #[test]
+    fn from__sorts_hand() {
+        assert_eq!("6♠ 4♣ 3♦ 2♥ A♠", low("A♠ 2♥ 3♦ 4♣ 6♠").hand.to_string());
+        assert_eq!("A♠ A♥ 5♠ 3♦ 2♣", low("A♠ 3♦ A♥ 2♣ 5♠").hand.to_string());
+    }
+
+    #[test]
+    fn ordering() {
+        let wheel = low("5♠ 4♥ 3♦ 2♣ A♠");
+        let six = low("6♠ 4♥ 3♦ 2♣ A♠");
+        let eight = low("8♠ 7♥ 6♦ 5♣ 4♠");
+        let eight_six = low("8♠ 6♥ 5♦ 4♣ 3♠");
+        let pair = low("A♠ A♥ 3♦ 2♣ 4♠");
+        let king = low("K♠ Q♥ J♦ T♣ 9♠");
+
+        assert!(wheel > six);
+        assert!(six > eight_six);
+        assert!(eight_six > eight);
+        assert!(king > pair);
+        assert!(pair > LowEval::default());
+    }
+
+    #[test]
+    fn ordering__pairs() {
+        let aces = low("A♠ A♥ 3♦ 2♣ 4♠");
+        let deuces = low("2♠ 2♥ A♦ 3♣ 4♠");
+        let two_pair = low("2♠ 2♥ A♦ A♣ 4♠");
+        let trips = low("2♠ 2♥ 2♦ A♣ 3♠");
+
+        assert!(aces > deuces);
+        assert!(deuces > two_pair);
+        assert!(two_pair > trips);
+    }
+
+    #[test]
+    fn is_eight_or_better() {
+        assert!(low("8♠ 7♥ 6♦ 5♣ 4♠").rank.is_eight_or_better());
+        assert!(low("5♠ 4♥ 3♦ 2♣ A♠").rank.is_eight_or_better());
+        assert!(!low("9♠ 4♥ 3♦ 2♣ A♠").rank.is_eight_or_better());
+        assert!(!low("A♠ A♥ 3♦ 2♣ 4♠").rank.is_eight_or_better());
+        assert!(!LowRank::default().is_eight_or_better());
+    }
+
+    #[test]
+    fn eight_or_better() {
+        assert!(low("9♠ 4♥ 3♦ 2♣ A♠").eight_or_better().is_none());
+        assert_eq!(
+            "8-7-6-5-4",
+            low("8♠ 7♥ 6♦ 5♣ 4♠").eight_or_better().rank.to_string()
+        );
+    }
+
+    #[test]
+    fn best() {
+        let cards = PokerCards::try_from("K♠ 2♥ 7♦ 2♣ A♠ 4♦ 4♣")
+            .unwrap()
+            .to_vec();
+
+        assert_eq!("K-7-4-2-A", LowEval::best(&cards).rank.to_string());
+        assert!(LowEval::best(&cards[..4]).is_none());
+    }
+}
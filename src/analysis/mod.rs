@@ -1,13 +1,22 @@
+pub mod badugi;
 pub mod chances;
 pub mod count;
+pub mod deuce_to_seven;
 pub mod eval;
 pub mod eval_7card;
 pub mod evals;
 pub mod evals_7card;
 pub mod holdem_playout;
 pub mod indexed;
+pub mod lookup_7card;
+pub mod low;
+pub mod monte_carlo;
 pub mod outs;
+pub mod range_chances;
+pub mod range_equity;
+pub mod sampled_chances;
 pub mod seat_calc;
+pub mod short_deck_eval;
 pub mod store;
 
 use crate::types::arrays::five_card::FiveCard;
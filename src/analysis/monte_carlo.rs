@@ -0,0 +1,179 @@
+use crate::analysis::sampled_chances::SampledChances;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The settings for a Monte Carlo run: the most samples to take, the seed for the random
+/// number generator, so that the same seed always draws the same cards, and optionally the
+/// standard error, in percentage points, at which the run can stop early.
+///
+/// The cards are drawn with `ChaCha8Rng`, whose output for a seed is fixed across versions and
+/// platforms, unlike `StdRng`'s, so a seed gives the same results anywhere.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MonteCarlo {
+    pub samples: usize,
+    pub seed: u64,
+    pub precision: Option<f32>,
+}
+
+impl MonteCarlo {
+    /// How many samples are taken between checks on the precision.
+    pub const BATCH: usize = 100;
+
+    #[must_use]
+    pub fn new(samples: usize, seed: u64) -> MonteCarlo {
+        MonteCarlo {
+            samples,
+            seed,
+            precision: None,
+        }
+    }
+
+    /// Stops the run once every seat's standard error is at or under the precision.
+    #[must_use]
+    pub fn with_precision(mut self, standard_error: f32) -> MonteCarlo {
+        self.precision = Some(standard_error);
+        self
+    }
+
+    /// Draws the number of cards out of the deck at random for each sample, and records the
+    /// shares of the pot for each seat that the trial returns for them.
+    pub fn run<F>(&self, deck: &PlayingCards, draw: usize, mut trial: F) -> SampledChances
+    where
+        F: FnMut(&PlayingCards) -> Vec<(usize, f32)>,
+    {
+        let mut cards: Vec<PlayingCard> = deck.to_vec();
+        if draw > cards.len() {
+            return SampledChances::default();
+        }
+
+        self.sample(|rng| {
+            let (drawn, _) = cards.partial_shuffle(rng, draw);
+            trial(&PlayingCards::from(drawn.to_vec()))
+        })
+    }
+
+    /// The more general version of `run()`, for when there's more to a sample than drawing from
+    /// a single deck. The trial is handed the seeded random number generator to do its own
+    /// dealing with, and any trial that comes back without any shares isn't counted.
+    pub fn sample<F>(&self, mut trial: F) -> SampledChances
+    where
+        F: FnMut(&mut ChaCha8Rng) -> Vec<(usize, f32)>,
+    {
+        let mut chances = SampledChances::default();
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+
+        for sample in 1..=self.samples {
+            let shares = trial(&mut rng);
+            if !shares.is_empty() {
+                chances.push(&shares);
+            }
+
+            if sample % MonteCarlo::BATCH == 0 && self.is_precise(&chances) {
+                break;
+            }
+        }
+        chances
+    }
+
+    //region private functions
+
+    fn is_precise(&self, chances: &SampledChances) -> bool {
+        match self.precision {
+            Some(precision) => chances.max_standard_error() <= precision,
+            None => false,
+        }
+    }
+
+    //endregion
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_monte_carlo_tests {
+    use super::*;
+    use rand::Rng;
+
+    /// Seat 0 wins whenever a spade is drawn.
+    fn spade(cards: &PlayingCards) -> Vec<(usize, f32)> {
+        if cards.to_string().contains('♠') {
+            vec![(0, 1.0), (1, 0.0)]
+        } else {
+            vec![(0, 0.0), (1, 1.0)]
+        }
+    }
+
+    #[test]
+    fn run() {
+        let chances = MonteCarlo::new(2_000, 42).run(&PlayingCards::deck(), 1, spade);
+
+        assert_eq!(2_000, chances.samples());
+        let (low, high) = chances.interval(0);
+        assert!(low < 25.0 && 25.0 < high);
+        assert!(chances.chances().keeping_it_100());
+    }
+
+    #[test]
+    fn run__seeded() {
+        let monte_carlo = MonteCarlo::new(500, 7);
+
+        let first = monte_carlo.run(&PlayingCards::deck(), 2, spade);
+        let second = monte_carlo.run(&PlayingCards::deck(), 2, spade);
+
+        assert_eq!(first.equity(0), second.equity(0));
+        assert_eq!(first.standard_error(0), second.standard_error(0));
+    }
+
+    /// Pins the cards that a seed draws, so that any change to the random number generator, or
+    /// to how it's used, shows up here instead of as tests that quietly stop agreeing.
+    #[test]
+    fn run__pinned() {
+        let mut drawn: Vec<String> = Vec::new();
+
+        let chances = MonteCarlo::new(500, 7).run(&PlayingCards::deck(), 2, |cards| {
+            if drawn.len() < 3 {
+                drawn.push(cards.to_string());
+            }
+            spade(cards)
+        });
+
+        assert_eq!(vec!["6♠ 7♠", "3♣ 5♠", "3♦ K♥"], drawn);
+        assert_eq!(47.2, chances.equity(0));
+    }
+
+    #[test]
+    fn run__with_precision() {
+        let chances =
+            MonteCarlo::new(100_000, 42)
+                .with_precision(2.0)
+                .run(&PlayingCards::deck(), 1, spade);
+
+        assert!(chances.samples() < 100_000);
+        assert_eq!(0, chances.samples() % MonteCarlo::BATCH);
+        assert!(chances.max_standard_error() <= 2.0);
+    }
+
+    #[test]
+    fn sample() {
+        let chances = MonteCarlo::new(1_000, 42).sample(|rng| {
+            if rng.gen_bool(0.5) {
+                vec![(0, 1.0)]
+            } else {
+                Vec::new()
+            }
+        });
+
+        assert!(chances.samples() < 1_000);
+        assert_eq!(100.0, chances.equity(0));
+    }
+
+    #[test]
+    fn run__not_enough_cards() {
+        let chances =
+            MonteCarlo::new(100, 42).run(&PlayingCards::try_from("A♠").unwrap(), 2, spade);
+
+        assert_eq!(0, chances.samples());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::analysis::chances::Chances;
+use crate::types::arrays::two_card::TwoCard;
+
+/// The results of pitting ranges against each other with `RangeEquity`: each range's share of
+/// the pot, along with the first, or hero's, range broken down into the equity of every one of
+/// its combos that's still live.
+#[derive(Clone, Debug, Default)]
+pub struct RangeChances {
+    pub chances: Chances,
+    /// The hero's combos, from the most equity to the least.
+    pub hero: Vec<(TwoCard, f32)>,
+}
+
+impl RangeChances {
+    /// Sorts the hero's combos from the most equity to the least, so that they're easy to read.
+    #[must_use]
+    pub fn new(chances: Chances, mut hero: Vec<(TwoCard, f32)>) -> RangeChances {
+        hero.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        RangeChances { chances, hero }
+    }
+
+    /// The percentage of the pot the range wins.
+    #[must_use]
+    pub fn equity(&self, range: usize) -> f32 {
+        self.chances.get(range)
+    }
+
+    /// The equity of one of the hero's combos, or `None` if it isn't in the range or is dead
+    /// because of the board.
+    #[must_use]
+    pub fn hero_equity(&self, cards: &TwoCard) -> Option<f32> {
+        self.hero
+            .iter()
+            .find(|(combo, _)| combo == cards)
+            .map(|(_, equity)| *equity)
+    }
+
+    pub fn playout(&self) {
+        for range in self.chances.keys() {
+            println!("Range #{}: {:.2}%", range, self.chances.get(*range));
+        }
+        println!("\nHero's combos:");
+        for (combo, equity) in &self.hero {
+            println!("   {combo}: {equity:.2}%");
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_range_chances_tests {
+    use super::*;
+
+    fn range_chances() -> RangeChances {
+        let mut chances = Chances::default();
+        chances.set(0, 60.0);
+        chances.set(1, 40.0);
+        RangeChances::new(
+            chances,
+            vec![
+                (TwoCard::from_index("K♠ K♥").unwrap(), 20.0),
+                (TwoCard::from_index("A♠ A♥").unwrap(), 80.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn new() {
+        let range_chances = range_chances();
+
+        assert_eq!("A♠ A♥", range_chances.hero[0].0.to_string());
+        assert_eq!(60.0, range_chances.equity(0));
+    }
+
+    #[test]
+    fn hero_equity() {
+        let range_chances = range_chances();
+
+        assert_eq!(
+            Some(20.0),
+            range_chances.hero_equity(&TwoCard::from_index("K♠ K♥").unwrap())
+        );
+        assert_eq!(
+            None,
+            range_chances.hero_equity(&TwoCard::from_index("Q♠ Q♥").unwrap())
+        );
+    }
+}
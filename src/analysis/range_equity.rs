@@ -0,0 +1,425 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::monte_carlo::MonteCarlo;
+use crate::analysis::range_chances::RangeChances;
+use crate::analysis::sampled_chances::SampledChances;
+use crate::games::variant::showdown::Showdown;
+use crate::types::arrays::two_card::TwoCard;
+use crate::types::arrays::Vectorable;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::ranges::weighted_two_cards_set::WeightedTwoCardsSet;
+use ckc_rs::HandError;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Hold'em equity between two or more ranges of hands, with any of the board that's known.
+///
+/// Every combo that shares a card with the board is dead, and combos from different ranges
+/// that share a card can't be dealt against each other, so each matchup is weighted by
+/// the product of its combos' weights, and only the ones that can actually happen count.
+///
+/// The first range is the hero's, whose equity is also broken down combo by combo.
+#[derive(Clone, Debug, Default)]
+pub struct RangeEquity {
+    ranges: Vec<WeightedTwoCardsSet>,
+    board: PlayingCards,
+}
+
+impl RangeEquity {
+    /// How many times a sample tries to deal a combo from every range without any of them
+    /// sharing cards before giving up on it.
+    pub const MAX_ATTEMPTS: usize = 1_000;
+
+    /// # Errors
+    ///
+    /// Throws a `HandError::Incomplete` if there are fewer than two ranges, and a
+    /// `HandError::TooManyCards` if the board has more than five cards.
+    pub fn new(
+        ranges: Vec<WeightedTwoCardsSet>,
+        board: PlayingCards,
+    ) -> Result<RangeEquity, HandError> {
+        if ranges.len() < 2 {
+            return Err(HandError::Incomplete);
+        }
+        if board.len() > 5 {
+            return Err(HandError::TooManyCards);
+        }
+        Ok(RangeEquity { ranges, board })
+    }
+
+    /// Takes each range in the standard shorthand, such as `TT+, AKs:0.5`, or as specific
+    /// combos like `AhKh`, along with a Card index string for the board.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` if a range can't be parsed, a
+    /// `HandError::InvalidCard` if the board can't, and the same `HandErrors` as `new()`.
+    pub fn from_notation(ranges: &[&str], board: &str) -> Result<RangeEquity, HandError> {
+        let ranges = ranges
+            .iter()
+            .map(|notation| WeightedTwoCardsSet::from_notation(notation))
+            .collect::<Result<Vec<WeightedTwoCardsSet>, HandError>>()?;
+        let board = if board.trim().is_empty() {
+            PlayingCards::default()
+        } else {
+            PlayingCards::try_from(board)?
+        };
+        RangeEquity::new(ranges, board)
+    }
+
+    /// Works out each range's equity by enumerating every matchup between the ranges, and
+    /// every way that the rest of the board can come out for each of them, with the matchups
+    /// split up between rayon's threads.
+    ///
+    /// **NOTE** From the deal, that's 1.7 million boards for every matchup, so for anything but
+    /// the smallest of ranges use `chances_sampled()` until there's a flop.
+    #[must_use]
+    pub fn chances(&self) -> RangeChances {
+        let combos = self.live_combos();
+        let matchups = RangeEquity::matchups(&combos);
+
+        let results: Vec<(f32, Vec<f32>)> = matchups
+            .par_iter()
+            .map(|matchup| {
+                let hands = RangeEquity::hands(&combos, matchup);
+                (
+                    RangeEquity::weight(&combos, matchup),
+                    self.runout_shares(&hands),
+                )
+            })
+            .collect();
+
+        let mut totals = vec![0.0_f64; self.ranges.len()];
+        let mut weights = 0.0_f64;
+        let mut hero: HashMap<TwoCard, (f64, f64)> = HashMap::new();
+        for (matchup, (weight, shares)) in matchups.iter().zip(results) {
+            let weight = f64::from(weight);
+            weights += weight;
+            for (total, share) in totals.iter_mut().zip(&shares) {
+                *total += weight * f64::from(*share);
+            }
+            let (sum, count) = hero.entry(combos[0][matchup[0]].0).or_default();
+            *sum += weight * f64::from(shares[0]);
+            *count += weight;
+        }
+
+        RangeChances::new(
+            RangeEquity::to_chances(&totals, weights),
+            RangeEquity::to_hero(&hero),
+        )
+    }
+
+    /// Estimates each range's equity by dealing a combo out of every range, in proportion to
+    /// their weights, and running the board out at random for each sample. Along with the
+    /// `RangeChances` it returns the `SampledChances`, for how precise the estimate is.
+    #[must_use]
+    pub fn chances_sampled(&self, monte_carlo: &MonteCarlo) -> (RangeChances, SampledChances) {
+        let combos = self.live_combos();
+        let Some(indexes) = combos
+            .iter()
+            .map(|range| WeightedIndex::new(range.iter().map(|(_, weight)| *weight)).ok())
+            .collect::<Option<Vec<WeightedIndex<f32>>>>()
+        else {
+            return (RangeChances::default(), SampledChances::default());
+        };
+
+        let mut hero: HashMap<TwoCard, (f64, f64)> = HashMap::new();
+        let sampled = monte_carlo.sample(|rng| {
+            let Some(matchup) = RangeEquity::deal(&combos, &indexes, rng) else {
+                return Vec::new();
+            };
+            let hands = RangeEquity::hands(&combos, &matchup);
+            let shares = self.random_shares(&hands, rng);
+
+            let (sum, count) = hero.entry(combos[0][matchup[0]].0).or_default();
+            *sum += f64::from(shares[0]);
+            *count += 1.0;
+
+            shares.into_iter().enumerate().collect()
+        });
+
+        (
+            RangeChances::new(sampled.chances(), RangeEquity::to_hero(&hero)),
+            sampled,
+        )
+    }
+
+    /// Returns the combos in the range that don't share any cards with the board, along with
+    /// their weights.
+    #[must_use]
+    pub fn live_combos(&self) -> Vec<Vec<(TwoCard, f32)>> {
+        self.ranges
+            .iter()
+            .map(|range| {
+                let mut combos: Vec<(TwoCard, f32)> = range
+                    .iter()
+                    .filter(|(cards, weight)| **weight > 0.0 && !self.is_dead(**cards))
+                    .map(|(cards, weight)| (*cards, *weight))
+                    .collect();
+                combos.sort_by_key(|combo| combo.0);
+                combos
+            })
+            .collect()
+    }
+
+    //region private functions
+
+    /// Picks a combo out of each range, trying again if any of them share cards.
+    fn deal(
+        combos: &[Vec<(TwoCard, f32)>],
+        indexes: &[WeightedIndex<f32>],
+        rng: &mut ChaCha8Rng,
+    ) -> Option<Vec<usize>> {
+        for _ in 0..RangeEquity::MAX_ATTEMPTS {
+            let matchup: Vec<usize> = indexes.iter().map(|index| index.sample(rng)).collect();
+            if RangeEquity::is_possible(combos, &matchup) {
+                return Some(matchup);
+            }
+        }
+        None
+    }
+
+    fn hands(combos: &[Vec<(TwoCard, f32)>], matchup: &[usize]) -> Vec<PlayingCards> {
+        matchup
+            .iter()
+            .enumerate()
+            .map(|(range, combo)| PlayingCards::from(&combos[range][*combo].0.to_vec()))
+            .collect()
+    }
+
+    fn is_dead(&self, cards: TwoCard) -> bool {
+        cards
+            .iter()
+            .any(|card| self.board.contains(&PlayingCard::from(*card)))
+    }
+
+    /// Whether none of the combos in the matchup share a card.
+    fn is_possible(combos: &[Vec<(TwoCard, f32)>], matchup: &[usize]) -> bool {
+        let mut cards: Vec<u32> = matchup
+            .iter()
+            .enumerate()
+            .flat_map(|(range, combo)| combos[range][*combo].0.to_vec())
+            .collect();
+        let dealt = cards.len();
+        cards.sort_unstable();
+        cards.dedup();
+        cards.len() == dealt
+    }
+
+    /// Every possible matchup, as the index of the combo from each range.
+    fn matchups(combos: &[Vec<(TwoCard, f32)>]) -> Vec<Vec<usize>> {
+        let mut matchups: Vec<Vec<usize>> = vec![Vec::new()];
+        for range in combos {
+            matchups = matchups
+                .into_iter()
+                .flat_map(|matchup| {
+                    (0..range.len()).map(move |combo| {
+                        let mut next = matchup.clone();
+                        next.push(combo);
+                        next
+                    })
+                })
+                .filter(|matchup| RangeEquity::is_possible(combos, matchup))
+                .collect();
+        }
+        matchups
+    }
+
+    /// Runs the rest of the board out once, at random.
+    fn random_shares(&self, hands: &[PlayingCards], rng: &mut ChaCha8Rng) -> Vec<f32> {
+        let mut remaining = self.remaining(hands).to_vec();
+        let (drawn, _) = remaining.partial_shuffle(rng, 5 - self.board.len());
+        Showdown::High.shares(
+            hands,
+            &self.board.combine(&PlayingCards::from(drawn.to_vec())),
+        )
+    }
+
+    fn remaining(&self, hands: &[PlayingCards]) -> PlayingCards {
+        let mut dealt = self.board.clone();
+        for hand in hands {
+            dealt.append(hand);
+        }
+        PlayingCards::deck().minus(&dealt)
+    }
+
+    /// The average share of the pot for each of the hands over every possible runout.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn runout_shares(&self, hands: &[PlayingCards]) -> Vec<f32> {
+        let mut totals = vec![0.0_f64; hands.len()];
+        let mut runouts = 0_usize;
+        for runout in self.remaining(hands).combinations(5 - self.board.len()) {
+            let board = self.board.combine(&PlayingCards::from(runout));
+            for (total, share) in totals.iter_mut().zip(Showdown::High.shares(hands, &board)) {
+                *total += f64::from(share);
+            }
+            runouts += 1;
+        }
+        totals
+            .into_iter()
+            .map(|total| (total / runouts.max(1) as f64) as f32)
+            .collect()
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn to_chances(totals: &[f64], weights: f64) -> Chances {
+        let mut chances = Chances::default();
+        if weights > 0.0 {
+            for (range, total) in totals.iter().enumerate() {
+                chances.set(range, (total / weights * 100.0) as f32);
+            }
+        }
+        chances
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn to_hero(hero: &HashMap<TwoCard, (f64, f64)>) -> Vec<(TwoCard, f32)> {
+        hero.iter()
+            .filter(|(_, (_, count))| *count > 0.0)
+            .map(|(combo, (sum, count))| (*combo, (sum / count * 100.0) as f32))
+            .collect()
+    }
+
+    fn weight(combos: &[Vec<(TwoCard, f32)>], matchup: &[usize]) -> f32 {
+        matchup
+            .iter()
+            .enumerate()
+            .map(|(range, combo)| combos[range][*combo].1)
+            .product()
+    }
+
+    //endregion
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_range_equity_tests {
+    use super::*;
+    use crate::games::holdem::table::Table;
+    use crate::types::card_slot::CardSlot;
+
+    #[test]
+    fn new__errors() {
+        assert_eq!(
+            HandError::Incomplete,
+            RangeEquity::from_notation(&["AA"], "").unwrap_err()
+        );
+        assert_eq!(
+            HandError::TooManyCards,
+            RangeEquity::from_notation(&["AA", "KK"], "2♠ 3♠ 4♠ 5♠ 6♠ 7♠").unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidIndex,
+            RangeEquity::from_notation(&["AA", "KX"], "").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn live_combos() {
+        let range_equity = RangeEquity::from_notation(&["AA", "KK, AKs"], "A♠ K♦ 7♣").unwrap();
+
+        let combos = range_equity.live_combos();
+
+        assert_eq!(3, combos[0].len());
+        // Three kings, along with the three suited AKs that don't need the A♠ or K♦.
+        assert_eq!(5, combos[1].len());
+    }
+
+    /// A single combo against another comes up with the same odds as the `Table`.
+    #[test]
+    fn chances__hand_v_hand() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ 9♣ 7♠");
+        let expected = table.chances_at_flop();
+
+        let range_chances = RangeEquity::from_notation(&["AsAh", "KsKh"], "A♦ 9♣ 7♠")
+            .unwrap()
+            .chances();
+
+        assert!((expected.get(0) - range_chances.equity(0)).abs() < 0.01);
+        assert!((expected.get(1) - range_chances.equity(1)).abs() < 0.01);
+        assert_eq!(1, range_chances.hero.len());
+    }
+
+    #[test]
+    fn chances__card_removal() {
+        // With the A♠ on the board, the hero only has three combos of aces, each of which
+        // blocks one of the villain's AKs.
+        let range_chances = RangeEquity::from_notation(&["AA", "AKs, QQ"], "A♠ 9♣ 7♦ 2♥")
+            .unwrap()
+            .chances();
+
+        assert_eq!(3, range_chances.hero.len());
+        assert!(range_chances.equity(0) > 90.0);
+        assert!(range_chances.chances.keeping_it_100());
+        assert!(range_chances
+            .hero_equity(&TwoCard::from_index("A♠ A♥").unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn chances__weighted() {
+        let even = RangeEquity::from_notation(&["AsAh", "KK, 22"], "A♦ 9♣ 7♠ 2♥")
+            .unwrap()
+            .chances();
+        let weighted = RangeEquity::from_notation(&["AsAh", "KK:0.1, 22"], "A♦ 9♣ 7♠ 2♥")
+            .unwrap()
+            .chances();
+
+        // The hero's way ahead of the kings, but behind the set of deuces, which now make up
+        // more of the villain's range.
+        assert!(weighted.equity(0) < even.equity(0));
+        assert!(weighted.chances.keeping_it_100());
+    }
+
+    #[test]
+    fn chances__three_ranges() {
+        let range_chances = RangeEquity::from_notation(&["AA", "KK", "QQ"], "9♦ 5♣ 3♠ 2♥")
+            .unwrap()
+            .chances();
+
+        // The kings and the queens each need to hit one of their two outs on the river.
+        assert!(range_chances.equity(0) > 90.0);
+        assert!((range_chances.equity(1) - range_chances.equity(2)).abs() < 0.01);
+        assert!(range_chances.chances.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_sampled() {
+        let range_equity = RangeEquity::from_notation(&["AsAh", "KK, 22"], "K♦ 9♣ 7♠").unwrap();
+        let expected = range_equity.chances();
+
+        let (range_chances, sampled) = range_equity.chances_sampled(&MonteCarlo::new(2_000, 42));
+        let (low, high) = sampled.interval(0);
+
+        assert_eq!(2_000, sampled.samples());
+        assert!(low < expected.equity(0) && expected.equity(0) < high);
+        assert_eq!(1, range_chances.hero.len());
+        assert!(range_chances.chances.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_sampled__seeded() {
+        let range_equity = RangeEquity::from_notation(&["QQ+", "AK, 22"], "").unwrap();
+        let monte_carlo = MonteCarlo::new(300, 7);
+
+        let (first, _) = range_equity.chances_sampled(&monte_carlo);
+        let (second, _) = range_equity.chances_sampled(&monte_carlo);
+
+        assert_eq!(first.equity(0), second.equity(0));
+        assert_eq!(first.hero, second.hero);
+    }
+
+    #[test]
+    fn chances_sampled__no_possible_matchups() {
+        let range_equity = RangeEquity::from_notation(&["AsAh", "AsKs"], "").unwrap();
+
+        let (range_chances, sampled) = range_equity.chances_sampled(&MonteCarlo::new(100, 42));
+
+        assert_eq!(0, sampled.samples());
+        assert!(range_chances.hero.is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::analysis::chances::Chances;
+use std::collections::BTreeMap;
+
+/// The sampled counterpart of `Chances`, accumulating each seat's share of the pot across a
+/// number of random runouts, so that along with the estimated equity it can report how
+/// precise the estimate is.
+///
+/// All of the figures are percentages, so a standard error of `0.5` means plus or minus half
+/// of a percentage point.
+#[derive(Clone, Debug, Default)]
+pub struct SampledChances {
+    samples: usize,
+    /// The sum of each seat's shares of the pot, along with the sum of their squares.
+    sums: BTreeMap<usize, (f64, f64)>,
+}
+
+impl SampledChances {
+    /// The z score for a 95% confidence interval.
+    pub const Z_95: f32 = 1.96;
+
+    /// Records the seats' shares of the pot for one sample.
+    pub fn push(&mut self, shares: &[(usize, f32)]) {
+        self.samples += 1;
+        for (seat, share) in shares {
+            let (sum, squares) = self.sums.entry(*seat).or_default();
+            *sum += f64::from(*share);
+            *squares += f64::from(*share) * f64::from(*share);
+        }
+    }
+
+    /// Returns the estimated equity for every seat as `Chances`.
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let mut chances = Chances::default();
+        for seat in self.keys() {
+            chances.set(*seat, self.equity(*seat));
+        }
+        chances
+    }
+
+    /// The estimated percentage of the pot that the seat wins, with ties splitting it.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn equity(&self, seat: usize) -> f32 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        let (sum, _) = self.sums.get(&seat).copied().unwrap_or_default();
+        ((sum / self.samples as f64) * 100.0) as f32
+    }
+
+    /// The 95% confidence interval for the seat's equity, clamped to between 0 and 100.
+    #[must_use]
+    pub fn interval(&self, seat: usize) -> (f32, f32) {
+        let equity = self.equity(seat);
+        let margin = self.standard_error(seat) * SampledChances::Z_95;
+        ((equity - margin).max(0.0), (equity + margin).min(100.0))
+    }
+
+    pub fn keys(&self) -> std::collections::btree_map::Keys<'_, usize, (f64, f64)> {
+        self.sums.keys()
+    }
+
+    /// The largest standard error of any of the seats, which is how precise the estimate is as
+    /// a whole.
+    #[must_use]
+    pub fn max_standard_error(&self) -> f32 {
+        self.keys()
+            .map(|seat| self.standard_error(*seat))
+            .fold(0.0, f32::max)
+    }
+
+    #[must_use]
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// The standard error of the seat's estimated equity, which shrinks with the square root of
+    /// the number of samples.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn standard_error(&self, seat: usize) -> f32 {
+        if self.samples < 2 {
+            return 0.0;
+        }
+        let n = self.samples as f64;
+        let (sum, squares) = self.sums.get(&seat).copied().unwrap_or_default();
+        let mean = sum / n;
+        let variance = ((squares / n) - (mean * mean)).max(0.0) * n / (n - 1.0);
+        ((variance / n).sqrt() * 100.0) as f32
+    }
+
+    pub fn playout(&self) {
+        for seat in self.keys() {
+            let (low, high) = self.interval(*seat);
+            println!(
+                "Seat {}: {:.1}% ±{:.2} (95%: {:.1}% - {:.1}%)",
+                seat,
+                self.equity(*seat),
+                self.standard_error(*seat),
+                low,
+                high
+            );
+        }
+        println!("Samples: {}", self.samples);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_sampled_chances_tests {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut chances = SampledChances::default();
+        chances.push(&[(0, 1.0), (1, 0.0)]);
+        chances.push(&[(0, 0.0), (1, 1.0)]);
+        chances.push(&[(0, 0.5), (1, 0.5)]);
+        chances.push(&[(0, 1.0), (1, 0.0)]);
+
+        assert_eq!(4, chances.samples());
+        assert_eq!(62.5, chances.equity(0));
+        assert_eq!(37.5, chances.equity(1));
+        assert!(chances.chances().keeping_it_100());
+    }
+
+    #[test]
+    fn standard_error() {
+        let mut chances = SampledChances::default();
+        chances.push(&[(0, 1.0)]);
+        chances.push(&[(0, 0.0)]);
+        chances.push(&[(0, 1.0)]);
+        chances.push(&[(0, 0.0)]);
+
+        // The sample variance is 1/3, so the standard error is the square root of 1/12.
+        assert!((chances.standard_error(0) - 28.8675).abs() < 0.001);
+        assert_eq!(chances.standard_error(0), chances.max_standard_error());
+        // Plus or minus 56.6 is wider than the whole range.
+        assert_eq!((0.0, 100.0), chances.interval(0));
+    }
+
+    #[test]
+    fn standard_error__no_variance() {
+        let mut chances = SampledChances::default();
+        chances.push(&[(0, 1.0)]);
+        chances.push(&[(0, 1.0)]);
+
+        assert_eq!(0.0, chances.standard_error(0));
+        assert_eq!((100.0, 100.0), chances.interval(0));
+    }
+
+    #[test]
+    fn default() {
+        let chances = SampledChances::default();
+
+        assert_eq!(0, chances.samples());
+        assert_eq!(0.0, chances.equity(0));
+        assert_eq!(0.0, chances.standard_error(0));
+    }
+}
@@ -8,6 +8,39 @@ use std::collections::HashMap;
 pub struct SeatCalc(HashMap<usize, Count>);
 
 impl SeatCalc {
+    /// Starts off a zero value entry for each of the seats, so that the ones that never win
+    /// aren't left out of the `Chances`.
+    #[must_use]
+    pub fn primed<I: IntoIterator<Item = usize>>(seats: I) -> SeatCalc {
+        let mut calc = SeatCalc::default();
+        for seat in seats {
+            calc.touch(seat);
+        }
+        calc
+    }
+
+    /// Returns the numbers of the seats holding the best of the ranks, or tied for it.
+    #[must_use]
+    pub fn best<T: Ord + Copy + Default>(ranks: &[(usize, T)]) -> Vec<usize> {
+        let best = ranks
+            .iter()
+            .map(|(_, rank)| *rank)
+            .max()
+            .unwrap_or_default();
+        ranks
+            .iter()
+            .filter(|(_, rank)| *rank == best)
+            .map(|(seat, _)| *seat)
+            .collect()
+    }
+
+    /// Adds the counts for each of the other's seats onto this one's.
+    pub fn extend(&mut self, other: &SeatCalc) {
+        for (seat, count) in &other.0 {
+            self.plus(*seat, count.get());
+        }
+    }
+
     #[must_use]
     pub fn get(&self, seat: usize) -> usize {
         match self.0.get(&seat) {
@@ -56,6 +89,40 @@ impl SeatCalc {
 mod eval_seat_calc_tests {
     use super::*;
 
+    #[test]
+    fn primed() {
+        let calc = SeatCalc::primed([0, 2]);
+
+        assert_eq!(0, calc.get(0));
+        assert_eq!("0%", format!("{:.0}%", calc.chances(1).get(2)));
+        assert_eq!(2, calc.chances(1).keys().count());
+    }
+
+    #[test]
+    fn best() {
+        assert_eq!(
+            vec![1, 3],
+            SeatCalc::best(&[(0, 2), (1, 5), (2, 4), (3, 5)])
+        );
+        assert_eq!(vec![0], SeatCalc::best(&[(0, 'a')]));
+        assert!(SeatCalc::best::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn extend() {
+        let mut calc = SeatCalc::primed([0, 1]);
+        calc.plus(1, 3);
+        let mut other = SeatCalc::default();
+        other.plus(1, 2);
+        other.plus(2, 5);
+
+        calc.extend(&other);
+
+        assert_eq!(0, calc.get(0));
+        assert_eq!(5, calc.get(1));
+        assert_eq!(5, calc.get(2));
+    }
+
     #[test]
     fn increment() {
         let mut calc = SeatCalc::default();
@@ -0,0 +1,245 @@
+use crate::analysis::eval::Eval;
+use crate::types::arrays::five_card::FiveCard;
+use crate::types::arrays::Evaluable;
+use crate::types::U32Card;
+use ckc_rs::hand_rank::{HandRank, HandRankName, HandRankValue};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Whether three of a kind beats a straight or the other way around in short deck, which is
+/// one of the few things that differs between the rooms that spread it.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ShortDeckRules {
+    /// Trips beat straights, since they're the harder hand to make with 36 cards. These are
+    /// the Triton rules that most games use.
+    #[default]
+    TripsBeatStraights,
+    StraightsBeatTrips,
+}
+
+/// `ShortDeckEval` is the short deck counterpart of `Eval`, ordering hands the way they are in
+/// [six plus hold'em](https://en.wikipedia.org/wiki/Six-plus_hold_%27em): a flush beats a full
+/// house, straights and trips are ordered by the `ShortDeckRules`, and with the fives gone,
+/// `A-6-7-8-9` plays as the lowest straight.
+///
+/// The `HandRank` is the standard Cactus Kev one, except for `A-6-7-8-9`, which takes the place
+/// of the nine high straight, or straight flush, that can't be made with a short deck.
+///
+/// Two evals are equal when they're worth the same, whatever the suits of their cards, so that
+/// equality and hashing agree with the ordering.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct ShortDeckEval {
+    pub rank: HandRank,
+    pub hand: FiveCard,
+    key: u32,
+}
+
+impl ShortDeckEval {
+    /// The value of the nine high straight that `A-6-7-8-9` takes the place of.
+    const NINE_HIGH_STRAIGHT: HandRankValue = 1605;
+    const NINE_HIGH_STRAIGHT_FLUSH: HandRankValue = 6;
+
+    #[must_use]
+    pub fn new(five_cards: FiveCard, rules: ShortDeckRules) -> ShortDeckEval {
+        let rank = ShortDeckEval::rank(&five_cards);
+
+        let mut hand = Eval::new(five_cards, rank).hand;
+        if ShortDeckEval::is_ace_to_nine(&five_cards.to_arr()) {
+            // Sorted by frequency the ace comes first, but it's playing low.
+            hand.0.rotate_left(1);
+        }
+
+        ShortDeckEval {
+            rank,
+            hand,
+            key: ShortDeckEval::key(rank, rules),
+        }
+    }
+
+    /// Returns the best short deck hand out of every five card combination of the cards, or
+    /// the default `ShortDeckEval` if there are less than five of them.
+    #[must_use]
+    pub fn best(cards: &[U32Card], rules: ShortDeckRules) -> ShortDeckEval {
+        // Sorting the hand for display is slow, so it's only done for the winner.
+        cards
+            .iter()
+            .combinations(5)
+            .map(|five| FiveCard::from([*five[0], *five[1], *five[2], *five[3], *five[4]]))
+            .max_by_key(|five| ShortDeckEval::key(ShortDeckEval::rank(five), rules))
+            .map(|five| ShortDeckEval::new(five, rules))
+            .unwrap_or_default()
+    }
+
+    //region private functions
+
+    fn rank(five_cards: &FiveCard) -> HandRank {
+        let (_, rank) = five_cards.evaluate();
+        if ShortDeckEval::is_ace_to_nine(&five_cards.to_arr()) {
+            HandRank::from(match rank.name {
+                HandRankName::Flush => ShortDeckEval::NINE_HIGH_STRAIGHT_FLUSH,
+                _ => ShortDeckEval::NINE_HIGH_STRAIGHT,
+            })
+        } else {
+            rank
+        }
+    }
+
+    fn is_ace_to_nine(cards: &[U32Card; 5]) -> bool {
+        // Ranks are numbered from the deuce at 0 up to the ace at 12.
+        let mut ranks: Vec<u32> = cards.iter().map(|card| (card >> 8) & 0xF).collect();
+        ranks.sort_unstable();
+        ranks == [4, 5, 6, 7, 12]
+    }
+
+    /// The higher the key, the better the hand: the order of the `HandRankName` under short deck
+    /// rules, followed by the inverse of the `HandRankValue` within it.
+    fn key(rank: HandRank, rules: ShortDeckRules) -> u32 {
+        let trips_beat_straights = rules == ShortDeckRules::TripsBeatStraights;
+        let order = match rank.name {
+            HandRankName::StraightFlush => 9,
+            HandRankName::FourOfAKind => 8,
+            HandRankName::Flush => 7,
+            HandRankName::FullHouse => 6,
+            HandRankName::ThreeOfAKind if trips_beat_straights => 5,
+            HandRankName::Straight if !trips_beat_straights => 5,
+            HandRankName::Straight | HandRankName::ThreeOfAKind => 4,
+            HandRankName::TwoPair => 3,
+            HandRankName::Pair => 2,
+            HandRankName::HighCard => 1,
+            HandRankName::Invalid => return 0,
+        };
+        (order << 16) | u32::from(HandRankValue::MAX - rank.value)
+    }
+
+    //endregion
+}
+
+impl fmt::Display for ShortDeckEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hand, self.rank)
+    }
+}
+
+impl Eq for ShortDeckEval {}
+
+impl Hash for ShortDeckEval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialEq for ShortDeckEval {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl PartialOrd<Self> for ShortDeckEval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ShortDeckEval {
+    fn cmp(&self, other: &ShortDeckEval) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_short_deck_eval_tests {
+    use super::*;
+    use crate::types::arrays::Vectorable;
+    use crate::types::poker_cards::PokerCards;
+    use ckc_rs::hand_rank::HandRankClass;
+    use rstest::rstest;
+
+    fn eval(index: &'static str, rules: ShortDeckRules) -> ShortDeckEval {
+        ShortDeckEval::new(FiveCard::try_from(index).unwrap(), rules)
+    }
+
+    #[test]
+    fn new__ace_to_nine() {
+        let straight = eval("A♠ 6♥ 7♦ 8♣ 9♠", ShortDeckRules::default());
+        let straight_flush = eval("A♠ 6♠ 7♠ 8♠ 9♠", ShortDeckRules::default());
+
+        assert_eq!("9♠ 8♣ 7♦ 6♥ A♠", straight.hand.to_string());
+        assert_eq!(HandRankClass::NineHighStraight, straight.rank.class);
+        assert_eq!(
+            HandRankClass::NineHighStraightFlush,
+            straight_flush.rank.class
+        );
+    }
+
+    #[test]
+    fn ordering__ace_to_nine_is_the_lowest_straight() {
+        let rules = ShortDeckRules::default();
+        let ace_to_nine = eval("A♠ 6♥ 7♦ 8♣ 9♠", rules);
+        let six_to_ten = eval("T♠ 6♥ 7♦ 8♣ 9♠", rules);
+        let aces = eval("A♠ A♥ K♦ Q♣ J♠", rules);
+
+        assert!(six_to_ten > ace_to_nine);
+        assert!(ace_to_nine > aces);
+        assert!(
+            eval("A♠ 6♠ 7♠ 8♠ 9♠", rules) > eval("A♥ A♦ A♣ A♠ K♠", rules),
+            "A-6-7-8-9 suited is a straight flush"
+        );
+    }
+
+    #[rstest]
+    #[case(ShortDeckRules::TripsBeatStraights)]
+    #[case(ShortDeckRules::StraightsBeatTrips)]
+    fn ordering__flush_beats_full_house(#[case] rules: ShortDeckRules) {
+        assert!(eval("A♠ J♠ 9♠ 8♠ 6♠", rules) > eval("K♠ K♥ K♦ Q♣ Q♠", rules));
+        assert!(eval("K♠ K♥ K♦ K♣ 6♠", rules) > eval("A♠ K♠ Q♠ J♠ 9♠", rules));
+    }
+
+    #[test]
+    fn eq__agrees_with_ordering() {
+        let rules = ShortDeckRules::default();
+        let spades = eval("A♠ K♠ Q♥ J♦ 9♣", rules);
+        let hearts = eval("A♥ K♥ Q♠ J♣ 9♦", rules);
+        let mut set = std::collections::HashSet::new();
+        set.insert(spades);
+        set.insert(hearts);
+
+        assert_eq!(Ordering::Equal, spades.cmp(&hearts));
+        assert_eq!(spades, hearts);
+        assert_eq!(1, set.len());
+        assert_ne!(spades, eval("A♥ K♥ Q♠ J♣ 8♦", rules));
+    }
+
+    #[test]
+    fn ordering__trips_and_straights() {
+        let trips = "6♠ 6♥ 6♦ 8♣ 9♠";
+        let straight = "T♠ J♥ Q♦ K♣ A♠";
+
+        assert!(
+            eval(trips, ShortDeckRules::TripsBeatStraights)
+                > eval(straight, ShortDeckRules::TripsBeatStraights)
+        );
+        assert!(
+            eval(straight, ShortDeckRules::StraightsBeatTrips)
+                > eval(trips, ShortDeckRules::StraightsBeatTrips)
+        );
+    }
+
+    #[test]
+    fn best() {
+        let cards = PokerCards::try_from("A♠ 6♥ 7♦ 8♣ 9♠ 9♥ K♦")
+            .unwrap()
+            .to_vec();
+
+        let best = ShortDeckEval::best(&cards, ShortDeckRules::default());
+
+        assert_eq!(HandRankName::Straight, best.rank.name);
+        assert_eq!(
+            ShortDeckEval::default(),
+            ShortDeckEval::best(&cards[..4], ShortDeckRules::default())
+        );
+    }
+}
@@ -1,15 +1,21 @@
+use crate::analysis::lookup_7card::LOOKUP_7CARD;
 use crate::types::arrays::five_card::FiveCard;
 use crate::types::arrays::seven_card::SevenCard;
 use crate::types::arrays::Vectorable;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::poker_deck::PokerDeck;
+use crate::types::U32Card;
 use ckc_rs::cards::binary_card::{BinaryCard, BC64};
 use ckc_rs::cards::five::Five;
 use ckc_rs::cards::seven::Seven;
 use ckc_rs::cards::{HandRanker, HandValidator};
 use ckc_rs::hand_rank::HandRankValue;
+use itertools::Itertools;
+use memmap2::{Mmap, MmapMut};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::fs::{File, OpenOptions};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct SimpleBinaryCardMap {
@@ -61,6 +67,236 @@ impl BinaryCardMap {
     pub fn is_blank(&self) -> bool {
         *self == BinaryCardMap::default()
     }
+
+    //region binary
+
+    pub const MAGIC: [u8; 8] = *b"FUDDBCM\0";
+    pub const VERSION: u32 = 1;
+    pub const HEADER_LEN: usize = 24;
+    pub const FIVE_CARD_COMBINATIONS: usize = 2_598_960;
+    pub const SEVEN_CARD_COMBINATIONS: usize = 133_784_560;
+
+    /// Writes the rank of every five and seven card combination to a binary file that can be
+    /// opened with `BinaryCardMap::open`. The combinations are generated already sorted, so
+    /// this doesn't need to hold them all in memory, but the file is around 1.3 GB.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created or mapped.
+    pub fn generate_binary(path: &str) -> Result<(), Box<dyn Error>> {
+        let fives = SortedCombinations::new(5).map(|(bc, cards)| {
+            (
+                bc,
+                ckc_rs::evaluate::five_cards([cards[0], cards[1], cards[2], cards[3], cards[4]]),
+            )
+        });
+        let sevens = SortedCombinations::new(7).map(|(bc, cards)| {
+            let seven = [
+                cards[0], cards[1], cards[2], cards[3], cards[4], cards[5], cards[6],
+            ];
+            (
+                bc,
+                LOOKUP_7CARD
+                    .hand_rank(&seven)
+                    .map_or(0, |hand_rank| hand_rank.value),
+            )
+        });
+        // A five card combination can never share a BinaryCard with a seven card one.
+        let merged = fives.merge_by(sevens, |a, b| a.0 < b.0);
+
+        BinaryCardMapFile::write(
+            path,
+            BinaryCardMap::FIVE_CARD_COMBINATIONS + BinaryCardMap::SEVEN_CARD_COMBINATIONS,
+            merged,
+        )
+    }
+
+    /// Writes the passed in maps to a binary file that can be opened with `BinaryCardMap::open`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created or mapped.
+    pub fn write_binary(path: &str, maps: &[BinaryCardMap]) -> Result<(), Box<dyn Error>> {
+        let mut entries: Vec<(BinaryCard, HandRankValue)> =
+            maps.iter().map(|bcm| (bcm.bc, bcm.rank)).collect();
+        entries.sort_unstable_by_key(|(bc, _)| *bc);
+        entries.dedup_by_key(|(bc, _)| *bc);
+
+        BinaryCardMapFile::write(path, entries.len(), entries.into_iter())
+    }
+
+    /// Memory maps a binary file written by `BinaryCardMap::write_binary` or
+    /// `BinaryCardMap::generate_binary`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be mapped, or its header doesn't match its size.
+    pub fn open(path: &str) -> Result<BinaryCardMapFile, Box<dyn Error>> {
+        BinaryCardMapFile::open(path)
+    }
+
+    //endregion
+}
+
+/// Read only, memory mapped view of a binary `BinaryCardMap` file.
+///
+/// The file starts with a 24 byte header: the `BinaryCardMap::MAGIC` bytes, the format version
+/// as a little endian u32, four reserved bytes, and the number of entries as a little endian u64.
+/// After that comes a column of every `BinaryCard` key, sorted, as little endian u64s, followed
+/// by a column of their `HandRankValues`, in the same order, as little endian u16s.
+pub struct BinaryCardMapFile {
+    mmap: Mmap,
+    count: usize,
+}
+
+impl BinaryCardMapFile {
+    fn open(path: &str) -> Result<BinaryCardMapFile, Box<dyn Error>> {
+        let file = File::open(path)?;
+        // Safety: the file is only ever read through the map, and is expected not to
+        // be changed by anything else while it's open.
+        let mmap = unsafe { Mmap::map(&file)? };
+        BinaryCardMapFile::new(mmap)
+    }
+
+    fn write(
+        path: &str,
+        count: usize,
+        entries: impl Iterator<Item = (BinaryCard, HandRankValue)>,
+    ) -> Result<(), Box<dyn Error>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(BinaryCardMapFile::file_len(count) as u64)?;
+        // Safety: the file was just created by us, and nothing else should be touching it.
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+
+        mmap[0..8].copy_from_slice(&BinaryCardMap::MAGIC);
+        mmap[8..12].copy_from_slice(&BinaryCardMap::VERSION.to_le_bytes());
+        mmap[16..24].copy_from_slice(&(count as u64).to_le_bytes());
+
+        let ranks_start = BinaryCardMap::HEADER_LEN + count * 8;
+        let mut written = 0;
+        for (i, (bc, rank)) in entries.take(count).enumerate() {
+            let key = BinaryCardMap::HEADER_LEN + i * 8;
+            mmap[key..key + 8].copy_from_slice(&bc.to_le_bytes());
+            let value = ranks_start + i * 2;
+            mmap[value..value + 2].copy_from_slice(&rank.to_le_bytes());
+            written += 1;
+        }
+        if written != count {
+            return Err(format!("expected {count} entries but only got {written}").into());
+        }
+
+        mmap.flush()?;
+        Ok(())
+    }
+
+    fn new(mmap: Mmap) -> Result<BinaryCardMapFile, Box<dyn Error>> {
+        if mmap.len() < BinaryCardMap::HEADER_LEN || mmap[0..8] != BinaryCardMap::MAGIC {
+            return Err("not a binary card map file".into());
+        }
+        let version = u32::from_le_bytes(mmap[8..12].try_into()?);
+        if version != BinaryCardMap::VERSION {
+            return Err(format!("unsupported binary card map version {version}").into());
+        }
+        let count = usize::try_from(u64::from_le_bytes(mmap[16..24].try_into()?))?;
+        if mmap.len() != BinaryCardMapFile::file_len(count) {
+            return Err("binary card map file is truncated".into());
+        }
+        Ok(BinaryCardMapFile { mmap, count })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the `HandRankValue` for the cards, or 0, the invalid `HandRankValue`, if they
+    /// aren't in the file.
+    #[must_use]
+    pub fn lookup(&self, bc: BinaryCard) -> HandRankValue {
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.key(mid).cmp(&bc) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return self.rank(mid),
+            }
+        }
+        0
+    }
+
+    fn file_len(count: usize) -> usize {
+        BinaryCardMap::HEADER_LEN + count * 10
+    }
+
+    fn key(&self, i: usize) -> BinaryCard {
+        let start = BinaryCardMap::HEADER_LEN + i * 8;
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.mmap[start..start + 8]);
+        BinaryCard::from_le_bytes(bytes)
+    }
+
+    fn rank(&self, i: usize) -> HandRankValue {
+        let start = BinaryCardMap::HEADER_LEN + self.count * 8 + i * 2;
+        HandRankValue::from_le_bytes([self.mmap[start], self.mmap[start + 1]])
+    }
+}
+
+/// Every combination of a number of cards from the deck, in increasing `BinaryCard` order.
+///
+/// Each card's `BinaryCard` is a single bit, so numbering the cards from the lowest bit up, a
+/// combination of them is a mask of card numbers, and the masks with the same number of bits,
+/// walked in increasing order with Gosper's hack, give their `BinaryCards` in increasing order.
+struct SortedCombinations {
+    cards: Vec<(BinaryCard, U32Card)>,
+    mask: u64,
+}
+
+impl SortedCombinations {
+    fn new(number: u32) -> SortedCombinations {
+        let mut cards: Vec<(BinaryCard, U32Card)> = PokerDeck::iter()
+            .map(|card| (BinaryCard::from_ckc(*card), *card))
+            .collect();
+        cards.sort_unstable();
+        SortedCombinations {
+            cards,
+            mask: (1 << number) - 1,
+        }
+    }
+}
+
+impl Iterator for SortedCombinations {
+    type Item = (BinaryCard, Vec<U32Card>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mask >= 1 << self.cards.len() {
+            return None;
+        }
+        let mut bc = 0;
+        let mut combination = Vec::new();
+        for (i, (card_bc, card)) in self.cards.iter().enumerate() {
+            if self.mask & (1 << i) != 0 {
+                bc |= card_bc;
+                combination.push(*card);
+            }
+        }
+
+        let lowest = self.mask & self.mask.wrapping_neg();
+        let ripple = self.mask + lowest;
+        self.mask = (((ripple ^ self.mask) >> 2) / lowest) | ripple;
+
+        Some((bc, combination))
+    }
 }
 
 impl From<Five> for BinaryCardMap {
@@ -137,6 +373,60 @@ mod generic_tests {
         );
     }
 
+    #[test]
+    fn binary__round_trip() {
+        let path = "logs/bcm_round_trip_test.bin";
+        let deck = PlayingCards::deck();
+        let mut maps: Vec<BinaryCardMap> = deck
+            .clone()
+            .draw(9)
+            .combinations(5)
+            .map(BinaryCardMap::from)
+            .collect();
+        maps.extend(
+            deck.clone()
+                .draw(9)
+                .combinations(7)
+                .map(BinaryCardMap::from),
+        );
+
+        BinaryCardMap::write_binary(path, &maps).unwrap();
+        let file = BinaryCardMap::open(path).unwrap();
+
+        assert_eq!(126 + 36, file.len());
+        for bcm in &maps {
+            assert_eq!(bcm.rank, file.lookup(bcm.bc));
+        }
+        assert_eq!(0, file.lookup(BinaryCard::from_index("2♣ 3♦ 4♥ 5♠ 7♣")));
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn open__invalid() {
+        assert!(
+            BinaryCardMap::open("data/hu_nash_equilibrium/holdemresources_hu_push.csv").is_err()
+        );
+        assert!(BinaryCardMap::open("logs/missing.bin").is_err());
+    }
+
+    #[test]
+    fn sorted_combinations() {
+        let combinations: Vec<BinaryCard> = SortedCombinations::new(2).map(|(bc, _)| bc).collect();
+
+        assert_eq!(1326, combinations.len());
+        assert!(combinations.windows(2).all(|w| w[0] < w[1]));
+        for (bc, cards) in SortedCombinations::new(7).take(100) {
+            let expected = cards
+                .iter()
+                .fold(0, |acc, card| acc | BinaryCard::from_ckc(*card));
+            assert_eq!(expected, bc);
+        }
+        assert_eq!(
+            BinaryCardMap::FIVE_CARD_COMBINATIONS,
+            SortedCombinations::new(5).count()
+        );
+    }
+
     #[test]
     fn is_blank() {
         assert!(BinaryCardMap::default().is_blank());
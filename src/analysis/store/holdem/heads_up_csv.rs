@@ -1,15 +1,249 @@
 use crate::analysis::store::holdem::heads_up_odds::HeadsUpOdds;
+use crate::analysis::store::holdem::heads_up_row::HeadsUpRow;
+use crate::games::holdem::heads_up::HeadsUp;
 use std::collections::HashMap;
+use std::error::Error;
+use std::fs::{File, OpenOptions};
+use std::io::Read;
+use std::path::Path;
 
+/// The preflop odds of every heads up matchup, stored by their suit isomorphic representative,
+/// so that the 47 thousand odd rows in the CSV file cover all 1.7 million concrete matchups.
 #[derive(Clone, Debug, Default)]
-pub struct HeadsUpCsv(HashMap<String, HeadsUpOdds>);
+pub struct HeadsUpCsv(HashMap<HeadsUp, HeadsUpOdds>);
 
 impl HeadsUpCsv {
-    // pub fn add(&mut self, _row: HeadsUpOdds) {}
+    pub const PATH: &'static str = "data/heads_up/preflop.csv";
+
+    /// Stores the odds under the representative of their hands.
+    pub fn add(&mut self, odds: HeadsUpOdds) {
+        let (canonical, flipped) = odds.hands().canonical();
+        self.0.insert(canonical, odds.relabel(canonical, flipped));
+    }
+
+    /// Returns the odds for any concrete matchup, looked up by its representative and relabelled
+    /// back to the hands that were passed in.
+    #[must_use]
+    pub fn get(&self, heads_up: &HeadsUp) -> Option<HeadsUpOdds> {
+        let (canonical, flipped) = heads_up.canonical();
+        self.0
+            .get(&canonical)
+            .map(|odds| odds.relabel(*heads_up, flipped))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the representative matchups that don't have odds yet.
+    #[must_use]
+    pub fn missing(&self, matchups: &[HeadsUp]) -> Vec<HeadsUp> {
+        matchups
+            .iter()
+            .filter(|heads_up| self.get(heads_up).is_none())
+            .copied()
+            .collect()
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, or if any of its rows aren't valid.
+    pub fn load(path: &str) -> Result<HeadsUpCsv, Box<dyn Error>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_path(path)?;
+
+        let mut store = HeadsUpCsv::default();
+        for row in reader.deserialize() {
+            let row: HeadsUpRow = row?;
+            store.add(HeadsUpOdds::try_from(&row)?);
+        }
+        Ok(store)
+    }
+
+    /// Calculates every suit isomorphic heads up matchup into the file. This takes days, so it
+    /// can be stopped at any time and will pick up where it left off when run again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or written to.
+    pub fn generate(path: &str) -> Result<usize, Box<dyn Error>> {
+        HeadsUpCsv::write(path, &HeadsUp::canonical_matchups())
+    }
+
+    /// Appends a row to the file for each of the matchups that isn't already in it, returning
+    /// how many were added. Each row is flushed as soon as it's calculated so that at most one
+    /// is lost if the run is interrupted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or written to.
+    pub fn write(path: &str, matchups: &[HeadsUp]) -> Result<usize, Box<dyn Error>> {
+        HeadsUpCsv::append(path, matchups, HeadsUp::odds_preflop)
+    }
+
+    //region private functions
+
+    fn append<F>(path: &str, matchups: &[HeadsUp], calc: F) -> Result<usize, Box<dyn Error>>
+    where
+        F: Fn(&HeadsUp) -> wincounter::result::HeadsUp,
+    {
+        let existing = HeadsUpCsv::resume(path)?;
+        let missing = existing.missing(matchups);
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(file.metadata()?.len() == 0)
+            .from_writer(file);
+
+        for heads_up in &missing {
+            writer.serialize(HeadsUpRow::from_result(*heads_up, &calc(heads_up)))?;
+            writer.flush()?;
+        }
+        Ok(missing.len())
+    }
+
+    /// Loads whatever complete rows are already in the file, first cutting off the partial row
+    /// left behind if the last run was stopped in the middle of writing one.
+    fn resume(path: &str) -> Result<HeadsUpCsv, Box<dyn Error>> {
+        if !Path::new(path).exists() {
+            return Ok(HeadsUpCsv::default());
+        }
+
+        let mut contents = Vec::new();
+        File::open(path)?.read_to_end(&mut contents)?;
+        if contents.last().map_or(false, |last| *last != b'\n') {
+            let complete = contents
+                .iter()
+                .rposition(|byte| *byte == b'\n')
+                .map_or(0, |i| i + 1);
+            OpenOptions::new()
+                .write(true)
+                .open(path)?
+                .set_len(complete as u64)?;
+        }
+
+        HeadsUpCsv::load(path)
+    }
+
+    //endregion
 }
 
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod analysis_store_holdem_heads_up_csv {
-    // use super::*;
+    use super::*;
+    use ckc_rs::PokerCard;
+    use std::fs;
+
+    fn path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("fudd_heads_up_csv_{name}.csv"));
+        let _ = fs::remove_file(&path);
+        path.to_str().unwrap().to_string()
+    }
+
+    /// Stands in for running out every board, which takes minutes a matchup.
+    fn calc(heads_up: &HeadsUp) -> wincounter::result::HeadsUp {
+        if heads_up.first.first().get_card_rank() == heads_up.second.first().get_card_rank() {
+            wincounter::result::HeadsUp::new(0, 0, 10)
+        } else {
+            wincounter::result::HeadsUp::new(8, 1, 1)
+        }
+    }
+
+    fn matchups() -> Vec<HeadsUp> {
+        vec![
+            HeadsUp::from("A♠ A♥ 7♦ 7♣"),
+            HeadsUp::from("A♠ K♠ A♥ K♥"),
+            HeadsUp::from("6♠ 6♥ 5♦ 5♣"),
+        ]
+    }
+
+    #[test]
+    fn add__get() {
+        let mut store = HeadsUpCsv::default();
+        store.add(HeadsUpOdds::from_result(
+            HeadsUp::from("A♠ A♥ 7♦ 7♣"),
+            &wincounter::result::HeadsUp::new(8, 1, 1),
+        ));
+
+        let odds = store.get(&HeadsUp::from("A♦ A♣ 7♠ 7♥")).unwrap();
+
+        assert_eq!(1, store.len());
+        assert_eq!("A♦ A♣: 80.0% - 7♠ 7♥: 10.0% - TIE: 10.0%", odds.to_string());
+        assert!(store.get(&HeadsUp::from("K♠ K♥ 7♦ 7♣")).is_none());
+    }
+
+    #[test]
+    fn get__flipped() {
+        let mut store = HeadsUpCsv::default();
+        store.add(HeadsUpOdds::from_result(
+            HeadsUp::from("A♠ Q♦ A♥ K♥"),
+            &wincounter::result::HeadsUp::new(8, 1, 1),
+        ));
+
+        // Swapping spades and hearts turns the first hand into the second.
+        let odds = store.get(&HeadsUp::from("A♠ K♠ A♥ Q♦")).unwrap();
+
+        assert_eq!("A♠ K♠: 10.0% - A♥ Q♦: 80.0% - TIE: 10.0%", odds.to_string());
+    }
+
+    #[test]
+    fn write__load() {
+        let path = path("write_load");
+
+        let written = HeadsUpCsv::append(&path, &matchups(), calc).unwrap();
+        let store = HeadsUpCsv::load(&path).unwrap();
+
+        assert_eq!(3, written);
+        assert_eq!(3, store.len());
+        assert!(store.missing(&matchups()).is_empty());
+        assert_eq!(
+            "A♦ K♦: 0.0% - A♣ K♣: 0.0% - TIE: 100.0%",
+            store
+                .get(&HeadsUp::from("A♦ K♦ A♣ K♣"))
+                .unwrap()
+                .to_string()
+        );
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn write__resume() {
+        let path = path("resume");
+        HeadsUpCsv::append(&path, &matchups()[..1], calc).unwrap();
+        // A row cut off part of the way through being written.
+        let mut contents = fs::read_to_string(&path).unwrap();
+        contents.push_str("A♠ K♠ A♥ K♥,0,");
+        fs::write(&path, contents).unwrap();
+
+        let written = HeadsUpCsv::append(&path, &matchups(), calc).unwrap();
+        let rows = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(2, written);
+        assert_eq!(3, HeadsUpCsv::load(&path).unwrap().len());
+        assert_eq!(1, rows.matches("Hands").count());
+        assert_eq!(0, HeadsUpCsv::append(&path, &matchups(), calc).unwrap());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn load__invalid() {
+        let path = path("invalid");
+        fs::write(
+            &path,
+            "Hands,FirstWins,SecondWins,Ties\nA♠ A♠ 7♦ 7♣,8,1,1\n",
+        )
+        .unwrap();
+
+        assert!(HeadsUpCsv::load(&path).is_err());
+        assert!(HeadsUpCsv::load(&self::path("does_not_exist")).is_err());
+        let _ = fs::remove_file(&path);
+    }
 }
@@ -1,9 +1,9 @@
+use crate::analysis::store::holdem::heads_up_row::HeadsUpRow;
 use crate::games::holdem::heads_up::HeadsUp;
-use crate::types::arrays::Vectorable;
-use crate::types::playing_cards::PlayingCards;
+use crate::types::index_error::IndexError;
 use std::fmt;
 
-#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct HeadsUpOdds {
     hands: HeadsUp,
     pub first_win: f32,
@@ -12,17 +12,45 @@ pub struct HeadsUpOdds {
 }
 
 impl HeadsUpOdds {
+    /// Runs out every possible board for the hands, so it takes a while.
     #[must_use]
     pub fn calculate(hands: HeadsUp) -> HeadsUpOdds {
         if hands.is_blank() {
             return HeadsUpOdds::default();
         }
+        HeadsUpOdds::from_result(hands, &hands.odds_preflop())
+    }
 
-        for v in hands.remaining().combinations(5) {
-            let (_first_seven, _second_seven) = hands.best_from_seven(&PlayingCards::from(v));
+    #[must_use]
+    pub fn from_result(hands: HeadsUp, result: &wincounter::result::HeadsUp) -> HeadsUpOdds {
+        HeadsUpOdds {
+            hands,
+            first_win: result.percentage_first(),
+            second_win: result.percentage_second(),
+            tie: result.percentage_ties(),
         }
+    }
 
-        HeadsUpOdds::default()
+    #[must_use]
+    pub fn hands(&self) -> HeadsUp {
+        self.hands
+    }
+
+    /// Returns the same odds for a matchup that plays the same as these hands, such as a
+    /// concrete matchup that these are the suit isomorphic representative of. If `flipped`
+    /// the first hand's odds are given to the second.
+    #[must_use]
+    pub fn relabel(&self, hands: HeadsUp, flipped: bool) -> HeadsUpOdds {
+        if flipped {
+            HeadsUpOdds {
+                hands,
+                first_win: self.second_win,
+                second_win: self.first_win,
+                tie: self.tie,
+            }
+        } else {
+            HeadsUpOdds { hands, ..*self }
+        }
     }
 
     #[must_use]
@@ -46,9 +74,27 @@ impl fmt::Display for HeadsUpOdds {
     }
 }
 
+/// Uncalculated odds for the hands.
 impl From<HeadsUp> for HeadsUpOdds {
-    fn from(_heads_up: HeadsUp) -> Self {
-        HeadsUpOdds::default()
+    fn from(hands: HeadsUp) -> Self {
+        HeadsUpOdds {
+            hands,
+            ..HeadsUpOdds::default()
+        }
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError` if the row's hands can't be parsed into a `HeadsUp`.
+impl TryFrom<&HeadsUpRow> for HeadsUpOdds {
+    type Error = IndexError;
+
+    fn try_from(row: &HeadsUpRow) -> Result<Self, Self::Error> {
+        Ok(HeadsUpOdds::from_result(
+            row.heads_up()?,
+            &wincounter::result::HeadsUp::new(row.first_wins, row.second_wins, row.ties),
+        ))
     }
 }
 
@@ -65,6 +111,20 @@ mod analysis_store_holdem_heads_up_odds {
         assert_eq!("6♠ 6♥: 79.7% - 5♦ 5♣: 18.4% - TIE: 1.9%", odds.to_string());
     }
 
+    #[test]
+    fn relabel() {
+        let hands = HeadsUp::from("6♦ 6♣ 5♠ 5♥");
+
+        let odds = calculatedHand().relabel(hands, false);
+        let flipped = calculatedHand().relabel(hands, true);
+
+        assert_eq!("6♦ 6♣: 79.7% - 5♠ 5♥: 18.4% - TIE: 1.9%", odds.to_string());
+        assert_eq!(
+            "6♦ 6♣: 18.4% - 5♠ 5♥: 79.7% - TIE: 1.9%",
+            flipped.to_string()
+        );
+    }
+
     #[test]
     fn is_blank() {
         assert!(HeadsUpOdds::default().is_blank());
@@ -86,6 +146,29 @@ mod analysis_store_holdem_heads_up_odds {
         );
     }
 
+    #[test]
+    fn from__heads_up() {
+        let odds = HeadsUpOdds::from(HeadsUp::from("A♥ Q♥ K♥ J♥"));
+
+        assert_eq!(uncalculatedHand(), odds);
+        assert!(!odds.is_calculated());
+    }
+
+    #[test]
+    fn try_from__heads_up_row() {
+        let row = HeadsUpRow {
+            hands: "6♠ 6♥ 5♦ 5♣".to_string(),
+            first_wins: 1_365_284,
+            second_wins: 314_904,
+            ties: 32_116,
+        };
+
+        let odds = HeadsUpOdds::try_from(&row).unwrap();
+
+        assert_eq!("6♠ 6♥: 79.7% - 5♦ 5♣: 18.4% - TIE: 1.9%", odds.to_string());
+        assert!(HeadsUpOdds::try_from(&HeadsUpRow::default()).is_err());
+    }
+
     fn uncalculatedHand() -> HeadsUpOdds {
         HeadsUpOdds {
             hands: HeadsUp::from("A♥ Q♥ K♥ J♥"),
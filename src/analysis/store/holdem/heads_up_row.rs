@@ -1,4 +1,6 @@
 use crate::games::holdem::hand::Hand;
+use crate::games::holdem::heads_up::HeadsUp;
+use crate::types::index_error::IndexError;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -28,9 +30,30 @@ impl HeadsUpRow {
         }
     }
 
+    #[must_use]
+    pub fn from_result(heads_up: HeadsUp, result: &wincounter::result::HeadsUp) -> HeadsUpRow {
+        HeadsUpRow::new(
+            heads_up.first,
+            heads_up.second,
+            result.first_wins,
+            result.second_wins,
+            result.ties,
+        )
+    }
+
+    /// Runs out every possible board for the hands. Since they're put into a `HeadsUp` the
+    /// stronger of the two hands comes first in the row.
     #[must_use]
     pub fn calc(first: Hand, second: Hand) -> HeadsUpRow {
-        HeadsUpRow::new(first, second, 0, 0, 0)
+        let heads_up = HeadsUp::new(first, second);
+        HeadsUpRow::from_result(heads_up, &heads_up.odds_preflop())
+    }
+
+    /// # Errors
+    ///
+    /// Will return an `IndexError` if the hands aren't four valid, distinct cards.
+    pub fn heads_up(&self) -> Result<HeadsUp, IndexError> {
+        self.hands.parse()
     }
 }
 
@@ -62,4 +85,28 @@ mod analysis_store_holdem_heads_up_row {
 
         assert_eq!("A♣ K♦ A♠ K♠,0,0,0", hand.to_string());
     }
+
+    #[test]
+    fn from_result() {
+        let heads_up = HeadsUp::from("6♠ 6♥ 5♦ 5♣");
+
+        let row = HeadsUpRow::from_result(
+            heads_up,
+            &wincounter::result::HeadsUp::new(1_365_284, 314_904, 32_116),
+        );
+
+        assert_eq!("6♠ 6♥ 5♦ 5♣,1365284,314904,32116", row.to_string());
+        assert_eq!(heads_up, row.heads_up().unwrap());
+    }
+
+    #[test]
+    #[ignore]
+    fn calc() {
+        let row = HeadsUpRow::calc(
+            Hand::new(PlayingCard::FIVE_DIAMONDS, PlayingCard::FIVE_CLUBS),
+            Hand::new(PlayingCard::SIX_SPADES, PlayingCard::SIX_HEARTS),
+        );
+
+        assert_eq!("6♠ 6♥ 5♦ 5♣,1365284,314904,32116", row.to_string());
+    }
 }
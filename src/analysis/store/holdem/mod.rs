@@ -3,3 +3,4 @@ pub mod heads_up_csv;
 pub mod heads_up_odds;
 pub mod heads_up_row;
 pub mod hup;
+pub mod push_fold_chart;
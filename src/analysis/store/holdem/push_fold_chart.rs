@@ -0,0 +1,220 @@
+use crate::types::arrays::two_card::TwoCard;
+use crate::types::ranges::two_cards_set::TwoCardsSet;
+use crate::types::ranges::weighted_two_cards_set::WeightedTwoCardsSet;
+use std::collections::HashMap;
+use std::error::Error;
+
+/// A heads up Nash equilibrium push/fold chart, such as the ones from
+/// [HoldemResources](https://www.holdemresources.net/hune) under `data/hu_nash_equilibrium`.
+///
+/// Each row of the chart is an effective stack depth in big blinds, and each column is one
+/// of the 169 starting hand classes, holding how often the hand should be played at that
+/// depth, from 0 to 1. The push chart is how often the small blind should shove, and the
+/// call chart is how often the big blind should call a shove.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PushFoldChart {
+    pub stacks: Vec<f32>,
+    pub classes: Vec<String>,
+    frequencies: Vec<Vec<f32>>,
+    columns: HashMap<String, usize>,
+}
+
+impl PushFoldChart {
+    pub const PUSH_PATH: &'static str = "data/hu_nash_equilibrium/holdemresources_hu_push.csv";
+    pub const CALL_PATH: &'static str = "data/hu_nash_equilibrium/holdemresources_hu_call.csv";
+
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or isn't in the form of a chart.
+    pub fn load(path: &str) -> Result<PushFoldChart, Box<dyn Error>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_path(path)?;
+
+        let mut chart = PushFoldChart {
+            classes: reader.headers()?.iter().skip(1).map(String::from).collect(),
+            ..PushFoldChart::default()
+        };
+        for (i, class) in chart.classes.iter().enumerate() {
+            chart.columns.insert(class.clone(), i);
+        }
+
+        for record in reader.records() {
+            let record = record?;
+            let mut values = record.iter();
+            let stack: f32 = values.next().ok_or("missing stack")?.parse()?;
+            let frequencies = values
+                .map(str::parse::<f32>)
+                .collect::<Result<Vec<f32>, _>>()?;
+            if frequencies.len() != chart.classes.len() {
+                return Err(format!("row for {stack} big blinds is incomplete").into());
+            }
+            chart.stacks.push(stack);
+            chart.frequencies.push(frequencies);
+        }
+        Ok(chart)
+    }
+
+    /// Loads the small blind's shoving chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `PushFoldChart::PUSH_PATH` can't be loaded.
+    pub fn push() -> Result<PushFoldChart, Box<dyn Error>> {
+        PushFoldChart::load(PushFoldChart::PUSH_PATH)
+    }
+
+    /// Loads the big blind's calling chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `PushFoldChart::CALL_PATH` can't be loaded.
+    pub fn call() -> Result<PushFoldChart, Box<dyn Error>> {
+        PushFoldChart::load(PushFoldChart::CALL_PATH)
+    }
+
+    /// How often the hand should be played with the passed in effective stack in big blinds.
+    /// The row for the closest stack depth in the chart is used.
+    #[must_use]
+    pub fn frequency(&self, two_card: &TwoCard, big_blinds: f32) -> f32 {
+        match (
+            self.columns.get(&two_card.shorthand()),
+            self.row(big_blinds),
+        ) {
+            (Some(column), Some(row)) => row[*column],
+            _ => 0.0,
+        }
+    }
+
+    /// Returns true if the hand should be played at least half of the time.
+    #[must_use]
+    pub fn should_play(&self, two_card: &TwoCard, big_blinds: f32) -> bool {
+        self.frequency(two_card, big_blinds) >= 0.5
+    }
+
+    /// Every hand that should be played at least half of the time at the stack depth.
+    #[must_use]
+    pub fn range(&self, big_blinds: f32) -> TwoCardsSet {
+        let hands: Vec<TwoCard> = self
+            .weighted_range(big_blinds)
+            .iter()
+            .filter(|(_, frequency)| **frequency >= 0.5)
+            .map(|(hand, _)| *hand)
+            .collect();
+        TwoCardsSet::from(&hands)
+    }
+
+    /// Every hand that is ever played at the stack depth, weighted by how often it's played.
+    #[must_use]
+    pub fn weighted_range(&self, big_blinds: f32) -> WeightedTwoCardsSet {
+        let mut range = WeightedTwoCardsSet::default();
+        if let Some(row) = self.row(big_blinds) {
+            for (class, frequency) in self.classes.iter().zip(row) {
+                if *frequency > 0.0 {
+                    for hand in WeightedTwoCardsSet::from_notation(class)
+                        .unwrap_or_default()
+                        .hands
+                        .into_keys()
+                    {
+                        range.insert(hand, *frequency);
+                    }
+                }
+            }
+        }
+        range
+    }
+
+    fn row(&self, big_blinds: f32) -> Option<&Vec<f32>> {
+        let closest = self
+            .stacks
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - big_blinds).abs().total_cmp(&(*b - big_blinds).abs()))
+            .map(|(i, _)| i)?;
+        self.frequencies.get(closest)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod analysis_store_holdem_push_fold_chart_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn load() {
+        let chart = PushFoldChart::push().unwrap();
+
+        assert_eq!(169, chart.classes.len());
+        assert_eq!(3981, chart.stacks.len());
+        assert_eq!("22", chart.classes[0]);
+        assert_eq!(1.0, chart.stacks[0]);
+        assert_eq!(200.0, *chart.stacks.last().unwrap());
+    }
+
+    #[test]
+    fn load__missing_file() {
+        assert!(PushFoldChart::load("data/hu_nash_equilibrium/missing.csv").is_err());
+    }
+
+    #[rstest]
+    #[case("A♠ A♥", 10.0, true)]
+    #[case("A♠ A♥", 200.0, true)]
+    #[case("7♠ 2♥", 1.0, true)]
+    #[case("7♠ 2♥", 10.0, false)]
+    #[case("K♦ 4♦", 10.0, true)]
+    #[case("K♦ 4♦", 200.0, false)]
+    fn should_play__push(
+        #[case] index: &'static str,
+        #[case] big_blinds: f32,
+        #[case] expected: bool,
+    ) {
+        let chart = PushFoldChart::push().unwrap();
+
+        assert_eq!(
+            expected,
+            chart.should_play(&TwoCard::try_from(index).unwrap(), big_blinds)
+        );
+    }
+
+    #[test]
+    fn should_play__call() {
+        let chart = PushFoldChart::call().unwrap();
+
+        assert!(chart.should_play(&TwoCard::try_from("K♠ K♥").unwrap(), 20.0));
+        assert!(!chart.should_play(&TwoCard::try_from("8♠ 3♥").unwrap(), 20.0));
+    }
+
+    #[test]
+    fn frequency__closest_stack() {
+        let chart = PushFoldChart::push().unwrap();
+        let hand = TwoCard::try_from("A♠ 2♥").unwrap();
+
+        assert_eq!(chart.frequency(&hand, 10.9), chart.frequency(&hand, 10.91));
+        assert_eq!(chart.frequency(&hand, 200.0), chart.frequency(&hand, 500.0));
+    }
+
+    #[test]
+    fn range() {
+        let chart = PushFoldChart::push().unwrap();
+
+        assert_eq!(1326, chart.range(1.0).len());
+        assert!(chart.range(10.0).len() < chart.range(5.0).len());
+        assert!(chart
+            .range(10.0)
+            .contains(&TwoCard::try_from("A♠ K♠").unwrap()));
+    }
+
+    #[test]
+    fn weighted_range() {
+        let chart = PushFoldChart::push().unwrap();
+
+        let range = chart.weighted_range(10.9);
+
+        assert_eq!(
+            Some(1.0),
+            range.weight(&TwoCard::try_from("2♠ 2♥").unwrap())
+        );
+        assert_eq!(None, range.weight(&TwoCard::try_from("3♠ 2♥").unwrap()));
+    }
+}
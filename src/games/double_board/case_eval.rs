@@ -0,0 +1,218 @@
+use crate::games::{hi_lo, holdem};
+use serde::{Deserialize, Serialize};
+
+/// How a seat came out of a double board showdown.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BoardsResolution {
+    /// Won the whole pot, both boards, without having to share either of them with anyone.
+    ScoopBoth,
+    /// Won or chopped at least one of the boards, taking part of the pot but not all of it.
+    WinOne,
+    /// Didn't win or chop any part of either board, taking nothing from the pot.
+    LoseBoth,
+}
+
+/// How the half of the pot for one of the boards is decided. In the hi/lo `OmahaGames` each
+/// board is split again between the best high hand and the best qualifying low.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum BoardEval {
+    High(holdem::case_eval::CaseEval),
+    HiLo(hi_lo::case_eval::CaseEval),
+}
+
+impl BoardEval {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            BoardEval::High(case_eval) => case_eval.is_empty(),
+            BoardEval::HiLo(case_eval) => case_eval.is_empty(),
+        }
+    }
+
+    /// Returns the numbers of every seat in the case.
+    #[must_use]
+    pub fn seat_numbers(&self) -> Vec<usize> {
+        match self {
+            BoardEval::High(case_eval) => case_eval.iter().map(|seat| seat.seat.number).collect(),
+            BoardEval::HiLo(case_eval) => case_eval.iter().map(|seat| seat.number).collect(),
+        }
+    }
+
+    /// Returns the numbers of the seats that won or tied for any part of the board.
+    #[must_use]
+    pub fn winners(&self) -> Vec<usize> {
+        self.seat_numbers()
+            .into_iter()
+            .filter(|number| self.share(*number) > 0.0)
+            .collect()
+    }
+
+    /// Returns the fraction of the board's half of the pot that goes to the seat.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn share(&self, seat: usize) -> f32 {
+        match self {
+            BoardEval::High(case_eval) => {
+                let winners = case_eval.winners();
+                if winners.iter().any(|winner| winner.seat.number == seat) {
+                    1.0 / winners.len() as f32
+                } else {
+                    0.0
+                }
+            }
+            BoardEval::HiLo(case_eval) => case_eval.share(seat),
+        }
+    }
+}
+
+impl Default for BoardEval {
+    fn default() -> Self {
+        BoardEval::High(holdem::case_eval::CaseEval::default())
+    }
+}
+
+impl From<holdem::case_eval::CaseEval> for BoardEval {
+    fn from(value: holdem::case_eval::CaseEval) -> Self {
+        BoardEval::High(value)
+    }
+}
+
+impl From<hi_lo::case_eval::CaseEval> for BoardEval {
+    fn from(value: hi_lo::case_eval::CaseEval) -> Self {
+        BoardEval::HiLo(value)
+    }
+}
+
+/// The double board version of `holdem::case_eval::CaseEval`, holding a separate `BoardEval`
+/// for each of the two boards. Half of the pot goes to the winner of each board, with each half
+/// split evenly between the seats that tie for it, or split again high and low.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CaseEval {
+    pub first: BoardEval,
+    pub second: BoardEval,
+}
+
+impl CaseEval {
+    #[must_use]
+    pub fn new(first: impl Into<BoardEval>, second: impl Into<BoardEval>) -> CaseEval {
+        CaseEval {
+            first: first.into(),
+            second: second.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+
+    /// Returns the numbers of every seat in the case.
+    #[must_use]
+    pub fn seat_numbers(&self) -> Vec<usize> {
+        self.first.seat_numbers()
+    }
+
+    /// Returns the numbers of the seats that won or tied for the first board.
+    #[must_use]
+    pub fn first_winners(&self) -> Vec<usize> {
+        self.first.winners()
+    }
+
+    /// Returns the numbers of the seats that won or tied for the second board.
+    #[must_use]
+    pub fn second_winners(&self) -> Vec<usize> {
+        self.second.winners()
+    }
+
+    /// Returns the fraction of the pot that goes to the seat.
+    #[must_use]
+    pub fn share(&self, seat: usize) -> f32 {
+        (self.first.share(seat) + self.second.share(seat)) / 2.0
+    }
+
+    #[must_use]
+    pub fn resolution(&self, seat: usize) -> BoardsResolution {
+        let share = self.share(seat);
+        if (share - 1.0).abs() < f32::EPSILON {
+            BoardsResolution::ScoopBoth
+        } else if share > 0.0 {
+            BoardsResolution::WinOne
+        } else {
+            BoardsResolution::LoseBoth
+        }
+    }
+
+    /// Returns the number of the seat that scooped both boards, if any.
+    #[must_use]
+    pub fn scooper(&self) -> Option<usize> {
+        self.seat_numbers()
+            .into_iter()
+            .find(|number| self.resolution(*number) == BoardsResolution::ScoopBoth)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod double_board_case_eval_tests {
+    use super::*;
+    use crate::games::holdem::seats::Seats;
+    use crate::games::omaha;
+    use crate::types::playing_cards::PlayingCards;
+
+    fn case_eval(seats: &'static str, first: &'static str, second: &'static str) -> CaseEval {
+        let seats = Seats::from_index(seats).unwrap();
+        CaseEval::new(
+            seats.case_eval(&PlayingCards::try_from(first).unwrap()),
+            seats.case_eval(&PlayingCards::try_from(second).unwrap()),
+        )
+    }
+
+    #[test]
+    fn resolution__scoop_both() {
+        let case = case_eval("A♠ A♥ K♠ K♥", "A♦ 9♣ 7♠ 4♥ 2♦", "Q♣ J♦ 8♥ 5♠ 3♣");
+
+        assert_eq!(BoardsResolution::ScoopBoth, case.resolution(0));
+        assert_eq!(BoardsResolution::LoseBoth, case.resolution(1));
+        assert_eq!(1.0, case.share(0));
+        assert_eq!(0.0, case.share(1));
+        assert_eq!(Some(0), case.scooper());
+    }
+
+    #[test]
+    fn resolution__win_one() {
+        let case = case_eval("A♠ A♥ K♠ K♥", "A♦ 9♣ 7♠ 4♥ 2♦", "K♦ J♦ 8♥ 5♠ 3♣");
+
+        assert_eq!(vec![0], case.first_winners());
+        assert_eq!(vec![1], case.second_winners());
+        assert_eq!(BoardsResolution::WinOne, case.resolution(0));
+        assert_eq!(BoardsResolution::WinOne, case.resolution(1));
+        assert_eq!(0.5, case.share(1));
+        assert_eq!(None, case.scooper());
+    }
+
+    #[test]
+    fn share__chopped_board() {
+        let case = case_eval("A♠ 2♥ A♥ 3♠", "A♦ A♣ K♠ Q♥ J♦", "9♦ 9♣ 7♠ 4♥ 3♦");
+
+        assert_eq!(vec![0, 1], case.first_winners());
+        assert_eq!(0.25, case.share(0));
+        assert_eq!(0.75, case.share(1));
+        assert_eq!(BoardsResolution::WinOne, case.resolution(0));
+    }
+
+    #[test]
+    fn share__hi_lo() {
+        let seats = omaha::seats::Seats::from_index("K♥ K♦ Q♠ J♣ A♠ 2♠ 9♣ 9♦").unwrap();
+        let case = CaseEval::new(
+            seats.case_eval_hi_lo(&PlayingCards::try_from("K♣ 8♥ 6♦ 4♣ 3♥").unwrap()),
+            seats.case_eval_hi_lo(&PlayingCards::try_from("Q♦ Q♣ 9♥ 7♣ 6♠").unwrap()),
+        );
+
+        assert_eq!(vec![0, 1], case.first_winners());
+        assert_eq!(vec![1], case.second_winners());
+        assert_eq!(0.25, case.share(0));
+        assert_eq!(0.75, case.share(1));
+        assert_eq!(BoardsResolution::WinOne, case.resolution(0));
+        assert_eq!(None, case.scooper());
+    }
+}
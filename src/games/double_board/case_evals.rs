@@ -0,0 +1,105 @@
+use crate::analysis::chances;
+use crate::analysis::seat_calc::SeatCalc;
+use crate::analysis::Evaluate;
+use crate::games::double_board::case_eval::{BoardsResolution, CaseEval};
+use crate::games::double_board::chances::Chances;
+use std::collections::BTreeMap;
+
+/// The double board version of `holdem::case_evals::CaseEvals`, holding a `CaseEval` for every
+/// runout of the two boards, be it every possible one or a random sample of them.
+#[derive(Clone, Debug, Default)]
+pub struct CaseEvals(Vec<CaseEval>);
+
+impl CaseEvals {
+    pub fn push(&mut self, case_eval: CaseEval) {
+        self.0.push(case_eval);
+    }
+
+    /// Returns, for every seat, the percentage of cases where they scoop both boards, win or
+    /// chop only one of them, and lose both, as well as their share of the pot across all of
+    /// the cases, which is their overall equity.
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let mut scoop = SeatCalc::default();
+        let mut win_one = SeatCalc::default();
+        let mut lose_both = SeatCalc::default();
+        let mut shares: BTreeMap<usize, f32> = BTreeMap::new();
+
+        for case in &self.0 {
+            for number in case.seat_numbers() {
+                scoop.touch(number);
+                win_one.touch(number);
+                lose_both.touch(number);
+                *shares.entry(number).or_default() += case.share(number);
+                match case.resolution(number) {
+                    BoardsResolution::ScoopBoth => scoop.increment(number),
+                    BoardsResolution::WinOne => win_one.increment(number),
+                    BoardsResolution::LoseBoth => lose_both.increment(number),
+                };
+            }
+        }
+
+        let mut equity = chances::Chances::default();
+        for (seat, share) in shares {
+            equity.set(seat, Evaluate::percent(1, self.0.len()) * share);
+        }
+
+        Chances {
+            scoop: scoop.chances(self.0.len()),
+            win_one: win_one.chances(self.0.len()),
+            lose_both: lose_both.chances(self.0.len()),
+            equity,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod double_board_case_evals_tests {
+    use super::*;
+    use crate::games::holdem::seats::Seats;
+    use crate::types::playing_cards::PlayingCards;
+
+    fn case_eval(first: &'static str, second: &'static str) -> CaseEval {
+        let seats = Seats::from_index("A♠ A♥ K♠ K♥").unwrap();
+        CaseEval::new(
+            seats.case_eval(&PlayingCards::try_from(first).unwrap()),
+            seats.case_eval(&PlayingCards::try_from(second).unwrap()),
+        )
+    }
+
+    #[test]
+    fn chances() {
+        let mut evals = CaseEvals::default();
+        // Seat 0 scoops.
+        evals.push(case_eval("A♦ 9♣ 7♠ 4♥ 2♦", "Q♣ J♦ 8♥ 5♠ 3♣"));
+        // Each seat takes a board.
+        evals.push(case_eval("A♦ 9♣ 7♠ 4♥ 2♦", "K♦ J♦ 8♥ 5♠ 3♣"));
+        let chances = evals.chances();
+
+        assert_eq!(50.0, chances.scoop.get(0));
+        assert_eq!(0.0, chances.scoop.get(1));
+        assert_eq!(50.0, chances.win_one.get(0));
+        assert_eq!(50.0, chances.win_one.get(1));
+        assert_eq!(0.0, chances.lose_both.get(0));
+        assert_eq!(50.0, chances.lose_both.get(1));
+        assert_eq!(75.0, chances.equity.get(0));
+        assert_eq!(25.0, chances.equity.get(1));
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances__empty() {
+        assert_eq!(0.0, CaseEvals::default().chances().equity.get(0));
+    }
+}
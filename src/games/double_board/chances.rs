@@ -0,0 +1,26 @@
+use crate::analysis::chances;
+
+/// Double board `Chances`, broken out for every seat into how often they scoop both boards, win
+/// or chop just one of them and lose both, along with their overall share of the pot.
+#[derive(Clone, Debug, Default)]
+pub struct Chances {
+    pub scoop: chances::Chances,
+    pub win_one: chances::Chances,
+    pub lose_both: chances::Chances,
+    pub equity: chances::Chances,
+}
+
+impl Chances {
+    pub fn playout(&self) {
+        for k in self.equity.keys() {
+            println!(
+                "Seat {}: {:.1}% - Scoop Both: {:.1}% Win One: {:.1}% Lose Both: {:.1}%",
+                k,
+                self.equity.get(*k),
+                self.scoop.get(*k),
+                self.win_one.get(*k),
+                self.lose_both.get(*k)
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod case_eval;
+pub mod case_evals;
+pub mod chances;
+pub mod players;
+pub mod table;
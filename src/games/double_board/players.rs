@@ -0,0 +1,120 @@
+use crate::games::double_board::case_eval::BoardEval;
+use crate::games::omaha::omaha_game::OmahaGame;
+use crate::games::{holdem, omaha};
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The players at a double board `Table`, who are either holding hold'em `HoleCards` or an
+/// Omaha hand. Players in one of the hi/lo `OmahaGames` are evaluated for both the high and the
+/// low on each board.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum Players {
+    Holdem(holdem::seats::Seats),
+    Omaha(omaha::seats::Seats),
+    OmahaHiLo(omaha::seats::Seats),
+}
+
+impl Players {
+    #[must_use]
+    pub fn holdem(number: usize) -> Players {
+        Players::Holdem(holdem::seats::Seats::seat(number))
+    }
+
+    #[must_use]
+    pub fn omaha(game: OmahaGame, number: usize) -> Players {
+        Players::for_game(game, omaha::seats::Seats::seat_for_game(game, number))
+    }
+
+    /// Seats the Omaha players, split high and low if the `OmahaGame` is played hi/lo.
+    #[must_use]
+    pub fn for_game(game: OmahaGame, seats: omaha::seats::Seats) -> Players {
+        if game.is_hi_lo() {
+            Players::OmahaHiLo(seats)
+        } else {
+            Players::Omaha(seats)
+        }
+    }
+
+    /// Returns a `BoardEval` with the best hand each player can make with the cards on one of
+    /// the boards, along with their best low for the hi/lo games.
+    #[must_use]
+    pub fn case_eval(&self, cycle: &PlayingCards) -> BoardEval {
+        match self {
+            Players::Holdem(seats) => seats.case_eval(cycle).into(),
+            Players::Omaha(seats) => seats.case_eval(cycle).into(),
+            Players::OmahaHiLo(seats) => seats.case_eval_hi_lo(cycle).into(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Players::Holdem(seats) => seats.len(),
+            Players::Omaha(seats) | Players::OmahaHiLo(seats) => seats.len(),
+        }
+    }
+
+    //region private functions
+
+    fn slot(&self) -> &dyn CardSlot {
+        match self {
+            Players::Holdem(seats) => seats,
+            Players::Omaha(seats) | Players::OmahaHiLo(seats) => seats,
+        }
+    }
+
+    //endregion
+}
+
+impl CardSlot for Players {
+    fn take(&self, card: PlayingCard) -> bool {
+        self.slot().take(card)
+    }
+
+    fn fold(&self) -> PlayingCards {
+        self.slot().fold()
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.slot().is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.slot().to_playing_cards()
+    }
+}
+
+impl Default for Players {
+    fn default() -> Self {
+        Players::Holdem(holdem::seats::Seats::default())
+    }
+}
+
+impl fmt::Display for Players {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Players::Holdem(seats) => write!(f, "{seats}"),
+            Players::Omaha(seats) | Players::OmahaHiLo(seats) => write!(f, "{seats}"),
+        }
+    }
+}
+
+impl From<holdem::seats::Seats> for Players {
+    fn from(value: holdem::seats::Seats) -> Self {
+        Players::Holdem(value)
+    }
+}
+
+impl From<omaha::seats::Seats> for Players {
+    fn from(value: omaha::seats::Seats) -> Self {
+        Players::Omaha(value)
+    }
+}
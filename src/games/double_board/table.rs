@@ -0,0 +1,332 @@
+use crate::games::double_board::case_eval::CaseEval;
+use crate::games::double_board::case_evals::CaseEvals;
+use crate::games::double_board::chances::Chances;
+use crate::games::double_board::players::Players;
+use crate::games::holdem::board::Board;
+use crate::games::omaha::omaha_game::OmahaGame;
+use crate::games::{holdem, omaha};
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::HandError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use wyz::FmtForward;
+
+/// A double board version of `holdem::table::Table`, as dealt in most bomb pots, where two
+/// independent boards are run out and half of the pot goes to the best hand on each of them.
+///
+/// The `Players` are either holding hold'em `HoleCards`, or an Omaha hand for one of the
+/// `OmahaGames`, with each board split again high and low for the hi/lo ones.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Table {
+    pub players: Players,
+    pub first: Board,
+    pub second: Board,
+}
+
+impl Table {
+    #[must_use]
+    pub fn seat(number: usize) -> Table {
+        Table {
+            players: Players::holdem(number),
+            ..Table::default()
+        }
+    }
+
+    #[must_use]
+    pub fn seat_for_game(game: OmahaGame, number: usize) -> Table {
+        Table {
+            players: Players::omaha(game, number),
+            ..Table::default()
+        }
+    }
+
+    /// Takes a Card index string and returns a hold'em `Table` with the last ten cards dealt to
+    /// the two boards, five each, and the rest to the `Players`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if the cards passed in aren't valid and at least fourteen; ten for
+    /// the boards and the remaining for the `Players`, which must be divisible by 2.
+    pub fn from_index(index: &str) -> Result<Table, HandError> {
+        let mut cards = PlayingCards::try_from(index)?;
+        let boards = Table::boards(&mut cards, 2)?;
+        Ok(Table::deal(
+            holdem::seats::Seats::try_from(cards)?.into(),
+            boards,
+        ))
+    }
+
+    /// The `OmahaGame` version of `from_index`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if the cards passed in aren't valid, and enough for the boards and
+    /// at least two `Players`, with the cards for the `Players` divisible by the
+    /// `OmahaGame's` hand size.
+    pub fn from_index_for_game(game: OmahaGame, index: &str) -> Result<Table, HandError> {
+        let mut cards = PlayingCards::try_from(index)?;
+        let boards = Table::boards(&mut cards, game.hand_size())?;
+        Ok(Table::deal(
+            Players::for_game(game, omaha::seats::Seats::deal_for_game(game, cards)?),
+            boards,
+        ))
+    }
+
+    /// Runs out every possible combination of the cards left for both boards.
+    ///
+    /// **NOTE** From the flop that's hundreds of thousands of cases even heads up, so
+    /// `chances_sampled()` is the way to go before the turn.
+    pub fn chances(&self) -> Chances {
+        self.eval().chances()
+    }
+
+    pub fn chances_sampled(&self, trials: usize) -> Chances {
+        self.eval_sampled(trials).chances()
+    }
+
+    pub fn dealt(&self) -> PlayingCards {
+        PlayingCards::default()
+            .combine(&self.players.dealt())
+            .combine(&self.first.dealt())
+            .combine(&self.second.dealt())
+    }
+
+    /// Permutates through every way that the rest of the two boards can come out from the
+    /// remaining `PlayingCards`, evaluating each board separately.
+    ///
+    /// **NOTE** Like `holdem::table::Table`, this doesn't take into account any burned cards.
+    #[must_use]
+    pub fn eval(&self) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        if !self.players.is_dealt() {
+            return evals;
+        }
+        let remaining = self.remaining();
+        for v in remaining.combinations(Table::to_come(&self.first)) {
+            let first = PlayingCards::from(v);
+            let rest = remaining.minus(&first);
+            for w in rest.combinations(Table::to_come(&self.second)) {
+                evals.push(self.case_eval(&first, &PlayingCards::from(w)));
+            }
+        }
+        evals
+    }
+
+    /// Runs the rest of the two boards out from the shuffled remaining `PlayingCards` for the
+    /// number of trials.
+    #[must_use]
+    pub fn eval_sampled(&self, trials: usize) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        if !self.players.is_dealt() {
+            return evals;
+        }
+        for _ in 0..trials {
+            let mut deck = self.remaining().shuffle();
+            let first = deck.draw(Table::to_come(&self.first));
+            let second = deck.draw(Table::to_come(&self.second));
+            evals.push(self.case_eval(&first, &second));
+        }
+        evals
+    }
+
+    pub fn remaining(&self) -> PlayingCards {
+        PlayingCards::deck_minus(&self.dealt())
+    }
+
+    //region private functions
+
+    /// Draws the cards for the boards off of the bottom, first board first, as long as there
+    /// are enough cards left for at least two `Players`.
+    fn boards(cards: &mut PlayingCards, hand_size: usize) -> Result<PlayingCards, HandError> {
+        if cards.len() < (hand_size * 2) + 10 {
+            return Err(HandError::NotEnoughCards);
+        }
+        Ok(cards.draw_from_the_bottom(10))
+    }
+
+    fn case_eval(&self, first: &PlayingCards, second: &PlayingCards) -> CaseEval {
+        CaseEval::new(
+            self.players
+                .case_eval(&self.first.to_playing_cards().combine(first)),
+            self.players
+                .case_eval(&self.second.to_playing_cards().combine(second)),
+        )
+    }
+
+    fn deal(players: Players, boards: PlayingCards) -> Table {
+        let mut boards = boards;
+        let table = Table {
+            players,
+            ..Table::default()
+        };
+        table.first.take_from_playing_cards(&boards.draw(5));
+        table.second.take_from_playing_cards(&boards.draw(5));
+        table
+    }
+
+    /// The number of cards still to come on the `Board`.
+    fn to_come(board: &Board) -> usize {
+        5 - board.len()
+    }
+
+    //endregion
+}
+
+impl CardSlot for Table {
+    /// Deals the `Players` and then the boards the way a dealer runs them out, both flops
+    /// before either turn, and both turns before either river.
+    fn take(&self, card: PlayingCard) -> bool {
+        if !self.players.is_dealt() {
+            self.players.take(card)
+        } else if !self.first.flop.is_dealt() {
+            self.first.take(card)
+        } else if !self.second.flop.is_dealt() {
+            self.second.take(card)
+        } else if !self.first.turn.is_dealt() {
+            self.first.take(card)
+        } else if !self.second.turn.is_dealt() {
+            self.second.take(card)
+        } else if !self.first.river.is_dealt() {
+            self.first.take(card)
+        } else {
+            self.second.take(card)
+        }
+    }
+
+    fn fold(&self) -> PlayingCards {
+        self.players
+            .fold()
+            .combine(&self.first.fold())
+            .combine(&self.second.fold())
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.players.is_dealt() && self.first.is_dealt() && self.second.is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.players
+            .to_playing_cards()
+            .combine(&self.first.to_playing_cards())
+            .combine(&self.second.to_playing_cards())
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("PLAYERS: {}", self.players)).fmt_display());
+        out.entry(&(format!("FIRST: {}", self.first)).fmt_display());
+        out.entry(&(format!("SECOND: {}", self.second)).fmt_display());
+
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod double_board_table_tests {
+    use super::*;
+
+    #[test]
+    fn from_index() {
+        let table = Table::from_index("A♠ A♥ K♠ K♥ A♦ 9♣ 7♠ 4♥ 2♦ Q♣ J♦ 8♥ 5♠ 3♣").unwrap();
+
+        assert!(table.is_dealt());
+        assert_eq!(
+            "[PLAYERS: [Seat 0: A♠ A♥, Seat 1: K♠ K♥], FIRST: [FLOP:  A♦ 9♣ 7♠, TURN:  4♥, RIVER: 2♦], SECOND: [FLOP:  Q♣ J♦ 8♥, TURN:  5♠, RIVER: 3♣]]",
+            table.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index__not_enough_cards() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            Table::from_index("A♠ A♥ A♦ 9♣ 7♠ 4♥ 2♦ Q♣ J♦ 8♥ 5♠ 3♣").unwrap_err()
+        );
+        assert_eq!(
+            HandError::NotEnoughCards,
+            Table::from_index_for_game(
+                OmahaGame::Omaha,
+                "A♠ A♥ K♠ K♥ A♦ 9♣ 7♠ 4♥ 2♦ Q♣ J♦ 8♥ 5♠ 3♣"
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn take() {
+        let table = Table::seat(2);
+
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ 9♣ 7♠ Q♣ J♦ 8♥ 4♥ 5♠ 2♦ 3♣");
+
+        assert!(table.is_dealt());
+        assert_eq!("A♦ 9♣ 7♠ 4♥ 2♦", table.first.to_playing_cards().to_string());
+        assert_eq!(
+            "Q♣ J♦ 8♥ 5♠ 3♣",
+            table.second.to_playing_cards().to_string()
+        );
+    }
+
+    #[test]
+    fn chances__river() {
+        let table = Table::from_index("A♠ A♥ K♠ K♥ A♦ 9♣ 7♠ 4♥ 2♦ K♦ J♦ 8♥ 5♠ 3♣").unwrap();
+
+        let chances = table.chances();
+
+        assert_eq!(100.0, chances.win_one.get(0));
+        assert_eq!(100.0, chances.win_one.get(1));
+        assert_eq!(50.0, chances.equity.get(0));
+    }
+
+    #[test]
+    fn chances__turn() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ 9♣ 7♠ Q♣ J♦ 8♥ 4♥ 5♠");
+
+        let evals = table.eval();
+        let chances = evals.chances();
+
+        assert_eq!(40 * 39, evals.len());
+        assert!(chances.scoop.get(0) > 80.0);
+        let total = chances.scoop.get(0) + chances.win_one.get(0) + chances.lose_both.get(0);
+        assert!((total - 100.0).abs() < 0.01);
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_sampled__omaha() {
+        let table = Table::seat_for_game(OmahaGame::Omaha, 2);
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ Q♠ A♣ Q♥ 9♦ 7♣ 2♥ J♣ 8♦ 3♠");
+
+        let chances = table.chances_sampled(500);
+
+        assert!(chances.equity.get(0) > chances.equity.get(1));
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances__omaha_hi_lo() {
+        let table = Table::from_index_for_game(
+            OmahaGame::OmahaHiLo,
+            "K♥ K♦ Q♠ J♣ A♠ 2♠ 9♣ 9♦ K♣ 8♥ 6♦ 4♣ 3♥ Q♦ Q♣ 9♥ 7♣ 6♠",
+        )
+        .unwrap();
+
+        let chances = table.chances();
+
+        assert_eq!(25.0, chances.equity.get(0));
+        assert_eq!(75.0, chances.equity.get(1));
+        assert_eq!(100.0, chances.win_one.get(0));
+    }
+
+    #[test]
+    fn eval__not_dealt() {
+        assert!(Table::seat(2).eval().is_empty());
+        assert!(Table::seat(2).eval_sampled(10).is_empty());
+    }
+}
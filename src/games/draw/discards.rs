@@ -0,0 +1,292 @@
+use crate::games::draw::draw_game::DrawGame;
+use crate::types::playing_cards::PlayingCards;
+use std::fmt;
+
+/// How often drawing to the `kept` cards, after throwing away the `discarded` ones, ends up with
+/// a hand at least as good as the target.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiscardOdds {
+    pub kept: PlayingCards,
+    pub discarded: PlayingCards,
+    pub cases: usize,
+    pub made: usize,
+}
+
+impl DiscardOdds {
+    /// Draws every possible combination of replacement cards out of the `deck`.
+    ///
+    /// **NOTE** Drawing four or five cards means hundreds of thousands of combinations or more,
+    /// so use `DiscardOdds::sampled()` for those.
+    #[must_use]
+    pub fn exhaustive(
+        game: DrawGame,
+        hand: &PlayingCards,
+        discarded: &PlayingCards,
+        deck: &PlayingCards,
+        target: &PlayingCards,
+    ) -> DiscardOdds {
+        let mut odds = DiscardOdds::new(hand, discarded);
+        for v in deck.combinations(discarded.len()) {
+            let drawn = PlayingCards::from(v);
+            odds.tally(game.is_as_good_as(&odds.kept.combine(&drawn), target));
+        }
+        odds
+    }
+
+    /// Draws replacement cards out of the shuffled `deck` for the number of trials.
+    #[must_use]
+    pub fn sampled(
+        game: DrawGame,
+        hand: &PlayingCards,
+        discarded: &PlayingCards,
+        deck: &PlayingCards,
+        target: &PlayingCards,
+        trials: usize,
+    ) -> DiscardOdds {
+        let mut odds = DiscardOdds::new(hand, discarded);
+        for _ in 0..trials {
+            let drawn = deck.shuffle().draw(discarded.len());
+            odds.tally(game.is_as_good_as(&odds.kept.combine(&drawn), target));
+        }
+        odds
+    }
+
+    #[must_use]
+    pub fn percent(&self) -> f32 {
+        if self.cases == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let percent = (self.made as f32 / self.cases as f32) * 100.0;
+        percent
+    }
+
+    //region private functions
+
+    fn new(hand: &PlayingCards, discarded: &PlayingCards) -> DiscardOdds {
+        DiscardOdds {
+            kept: hand.minus(discarded),
+            discarded: discarded.clone(),
+            cases: 0,
+            made: 0,
+        }
+    }
+
+    fn tally(&mut self, made: bool) {
+        self.cases += 1;
+        if made {
+            self.made += 1;
+        }
+    }
+
+    //endregion
+}
+
+impl fmt::Display for DiscardOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Keep: {} Discard: {} {:.2}%",
+            self.kept,
+            self.discarded,
+            self.percent()
+        )
+    }
+}
+
+/// The `DiscardOdds` for every way of discarding from a hand, from the best to the worst, for
+/// working out the best draw to make in a round.
+///
+/// The odds are for the next draw only. Any cards that are known not to be in the deck, such as
+/// other players' discards that were flashed, can be passed in as `dead`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Discards(Vec<DiscardOdds>);
+
+impl Discards {
+    /// Works out the odds for each discard by drawing every possible combination of
+    /// replacements.
+    ///
+    /// **NOTE** Considering every discard means drawing five cards, which is over a million
+    /// combinations on its own, so this is slow. `Discards::sampled()` is usually good enough.
+    #[must_use]
+    pub fn exhaustive(
+        game: DrawGame,
+        hand: &PlayingCards,
+        dead: &PlayingCards,
+        target: &PlayingCards,
+    ) -> Discards {
+        let deck = PlayingCards::deck_minus(&hand.combine(dead));
+        Discards::from(
+            Discards::options(hand)
+                .iter()
+                .map(|discarded| DiscardOdds::exhaustive(game, hand, discarded, &deck, target))
+                .collect::<Vec<DiscardOdds>>(),
+        )
+    }
+
+    /// Estimates the odds for each discard by drawing replacements at random `trials` times.
+    #[must_use]
+    pub fn sampled(
+        game: DrawGame,
+        hand: &PlayingCards,
+        dead: &PlayingCards,
+        target: &PlayingCards,
+        trials: usize,
+    ) -> Discards {
+        let deck = PlayingCards::deck_minus(&hand.combine(dead));
+        Discards::from(
+            Discards::options(hand)
+                .iter()
+                .map(|discarded| DiscardOdds::sampled(game, hand, discarded, &deck, target, trials))
+                .collect::<Vec<DiscardOdds>>(),
+        )
+    }
+
+    #[must_use]
+    pub fn best(&self) -> Option<&DiscardOdds> {
+        self.0.first()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &DiscardOdds> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Every set of cards that can be discarded from the hand, including none of them.
+    #[must_use]
+    pub fn options(hand: &PlayingCards) -> Vec<PlayingCards> {
+        (0..=hand.len())
+            .flat_map(|n| hand.combinations(n).map(PlayingCards::from))
+            .collect()
+    }
+}
+
+/// Sorts the `DiscardOdds` from the best to the worst, with the fewest cards drawn breaking
+/// ties, since standing pat gives away less.
+impl From<Vec<DiscardOdds>> for Discards {
+    fn from(mut v: Vec<DiscardOdds>) -> Self {
+        v.sort_by(|a, b| {
+            b.percent()
+                .total_cmp(&a.percent())
+                .then(a.discarded.len().cmp(&b.discarded.len()))
+        });
+        Discards(v)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod draw_discards_tests {
+    use super::*;
+
+    fn cards(index: &'static str) -> PlayingCards {
+        PlayingCards::try_from(index).unwrap()
+    }
+
+    #[test]
+    fn discard_odds__exhaustive() {
+        let hand = cards("7♠ 5♥ 4♦ 3♣ K♠");
+        let target = cards("8♠ 6♥ 4♦ 3♣ 2♠");
+        let deck = PlayingCards::deck_minus(&hand);
+
+        let odds =
+            DiscardOdds::exhaustive(DrawGame::default(), &hand, &cards("K♠"), &deck, &target);
+
+        // Only drawing one of the four deuces gets there.
+        assert_eq!(47, odds.cases);
+        assert_eq!(4, odds.made);
+        assert_eq!("Keep: 7♠ 5♥ 4♦ 3♣ Discard: K♠ 8.51%", odds.to_string());
+    }
+
+    #[test]
+    fn discard_odds__exhaustive__dead_cards() {
+        let hand = cards("7♠ 5♥ 4♦ 3♣ K♠");
+        let target = cards("8♠ 6♥ 4♦ 3♣ 2♠");
+        let deck = PlayingCards::deck_minus(&hand.combine(&cards("2♥ 2♦")));
+
+        let odds =
+            DiscardOdds::exhaustive(DrawGame::default(), &hand, &cards("K♠"), &deck, &target);
+
+        assert_eq!(45, odds.cases);
+        assert_eq!(2, odds.made);
+    }
+
+    #[test]
+    fn discard_odds__standing_pat() {
+        let hand = cards("8♠ 6♥ 4♦ 3♣ 2♠");
+        let deck = PlayingCards::deck_minus(&hand);
+
+        let odds = DiscardOdds::exhaustive(
+            DrawGame::default(),
+            &hand,
+            &PlayingCards::default(),
+            &deck,
+            &hand,
+        );
+
+        assert_eq!(1, odds.cases);
+        assert_eq!(100.0, odds.percent());
+    }
+
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        let hand = cards("7♠ 5♥ 4♦ 3♣ K♠");
+        let target = cards("8♠ 6♥ 4♦ 3♣ 2♠");
+
+        let discards = Discards::exhaustive(
+            DrawGame::default(),
+            &hand,
+            &PlayingCards::default(),
+            &target,
+        );
+
+        assert_eq!(32, discards.len());
+        assert_eq!(cards("K♠"), discards.best().unwrap().discarded);
+    }
+
+    #[test]
+    fn sampled() {
+        let hand = cards("7♠ 5♥ 4♦ 3♣ 2♠");
+
+        let discards = Discards::sampled(DrawGame::default(), &hand, &cards("2♥"), &hand, 100);
+
+        // With the nuts, nothing beats standing pat, and ties go to drawing fewer cards.
+        assert_eq!(32, discards.len());
+        assert!(discards.best().unwrap().discarded.is_empty());
+        assert_eq!(100.0, discards.best().unwrap().percent());
+    }
+
+    #[test]
+    fn from__sorts() {
+        let worse = DiscardOdds {
+            kept: cards("7♠ 5♥ 4♦ 3♣"),
+            discarded: cards("K♠"),
+            cases: 10,
+            made: 5,
+        };
+        let better = DiscardOdds {
+            kept: cards("7♠ 5♥ 4♦"),
+            discarded: cards("3♣ K♠"),
+            cases: 10,
+            made: 8,
+        };
+
+        let discards = Discards::from(vec![worse.clone(), better.clone()]);
+
+        assert_eq!(Some(&better), discards.best());
+        assert_eq!(
+            vec![&better, &worse],
+            discards.iter().collect::<Vec<&DiscardOdds>>()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::analysis::badugi::BadugiRank;
+use crate::analysis::deuce_to_seven::{DeuceToSevenEval, DeuceToSevenRank};
+use crate::analysis::seat_calc::SeatCalc;
+use crate::types::arrays::Evaluable;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::hand_rank::HandRank;
+use ckc_rs::PokerCard;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The draw games that fudd knows how to deal and evaluate.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum DrawGame {
+    /// [Deuce-to-seven triple draw](https://en.wikipedia.org/wiki/Lowball_(poker)#Deuce-to-seven_low),
+    /// with three rounds of drawing.
+    #[default]
+    DeuceToSevenTripleDraw,
+    /// Deuce-to-seven single draw, also known as Kansas City lowball, with only the one.
+    DeuceToSevenSingleDraw,
+    /// [Badugi](https://en.wikipedia.org/wiki/Badugi), a four card triple draw game.
+    Badugi,
+    /// [Five card draw](https://en.wikipedia.org/wiki/Five-card_draw), played for high with a
+    /// single draw.
+    FiveCardDraw,
+}
+
+impl DrawGame {
+    /// The number of drawing rounds before the showdown.
+    #[must_use]
+    pub fn draws(&self) -> usize {
+        match self {
+            DrawGame::DeuceToSevenTripleDraw | DrawGame::Badugi => 3,
+            DrawGame::DeuceToSevenSingleDraw | DrawGame::FiveCardDraw => 1,
+        }
+    }
+
+    /// The number of cards each player is dealt, and holds after every draw.
+    #[must_use]
+    pub fn hand_size(&self) -> usize {
+        match self {
+            DrawGame::DeuceToSevenTripleDraw
+            | DrawGame::DeuceToSevenSingleDraw
+            | DrawGame::FiveCardDraw => 5,
+            DrawGame::Badugi => 4,
+        }
+    }
+
+    /// Returns true if the hand is at least as good as the target, such as for working out how
+    /// often a draw gets there.
+    #[must_use]
+    pub fn is_as_good_as(&self, hand: &PlayingCards, target: &PlayingCards) -> bool {
+        self.winners(&[(0, hand.clone()), (1, target.clone())])
+            .contains(&0)
+    }
+
+    /// Returns the numbers of the seats with the best hand at showdown, or tied for it.
+    #[must_use]
+    pub fn winners(&self, hands: &[(usize, PlayingCards)]) -> Vec<usize> {
+        match self {
+            DrawGame::DeuceToSevenTripleDraw | DrawGame::DeuceToSevenSingleDraw => {
+                let ranks: Vec<(usize, DeuceToSevenRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, DrawGame::deuce_to_seven(cards)))
+                    .collect();
+                SeatCalc::best(&ranks)
+            }
+            DrawGame::Badugi => {
+                let ranks: Vec<(usize, BadugiRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, DrawGame::badugi(cards)))
+                    .collect();
+                SeatCalc::best(&ranks)
+            }
+            DrawGame::FiveCardDraw => {
+                let ranks: Vec<(usize, HandRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, DrawGame::high(cards)))
+                    .collect();
+                SeatCalc::best(&ranks)
+            }
+        }
+    }
+
+    //region private functions
+
+    fn badugi(cards: &PlayingCards) -> BadugiRank {
+        let cards: Vec<u32> = cards.iter().map(PlayingCard::as_u32).collect();
+        BadugiRank::from_cards(&cards)
+    }
+
+    /// The `HandRank` of five cards played for high, or the default if there aren't five.
+    #[must_use]
+    pub fn high(cards: &PlayingCards) -> HandRank {
+        match cards.to_five_cards() {
+            Ok(five) => five.hand_rank(),
+            Err(_) => HandRank::default(),
+        }
+    }
+
+    fn deuce_to_seven(cards: &PlayingCards) -> DeuceToSevenRank {
+        let cards: Vec<u32> = cards.iter().map(PlayingCard::as_u32).collect();
+        DeuceToSevenEval::best(&cards).rank
+    }
+
+    //endregion
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod draw_draw_game_tests {
+    use super::*;
+
+    fn hands(indexes: &[&'static str]) -> Vec<(usize, PlayingCards)> {
+        indexes
+            .iter()
+            .map(|index| PlayingCards::try_from(*index).unwrap())
+            .enumerate()
+            .collect()
+    }
+
+    #[test]
+    fn draws() {
+        assert_eq!(3, DrawGame::DeuceToSevenTripleDraw.draws());
+        assert_eq!(1, DrawGame::DeuceToSevenSingleDraw.draws());
+        assert_eq!(3, DrawGame::Badugi.draws());
+        assert_eq!(4, DrawGame::Badugi.hand_size());
+    }
+
+    #[test]
+    fn winners() {
+        let game = DrawGame::default();
+
+        assert_eq!(
+            vec![1],
+            game.winners(&hands(&["A♠ 5♥ 4♦ 3♣ 2♠", "8♠ 6♥ 5♦ 4♣ 2♦"]))
+        );
+        assert_eq!(
+            vec![0],
+            game.winners(&hands(&["K♠ Q♥ J♦ 9♣ 8♠", "7♠ 6♥ 5♦ 4♣ 3♦"]))
+        );
+        assert_eq!(
+            vec![0, 1],
+            game.winners(&hands(&["7♠ 5♥ 4♦ 3♣ 2♠", "7♥ 5♦ 4♣ 3♦ 2♥"]))
+        );
+    }
+
+    #[test]
+    fn winners__badugi() {
+        let game = DrawGame::Badugi;
+
+        assert_eq!(
+            vec![1],
+            game.winners(&hands(&["A♠ 2♥ 3♦ 3♣", "K♠ Q♥ J♦ T♣"]))
+        );
+        assert_eq!(
+            vec![0],
+            game.winners(&hands(&["4♠ 3♥ 2♦ A♣", "5♥ 3♠ 2♣ A♦"]))
+        );
+    }
+
+    #[test]
+    fn winners__five_card_draw() {
+        let game = DrawGame::FiveCardDraw;
+
+        assert_eq!(1, game.draws());
+        assert_eq!(
+            vec![1],
+            game.winners(&hands(&["A♠ Q♥ 9♦ 5♣ 2♠", "8♠ 8♥ 5♦ 4♣ 2♦"]))
+        );
+        assert_eq!(
+            vec![0, 1],
+            game.winners(&hands(&["A♠ K♥ Q♦ J♣ 9♠", "A♥ K♦ Q♣ J♠ 9♥"]))
+        );
+    }
+
+    #[test]
+    fn is_as_good_as() {
+        let game = DrawGame::default();
+        let target = PlayingCards::try_from("9♠ 8♥ 6♦ 4♣ 3♠").unwrap();
+
+        assert!(game.is_as_good_as(&PlayingCards::try_from("9♥ 8♦ 6♣ 4♠ 3♥").unwrap(), &target));
+        assert!(game.is_as_good_as(&PlayingCards::try_from("9♥ 7♦ 6♣ 4♠ 3♥").unwrap(), &target));
+        assert!(!game.is_as_good_as(&PlayingCards::try_from("T♥ 7♦ 6♣ 4♠ 3♥").unwrap(), &target));
+    }
+}
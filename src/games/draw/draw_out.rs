@@ -0,0 +1,183 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::seat_calc::SeatCalc;
+use crate::games::draw::draw_game::DrawGame;
+use crate::types::playing_cards::PlayingCards;
+use std::fmt::{Display, Formatter};
+use wyz::FmtForward;
+
+/// The `Chances` of each player winning a draw game, given the cards they're keeping going
+/// into the last draw. Each player draws back up to the `DrawGame's` hand size.
+///
+/// Any cards that are known to be out of the deck, such as the discards that have already been
+/// made, can be passed in as `dead`, so that they aren't drawn.
+#[derive(Clone, Debug, Default)]
+pub struct DrawOut {
+    pub game: DrawGame,
+    pub hands: Vec<(usize, PlayingCards)>,
+    pub dead: PlayingCards,
+}
+
+impl DrawOut {
+    #[must_use]
+    pub fn new(game: DrawGame, dead: PlayingCards) -> DrawOut {
+        DrawOut {
+            game,
+            hands: Vec::new(),
+            dead,
+        }
+    }
+
+    /// Adds the cards that the player in the seat is keeping.
+    pub fn add(&mut self, seat: usize, kept: PlayingCards) {
+        self.hands.push((seat, kept));
+    }
+
+    /// Works out the `Chances` by drawing every possible combination of cards for each player.
+    ///
+    /// **NOTE** The number of combinations multiplies with every card drawn, so this is only
+    /// practical when the players are drawing a card or two each. Use `chances_sampled()` for
+    /// anything more.
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let mut calc = SeatCalc::primed(self.hands.iter().map(|(seat, _)| *seat));
+        let mut hands: Vec<(usize, PlayingCards)> = Vec::new();
+        let cases = self.draw_out(&self.hands, &self.remaining(), &mut hands, &mut calc);
+        calc.chances(cases)
+    }
+
+    /// Estimates the `Chances` by drawing cards for each player at random `trials` times.
+    #[must_use]
+    pub fn chances_sampled(&self, trials: usize) -> Chances {
+        let mut calc = SeatCalc::primed(self.hands.iter().map(|(seat, _)| *seat));
+        let remaining = self.remaining();
+        for _ in 0..trials {
+            let mut deck = remaining.shuffle();
+            let hands: Vec<(usize, PlayingCards)> = self
+                .hands
+                .iter()
+                .map(|(seat, kept)| (*seat, kept.combine(&deck.draw(self.draws(kept)))))
+                .collect();
+            for winner in self.game.winners(&hands) {
+                calc.increment(winner);
+            }
+        }
+        calc.chances(trials)
+    }
+
+    /// Returns all the cards in the deck that aren't being kept by a player or dead.
+    #[must_use]
+    pub fn remaining(&self) -> PlayingCards {
+        let mut known = self.dead.clone();
+        for (_, kept) in &self.hands {
+            known.append(kept);
+        }
+        PlayingCards::deck_minus(&known)
+    }
+
+    //region private functions
+
+    fn draws(&self, kept: &PlayingCards) -> usize {
+        self.game.hand_size().saturating_sub(kept.len())
+    }
+
+    /// Recursively draws each player every combination of the cards they need, tallying the
+    /// winners of every complete draw in the `SeatCalc`, and returning the number of draws.
+    fn draw_out(
+        &self,
+        players: &[(usize, PlayingCards)],
+        remaining: &PlayingCards,
+        hands: &mut Vec<(usize, PlayingCards)>,
+        calc: &mut SeatCalc,
+    ) -> usize {
+        let Some(((seat, kept), rest)) = players.split_first() else {
+            for winner in self.game.winners(hands) {
+                calc.increment(winner);
+            }
+            return 1;
+        };
+        let mut cases = 0;
+        for v in remaining.combinations(self.draws(kept)) {
+            let drawn = PlayingCards::from(v);
+            hands.push((*seat, kept.combine(&drawn)));
+            cases += self.draw_out(rest, &remaining.minus(&drawn), hands, calc);
+            hands.pop();
+        }
+        cases
+    }
+
+    //endregion
+}
+
+impl Display for DrawOut {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        for (seat, kept) in &self.hands {
+            out.entry(&(format!("Seat {seat}: {kept}")).fmt_display());
+        }
+        out.entry(&(format!("DEAD: {}", self.dead)).fmt_display());
+
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod draw_draw_out_tests {
+    use super::*;
+
+    fn cards(index: &'static str) -> PlayingCards {
+        PlayingCards::try_from(index).unwrap()
+    }
+
+    #[test]
+    fn chances__badugi() {
+        let mut draw_out = DrawOut::new(DrawGame::Badugi, PlayingCards::default());
+        draw_out.add(0, cards("8♠ 5♥ 3♦ 2♣"));
+        draw_out.add(1, cards("4♠ 3♥ A♦"));
+
+        let chances = draw_out.chances();
+
+        // Seat 1 needs a club that makes a badugi lower than 8-5-3-2. The 2♣ is gone, so
+        // that's the 5♣, 6♣, 7♣ and 8♣, out of 45 cards.
+        assert_eq!(45, draw_out.remaining().len());
+        assert!(chances.keeping_it_100());
+        assert_eq!(
+            format!("{:.2}", 4.0 / 45.0 * 100.0),
+            format!("{:.2}", chances.get(1))
+        );
+    }
+
+    #[test]
+    fn chances__known_discards() {
+        let mut draw_out = DrawOut::new(DrawGame::Badugi, cards("5♣ 6♣"));
+        draw_out.add(0, cards("8♠ 5♥ 3♦ 2♣"));
+        draw_out.add(1, cards("4♠ 3♥ A♦"));
+
+        // With two of the outs seen in the discards, there's only the 7♣ and 8♣ left.
+        assert_eq!(
+            format!("{:.2}", 2.0 / 43.0 * 100.0),
+            format!("{:.2}", draw_out.chances().get(1))
+        );
+    }
+
+    #[test]
+    fn chances_sampled() {
+        let mut draw_out = DrawOut::new(DrawGame::DeuceToSevenTripleDraw, PlayingCards::default());
+        draw_out.add(0, cards("7♠ 5♥ 4♦ 3♣ 2♠"));
+        draw_out.add(1, cards("K♠ Q♥ J♦ 9♣ 8♠"));
+
+        let chances = draw_out.chances_sampled(10);
+
+        assert_eq!(100.0, chances.get(0));
+        assert_eq!(0.0, chances.get(1));
+    }
+
+    #[test]
+    fn display() {
+        let mut draw_out = DrawOut::new(DrawGame::Badugi, cards("5♣"));
+        draw_out.add(0, cards("8♠ 5♥ 3♦ 2♣"));
+
+        assert_eq!("[Seat 0: 8♠ 5♥ 3♦ 2♣, DEAD: 5♣]", draw_out.to_string());
+    }
+}
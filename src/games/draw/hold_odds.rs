@@ -0,0 +1,446 @@
+use crate::games::draw::discards::Discards;
+use crate::games::draw::draw_game::DrawGame;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::hand_rank::HandRankName;
+use ckc_rs::PokerCard;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Who the hand is up against at showdown in five card draw.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Opponent {
+    /// A known five card hand that the opponent shows down, whose cards are taken out of the
+    /// deck.
+    Hand(PlayingCards),
+    /// A random five card hand, dealt out of what's left, that draws once the way most players
+    /// would with `Opponent::standard_hold()`. Since there are far too many of these to go
+    /// through them all, they can only be sampled.
+    #[default]
+    Random,
+}
+
+impl Opponent {
+    /// Returns the cards that are known to be held by the opponent.
+    #[must_use]
+    pub fn cards(&self) -> PlayingCards {
+        match self {
+            Opponent::Hand(hand) => hand.clone(),
+            Opponent::Random => PlayingCards::default(),
+        }
+    }
+
+    /// The cards that a typical player keeps from their five: standing pat with a straight or
+    /// better, keeping their pairs, trips or quads, and otherwise holding their two highest cards.
+    #[must_use]
+    pub fn standard_hold(hand: &PlayingCards) -> PlayingCards {
+        match DrawGame::high(hand).name {
+            HandRankName::StraightFlush
+            | HandRankName::FourOfAKind
+            | HandRankName::FullHouse
+            | HandRankName::Flush
+            | HandRankName::Straight => hand.clone(),
+            HandRankName::ThreeOfAKind | HandRankName::TwoPair | HandRankName::Pair => {
+                PlayingCards::from(
+                    hand.iter()
+                        .filter(|card| {
+                            hand.iter()
+                                .filter(|other| other.get_rank_bit() == card.get_rank_bit())
+                                .count()
+                                > 1
+                        })
+                        .copied()
+                        .collect::<Vec<PlayingCard>>(),
+                )
+            }
+            HandRankName::HighCard | HandRankName::Invalid => {
+                let mut cards = hand.to_vec();
+                cards.sort_by(|a, b| b.cmp(a));
+                PlayingCards::from(cards.into_iter().take(2).collect::<Vec<PlayingCard>>())
+            }
+        }
+    }
+}
+
+/// How a five card draw hand ends up after holding the `kept` cards and drawing to replace the
+/// `discarded` ones: how often it finishes as each `HandRankName`, and how it does against the
+/// `Opponent`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HoldOdds {
+    pub kept: PlayingCards,
+    pub discarded: PlayingCards,
+    pub cases: usize,
+    pub names: BTreeMap<HandRankName, usize>,
+    pub wins: usize,
+    pub ties: usize,
+}
+
+impl HoldOdds {
+    /// Draws every possible combination of replacement cards out of the `deck`, matching each
+    /// of them up against the opponent's known hand.
+    #[must_use]
+    pub fn exhaustive(
+        hand: &PlayingCards,
+        discarded: &PlayingCards,
+        deck: &PlayingCards,
+        theirs: &PlayingCards,
+    ) -> HoldOdds {
+        let mut odds = HoldOdds::new(hand, discarded);
+        for v in deck.combinations(discarded.len()) {
+            let drawn = PlayingCards::from(v);
+            odds.tally(&odds.kept.combine(&drawn), theirs);
+        }
+        odds
+    }
+
+    /// Draws replacement cards out of the shuffled `deck` for the number of trials, dealing an
+    /// `Opponent::Random` their hand and their draw from the same shuffle. The same seed always
+    /// deals the same cards.
+    #[must_use]
+    pub fn sampled(
+        hand: &PlayingCards,
+        discarded: &PlayingCards,
+        deck: &PlayingCards,
+        opponent: &Opponent,
+        trials: usize,
+        seed: u64,
+    ) -> HoldOdds {
+        let mut odds = HoldOdds::new(hand, discarded);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut cards = deck.to_vec();
+        for _ in 0..trials {
+            cards.shuffle(&mut rng);
+            let mut shuffled = cards.iter().copied();
+            let drawn = PlayingCards::from(
+                shuffled
+                    .by_ref()
+                    .take(discarded.len())
+                    .collect::<Vec<PlayingCard>>(),
+            );
+            let theirs = match opponent {
+                Opponent::Hand(theirs) => theirs.clone(),
+                Opponent::Random => {
+                    let dealt =
+                        PlayingCards::from(shuffled.by_ref().take(5).collect::<Vec<PlayingCard>>());
+                    let kept = Opponent::standard_hold(&dealt);
+                    kept.combine(&PlayingCards::from(
+                        shuffled.take(5 - kept.len()).collect::<Vec<PlayingCard>>(),
+                    ))
+                }
+            };
+            odds.tally(&odds.kept.combine(&drawn), &theirs);
+        }
+        odds
+    }
+
+    /// The share of the pot won against the `Opponent`, as a percentage, with ties splitting it.
+    #[must_use]
+    pub fn equity(&self) -> f32 {
+        if self.cases == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let equity = ((self.wins as f32 + (self.ties as f32 / 2.0)) / self.cases as f32) * 100.0;
+        equity
+    }
+
+    /// The percentage of draws that finish with the `HandRankName`.
+    #[must_use]
+    pub fn percent(&self, name: HandRankName) -> f32 {
+        if self.cases == 0 {
+            return 0.0;
+        }
+        let made = self.names.get(&name).copied().unwrap_or_default();
+        #[allow(clippy::cast_precision_loss)]
+        let percent = (made as f32 / self.cases as f32) * 100.0;
+        percent
+    }
+
+    //region private functions
+
+    fn new(hand: &PlayingCards, discarded: &PlayingCards) -> HoldOdds {
+        HoldOdds {
+            kept: hand.minus(discarded),
+            discarded: discarded.clone(),
+            ..HoldOdds::default()
+        }
+    }
+
+    fn tally(&mut self, hand: &PlayingCards, theirs: &PlayingCards) {
+        let rank = DrawGame::high(hand);
+        self.cases += 1;
+        *self.names.entry(rank.name).or_default() += 1;
+        match rank.cmp(&DrawGame::high(theirs)) {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Equal => self.ties += 1,
+            Ordering::Less => {}
+        }
+    }
+
+    //endregion
+}
+
+impl fmt::Display for HoldOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Keep: {} Discard: {} {:.2}%",
+            self.kept,
+            self.discarded,
+            self.equity()
+        )
+    }
+}
+
+/// The `HoldOdds` for all 32 ways of holding and discarding from a five card draw hand, from the
+/// most equity against the `Opponent` to the least.
+///
+/// Any cards that are known not to be in the deck, such as ones that were flashed, can be passed
+/// in as `dead`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Holds(Vec<HoldOdds>);
+
+impl Holds {
+    /// Works out the odds for each hold against the opponent's known hand by drawing every
+    /// possible combination of replacements. Use `Holds::sampled()` against a random hand.
+    ///
+    /// **NOTE** Like `Discards::exhaustive()`, throwing away all five cards means over a million
+    /// draws on its own, so this is slow. `Holds::sampled()` is usually good enough.
+    #[must_use]
+    pub fn exhaustive(hand: &PlayingCards, dead: &PlayingCards, theirs: &PlayingCards) -> Holds {
+        let deck = Holds::deck(hand, dead, theirs);
+        Holds::from(
+            Discards::options(hand)
+                .iter()
+                .map(|discarded| HoldOdds::exhaustive(hand, discarded, &deck, theirs))
+                .collect::<Vec<HoldOdds>>(),
+        )
+    }
+
+    /// Estimates the odds for each hold by drawing replacements at random `trials` times, with
+    /// the same seed always giving the same odds.
+    #[must_use]
+    pub fn sampled(
+        hand: &PlayingCards,
+        dead: &PlayingCards,
+        opponent: &Opponent,
+        trials: usize,
+        seed: u64,
+    ) -> Holds {
+        let deck = Holds::deck(hand, dead, &opponent.cards());
+        Holds::from(
+            Discards::options(hand)
+                .iter()
+                .map(|discarded| HoldOdds::sampled(hand, discarded, &deck, opponent, trials, seed))
+                .collect::<Vec<HoldOdds>>(),
+        )
+    }
+
+    #[must_use]
+    pub fn best(&self) -> Option<&HoldOdds> {
+        self.0.first()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HoldOdds> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the holds ordered by how often they finish with the `HandRankName`, such as for
+    /// finding the best way to draw to a flush. Ties keep their order by equity.
+    #[must_use]
+    pub fn ranked_by(&self, name: HandRankName) -> Vec<&HoldOdds> {
+        let mut ranked: Vec<&HoldOdds> = self.0.iter().collect();
+        ranked.sort_by(|a, b| b.percent(name).total_cmp(&a.percent(name)));
+        ranked
+    }
+
+    //region private functions
+
+    fn deck(hand: &PlayingCards, dead: &PlayingCards, theirs: &PlayingCards) -> PlayingCards {
+        PlayingCards::deck_minus(&hand.combine(dead).combine(theirs))
+    }
+
+    //endregion
+}
+
+/// Sorts the `HoldOdds` from the most equity to the least, with the fewest cards drawn breaking
+/// ties.
+impl From<Vec<HoldOdds>> for Holds {
+    fn from(mut v: Vec<HoldOdds>) -> Self {
+        v.sort_by(|a, b| {
+            b.equity()
+                .total_cmp(&a.equity())
+                .then(a.discarded.len().cmp(&b.discarded.len()))
+        });
+        Holds(v)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod draw_hold_odds_tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn cards(index: &'static str) -> PlayingCards {
+        PlayingCards::try_from(index).unwrap()
+    }
+
+    fn kings() -> PlayingCards {
+        cards("K♠ K♥ Q♦ J♣ 9♥")
+    }
+
+    #[test]
+    fn hold_odds__exhaustive() {
+        let hand = cards("A♠ A♥ K♦ 7♣ 2♠");
+        let theirs = kings();
+        let deck = PlayingCards::deck_minus(&hand.combine(&theirs));
+
+        let odds = HoldOdds::exhaustive(&hand, &cards("K♦ 7♣ 2♠"), &deck, &theirs);
+
+        // Quads needs both of the other aces, with any one of the 40 cards left as the kicker.
+        assert_eq!(11_480, odds.cases);
+        assert_eq!(40, odds.names[&HandRankName::FourOfAKind]);
+        assert_eq!(odds.cases, odds.names.values().sum::<usize>());
+        assert_eq!(0.0, odds.percent(HandRankName::HighCard));
+        assert_eq!(100.0, odds.equity());
+    }
+
+    #[test]
+    fn hold_odds__standing_pat() {
+        let hand = cards("Q♠ Q♥ 7♦ 7♣ 2♠");
+        let deck = PlayingCards::deck_minus(&hand);
+
+        let odds = HoldOdds::exhaustive(&hand, &PlayingCards::default(), &deck, &kings());
+
+        assert_eq!(1, odds.cases);
+        assert_eq!(100.0, odds.percent(HandRankName::TwoPair));
+        assert_eq!(100.0, odds.equity());
+        assert_eq!("Keep: Q♠ Q♥ 7♦ 7♣ 2♠ Discard:  100.00%", odds.to_string());
+    }
+
+    #[test]
+    fn hold_odds__ties() {
+        let odds = HoldOdds {
+            cases: 4,
+            wins: 1,
+            ties: 2,
+            ..HoldOdds::default()
+        };
+
+        assert_eq!(50.0, odds.equity());
+        assert_eq!(0.0, HoldOdds::default().equity());
+    }
+
+    #[test]
+    fn sampled() {
+        let holds = Holds::sampled(
+            &cards("7♠ 7♥ 7♦ 7♣ 2♠"),
+            &cards("2♥"),
+            &Opponent::Hand(kings()),
+            20,
+            42,
+        );
+        let quads = holds.ranked_by(HandRankName::FourOfAKind);
+
+        // Anything holding three sevens or more can't lose to kings, so standing pat is best.
+        assert_eq!(32, holds.len());
+        assert!(holds.best().unwrap().discarded.is_empty());
+        assert_eq!(100.0, holds.best().unwrap().equity());
+        assert_eq!(100.0, quads[0].percent(HandRankName::FourOfAKind));
+        assert_eq!(100.0, quads[1].percent(HandRankName::FourOfAKind));
+    }
+
+    #[test]
+    fn sampled__random_opponent() {
+        let holds = Holds::sampled(
+            &cards("A♠ K♠ Q♠ J♠ T♠"),
+            &PlayingCards::default(),
+            &Opponent::Random,
+            10,
+            42,
+        );
+
+        assert!(holds.best().unwrap().discarded.is_empty());
+        assert_eq!(
+            100.0,
+            holds.best().unwrap().percent(HandRankName::StraightFlush)
+        );
+    }
+
+    #[test]
+    fn sampled__seeded() {
+        let hand = cards("A♠ A♥ K♦ 7♣ 2♠");
+        let deck = PlayingCards::deck_minus(&hand);
+        let discarded = cards("K♦ 7♣ 2♠");
+
+        let first = HoldOdds::sampled(&hand, &discarded, &deck, &Opponent::Random, 200, 7);
+        let second = HoldOdds::sampled(&hand, &discarded, &deck, &Opponent::Random, 200, 7);
+
+        assert_eq!(first, second);
+        assert_eq!(200, first.cases);
+        assert_ne!(
+            first,
+            HoldOdds::sampled(&hand, &discarded, &deck, &Opponent::Random, 200, 8)
+        );
+    }
+
+    #[rstest]
+    #[case("K♠ K♥ Q♦ J♣ 9♥", "K♠ K♥")]
+    #[case("K♠ K♥ 9♦ 9♣ 2♥", "K♠ K♥ 9♦ 9♣")]
+    #[case("7♠ 7♥ 7♦ J♣ 2♥", "7♠ 7♥ 7♦")]
+    #[case("A♠ 9♠ 7♠ 4♠ 2♠", "A♠ 9♠ 7♠ 4♠ 2♠")]
+    #[case("2♠ K♥ 9♦ A♣ 4♥", "A♣ K♥")]
+    fn opponent__standard_hold(#[case] hand: &'static str, #[case] expected: &'static str) {
+        assert_eq!(cards(expected), Opponent::standard_hold(&cards(hand)));
+    }
+
+    #[test]
+    #[ignore]
+    fn exhaustive() {
+        let holds = Holds::exhaustive(&cards("A♠ A♥ K♦ 7♣ 2♠"), &PlayingCards::default(), &kings());
+
+        assert_eq!(32, holds.len());
+        assert!(holds.best().unwrap().kept.contains(&"A♠".into()));
+    }
+
+    #[test]
+    fn from__sorts() {
+        let worse = HoldOdds {
+            kept: cards("A♠ A♥"),
+            discarded: cards("K♦ 7♣ 2♠"),
+            cases: 10,
+            wins: 5,
+            ..HoldOdds::default()
+        };
+        let better = HoldOdds {
+            kept: cards("A♠ A♥ K♦"),
+            discarded: cards("7♣ 2♠"),
+            cases: 10,
+            wins: 6,
+            ..HoldOdds::default()
+        };
+
+        let holds = Holds::from(vec![worse.clone(), better.clone()]);
+
+        assert_eq!(Some(&better), holds.best());
+        assert_eq!(
+            vec![&better, &worse],
+            holds.iter().collect::<Vec<&HoldOdds>>()
+        );
+    }
+}
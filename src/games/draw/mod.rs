@@ -0,0 +1,5 @@
+pub mod discards;
+pub mod draw_game;
+pub mod draw_out;
+pub mod hold_odds;
+pub mod table;
@@ -0,0 +1,257 @@
+use crate::games::draw::draw_game::DrawGame;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::HandError;
+use std::fmt::{Display, Formatter};
+use wyz::FmtForward;
+
+/// A draw game `Table`, which deals each player a hand out of the `deck`, and then lets them
+/// discard and draw replacements for as many rounds as the `DrawGame` allows.
+///
+/// Discards go into the `muck`, and if the `deck` runs out of cards, the `muck` is shuffled
+/// and put under it, the way a dealer would.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub game: DrawGame,
+    pub players: Vec<PlayingCards>,
+    pub deck: PlayingCards,
+    pub muck: PlayingCards,
+    pub round: usize,
+}
+
+impl Table {
+    #[must_use]
+    pub fn new(game: DrawGame, player_count: usize) -> Table {
+        Table::new_with_deck(game, player_count, PlayingCards::deck_shuffled())
+    }
+
+    /// Creates a `Table` that deals from the cards passed in, in order, which makes for
+    /// repeatable hands.
+    #[must_use]
+    pub fn new_with_deck(game: DrawGame, player_count: usize, deck: PlayingCards) -> Table {
+        Table {
+            game,
+            players: vec![PlayingCards::default(); player_count],
+            deck,
+            muck: PlayingCards::default(),
+            round: 0,
+        }
+    }
+
+    /// Deals each player their hand, one card at a time.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::NotEnoughCards` if there aren't enough cards in the deck to go
+    /// around.
+    pub fn deal(&mut self) -> Result<(), HandError> {
+        if self.deck.len() < self.players.len() * self.game.hand_size() {
+            return Err(HandError::NotEnoughCards);
+        }
+        for _ in 0..self.game.hand_size() {
+            for hand in &mut self.players {
+                hand.insert(self.deck.draw_one());
+            }
+        }
+        Ok(())
+    }
+
+    /// Discards the cards from the player's hand, and replaces them from the deck, returning
+    /// the new cards.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` if there isn't a player in the seat,
+    /// `HandError::InvalidCard` if any of the discards aren't in their hand,
+    /// `HandError::TooManyCards` if all of the draws have already been played, and
+    /// `HandError::NotEnoughCards` if there aren't enough cards left, even with the muck.
+    pub fn draw(
+        &mut self,
+        seat: usize,
+        discards: &PlayingCards,
+    ) -> Result<PlayingCards, HandError> {
+        if self.draws_left() == 0 {
+            return Err(HandError::TooManyCards);
+        }
+        let hand = self.players.get(seat).ok_or(HandError::InvalidIndex)?;
+        if !discards.is_subset(hand) {
+            return Err(HandError::InvalidCard);
+        }
+        if self.deck.len() < discards.len() {
+            if self.deck.len() + self.muck.len() < discards.len() {
+                return Err(HandError::NotEnoughCards);
+            }
+            self.deck.append(&self.muck.shuffle());
+            self.muck = PlayingCards::default();
+        }
+
+        let drawn = self.deck.draw(discards.len());
+        self.players[seat] = self.players[seat].minus(discards).combine(&drawn);
+        self.muck.append(discards);
+        Ok(drawn)
+    }
+
+    /// The number of drawing rounds that are still to come.
+    #[must_use]
+    pub fn draws_left(&self) -> usize {
+        self.game.draws().saturating_sub(self.round)
+    }
+
+    /// Moves on to the next drawing round, returning false if it was the last one.
+    pub fn end_round(&mut self) -> bool {
+        if self.draws_left() > 0 {
+            self.round += 1;
+        }
+        self.draws_left() > 0
+    }
+
+    #[must_use]
+    pub fn is_showdown(&self) -> bool {
+        self.draws_left() == 0
+    }
+
+    #[must_use]
+    pub fn winners(&self) -> Vec<usize> {
+        let hands: Vec<(usize, PlayingCards)> = self.players.iter().cloned().enumerate().collect();
+        self.game.winners(&hands)
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        for (seat, hand) in self.players.iter().enumerate() {
+            out.entry(&(format!("Seat {seat}: {hand}")).fmt_display());
+        }
+        out.entry(&(format!("DRAWS LEFT: {}", self.draws_left())).fmt_display());
+
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod draw_table_tests {
+    use super::*;
+
+    fn table(game: DrawGame) -> Table {
+        let deck =
+            PlayingCards::try_from("7♠ K♥ 5♦ K♦ 4♣ Q♣ 3♠ Q♥ A♦ J♣ 2♠ 8♥ 6♦ 9♣ T♠ T♥").unwrap();
+        let mut table = Table::new_with_deck(game, 2, deck);
+        table.deal().unwrap();
+        table
+    }
+
+    #[test]
+    fn deal() {
+        let table = table(DrawGame::default());
+
+        assert_eq!("7♠ 5♦ 4♣ 3♠ A♦", table.players[0].to_string());
+        assert_eq!("K♥ K♦ Q♣ Q♥ J♣", table.players[1].to_string());
+        assert_eq!(6, table.deck.len());
+    }
+
+    #[test]
+    fn deal__not_enough_cards() {
+        let mut table = Table::new_with_deck(
+            DrawGame::default(),
+            2,
+            PlayingCards::try_from("7♠ K♥ 5♦ K♦").unwrap(),
+        );
+
+        assert_eq!(HandError::NotEnoughCards, table.deal().unwrap_err());
+    }
+
+    #[test]
+    fn draw() {
+        let mut table = table(DrawGame::default());
+
+        let drawn = table
+            .draw(0, &PlayingCards::try_from("A♦").unwrap())
+            .unwrap();
+
+        assert_eq!("2♠", drawn.to_string());
+        assert_eq!("7♠ 5♦ 4♣ 3♠ 2♠", table.players[0].to_string());
+        assert_eq!("A♦", table.muck.to_string());
+        assert_eq!(vec![0], table.winners());
+    }
+
+    #[test]
+    fn draw__errors() {
+        let mut table = table(DrawGame::default());
+
+        assert_eq!(
+            HandError::InvalidIndex,
+            table.draw(2, &PlayingCards::default()).unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidCard,
+            table
+                .draw(0, &PlayingCards::try_from("K♥").unwrap())
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn draw__reshuffles_the_muck() {
+        let mut table = table(DrawGame::default());
+        let kings_and_queens = PlayingCards::try_from("K♥ K♦ Q♣ Q♥").unwrap();
+
+        table.draw(1, &kings_and_queens).unwrap();
+        table.end_round();
+        let drawn = table
+            .draw(1, &PlayingCards::try_from("J♣ 9♣ 8♥").unwrap())
+            .unwrap();
+
+        // Only the two tens are left in the deck, so the third card comes out of the muck.
+        assert_eq!(3, drawn.len());
+        assert!(drawn.contains(&"T♠".into()));
+        assert!(drawn.contains(&"T♥".into()));
+        assert!(
+            drawn.is_subset(&kings_and_queens.combine(&PlayingCards::try_from("T♠ T♥").unwrap()))
+        );
+        assert_eq!("J♣ 9♣ 8♥", table.muck.to_string());
+        assert_eq!(5, table.players[1].len());
+    }
+
+    #[test]
+    fn end_round() {
+        let mut table = table(DrawGame::DeuceToSevenSingleDraw);
+
+        assert_eq!(1, table.draws_left());
+        assert!(!table.end_round());
+        assert!(table.is_showdown());
+        assert_eq!(
+            HandError::TooManyCards,
+            table.draw(0, &PlayingCards::default()).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn five_card_draw() {
+        let mut table = table(DrawGame::FiveCardDraw);
+
+        table
+            .draw(1, &PlayingCards::try_from("J♣").unwrap())
+            .unwrap();
+        table
+            .draw(0, &PlayingCards::try_from("7♠").unwrap())
+            .unwrap();
+
+        assert_eq!("K♥ K♦ Q♣ Q♥ 2♠", table.players[1].to_string());
+        assert_eq!("5♦ 4♣ 3♠ A♦ 8♥", table.players[0].to_string());
+        assert!(!table.end_round());
+        assert!(table.is_showdown());
+        assert_eq!(vec![1], table.winners());
+    }
+
+    #[test]
+    fn display() {
+        let table = table(DrawGame::default());
+
+        assert_eq!(
+            "[Seat 0: 7♠ 5♦ 4♣ 3♠ A♦, Seat 1: K♥ K♦ Q♣ Q♥ J♣, DRAWS LEFT: 3]",
+            table.to_string()
+        );
+    }
+}
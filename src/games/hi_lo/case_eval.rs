@@ -0,0 +1,196 @@
+use crate::analysis::low::LowRank;
+use crate::games::hi_lo::seat_eval::SeatEval;
+use ckc_rs::hand_rank::HandRank;
+use serde::{Deserialize, Serialize};
+
+/// How a seat came out of a split pot showdown.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PotResolution {
+    /// Won the whole pot, high and low, without having to share it with anyone.
+    Scoop,
+    /// Won part of the pot, other than exactly a quarter.
+    Split,
+    /// Won exactly a quarter of the pot, usually by tying for one half of it.
+    Quartered,
+    Lost,
+}
+
+/// The split pot version of `holdem::case_eval::CaseEval`. Half of the pot goes to the best high
+/// hand and half to the best qualifying low, with each half split evenly between the seats that
+/// tie for it. If nobody qualifies for the low, the high hand takes the whole pot.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CaseEval(Vec<SeatEval>);
+
+impl CaseEval {
+    pub fn push(&mut self, seat_eval: SeatEval) {
+        self.0.push(seat_eval);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SeatEval> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn seats(&self) -> &Vec<SeatEval> {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn winning_rank(&self) -> HandRank {
+        self.iter()
+            .map(|seat| seat.high.rank)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the best low, which is no low if nobody qualified for one.
+    #[must_use]
+    pub fn winning_low(&self) -> LowRank {
+        self.iter()
+            .map(|seat| seat.low.rank)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the numbers of the seats that won or tied for the high half of the pot.
+    #[must_use]
+    pub fn high_winners(&self) -> Vec<usize> {
+        let winning_rank = self.winning_rank();
+        self.iter()
+            .filter(|seat| seat.high.rank == winning_rank)
+            .map(|seat| seat.number)
+            .collect()
+    }
+
+    /// Returns the numbers of the seats that won or tied for the low half of the pot, which
+    /// is empty if nobody qualified for a low.
+    #[must_use]
+    pub fn low_winners(&self) -> Vec<usize> {
+        let winning_low = self.winning_low();
+        if winning_low.is_none() {
+            return Vec::new();
+        }
+        self.iter()
+            .filter(|seat| seat.low.rank == winning_low)
+            .map(|seat| seat.number)
+            .collect()
+    }
+
+    /// Returns the fraction of the pot that goes to the seat.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn share(&self, seat: usize) -> f32 {
+        let high = self.high_winners();
+        let low = self.low_winners();
+        let high_pot = if low.is_empty() { 1.0 } else { 0.5 };
+
+        let mut share = 0.0;
+        if high.contains(&seat) {
+            share += high_pot / high.len() as f32;
+        }
+        if low.contains(&seat) {
+            share += 0.5 / low.len() as f32;
+        }
+        share
+    }
+
+    #[must_use]
+    pub fn resolution(&self, seat: usize) -> PotResolution {
+        let share = self.share(seat);
+        if (share - 1.0).abs() < f32::EPSILON {
+            PotResolution::Scoop
+        } else if (share - 0.25).abs() < f32::EPSILON {
+            PotResolution::Quartered
+        } else if share > 0.0 {
+            PotResolution::Split
+        } else {
+            PotResolution::Lost
+        }
+    }
+
+    /// Returns the number of the seat that scooped the pot, if any.
+    #[must_use]
+    pub fn scooper(&self) -> Option<usize> {
+        self.iter()
+            .map(|seat| seat.number)
+            .find(|number| self.resolution(*number) == PotResolution::Scoop)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod hi_lo_case_eval_tests {
+    use super::*;
+    use crate::analysis::eval::Eval;
+    use crate::analysis::low::LowEval;
+    use crate::types::arrays::five_card::FiveCard;
+
+    fn seat(number: usize, high: &'static str, low: &'static str) -> SeatEval {
+        let low = if low.is_empty() {
+            LowEval::default()
+        } else {
+            LowEval::from(FiveCard::try_from(low).unwrap()).eight_or_better()
+        };
+        SeatEval::new(number, Eval::try_from(high).unwrap(), low)
+    }
+
+    #[test]
+    fn scoop() {
+        let mut case = CaseEval::default();
+        case.push(seat(0, "5♠ 4♥ 3♦ 2♣ A♠", "5♠ 4♥ 3♦ 2♣ A♠"));
+        case.push(seat(1, "K♠ K♥ 3♦ 2♣ A♠", "K♠ K♥ 3♦ 2♣ A♠"));
+
+        assert_eq!(vec![0], case.high_winners());
+        assert_eq!(vec![0], case.low_winners());
+        assert_eq!(1.0, case.share(0));
+        assert_eq!(PotResolution::Scoop, case.resolution(0));
+        assert_eq!(PotResolution::Lost, case.resolution(1));
+        assert_eq!(Some(0), case.scooper());
+    }
+
+    #[test]
+    fn scoop__no_low() {
+        let mut case = CaseEval::default();
+        case.push(seat(0, "K♠ K♥ K♦ 2♣ A♠", ""));
+        case.push(seat(1, "Q♠ Q♥ 3♦ 2♣ A♠", ""));
+
+        assert!(case.low_winners().is_empty());
+        assert_eq!(Some(0), case.scooper());
+        assert_eq!(0.0, case.share(1));
+    }
+
+    #[test]
+    fn split() {
+        let mut case = CaseEval::default();
+        case.push(seat(0, "K♠ K♥ K♦ 2♣ A♠", ""));
+        case.push(seat(1, "8♠ 6♥ 4♦ 2♣ A♠", "8♠ 6♥ 4♦ 2♣ A♠"));
+
+        assert_eq!(0.5, case.share(0));
+        assert_eq!(0.5, case.share(1));
+        assert_eq!(PotResolution::Split, case.resolution(0));
+        assert_eq!(PotResolution::Split, case.resolution(1));
+        assert_eq!(None, case.scooper());
+    }
+
+    #[test]
+    fn quartered() {
+        let mut case = CaseEval::default();
+        case.push(seat(0, "K♠ K♥ K♦ 2♣ A♠", "8♠ 6♥ 4♦ 2♣ A♠"));
+        case.push(seat(1, "Q♠ Q♥ 4♦ 2♣ A♠", "8♥ 6♦ 4♣ 2♠ A♥"));
+
+        assert_eq!(0.75, case.share(0));
+        assert_eq!(0.25, case.share(1));
+        assert_eq!(PotResolution::Split, case.resolution(0));
+        assert_eq!(PotResolution::Quartered, case.resolution(1));
+    }
+}
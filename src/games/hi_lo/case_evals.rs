@@ -0,0 +1,119 @@
+use crate::analysis::chances;
+use crate::analysis::seat_calc::SeatCalc;
+use crate::analysis::Evaluate;
+use crate::games::hi_lo::case_eval::{CaseEval, PotResolution};
+use crate::games::hi_lo::chances::Chances;
+use std::collections::BTreeMap;
+
+/// The split pot version of `holdem::case_evals::CaseEvals`, holding a `CaseEval` for every
+/// possible way the rest of the board can come out.
+#[derive(Clone, Debug, Default)]
+pub struct CaseEvals(Vec<CaseEval>);
+
+impl CaseEvals {
+    pub fn push(&mut self, case_eval: CaseEval) {
+        self.0.push(case_eval);
+    }
+
+    /// Returns, for every seat, the percentage of cases where they win or tie for the high,
+    /// win or tie for the low, scoop the whole pot, as well as their share of the pot across
+    /// all of the cases, which is their overall equity.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn chances(&self) -> Chances {
+        let mut high = SeatCalc::default();
+        let mut low = SeatCalc::default();
+        let mut scoop = SeatCalc::default();
+        let mut shares: BTreeMap<usize, f32> = BTreeMap::new();
+
+        for case in &self.0 {
+            for seat in case.iter() {
+                high.touch(seat.number);
+                low.touch(seat.number);
+                scoop.touch(seat.number);
+                *shares.entry(seat.number).or_default() += case.share(seat.number);
+                if case.resolution(seat.number) == PotResolution::Scoop {
+                    scoop.increment(seat.number);
+                }
+            }
+            for number in case.high_winners() {
+                high.increment(number);
+            }
+            for number in case.low_winners() {
+                low.increment(number);
+            }
+        }
+
+        let mut equity = chances::Chances::default();
+        for (seat, share) in shares {
+            equity.set(seat, Evaluate::percent(1, self.0.len()) * share);
+        }
+
+        Chances {
+            high: high.chances(self.0.len()),
+            low: low.chances(self.0.len()),
+            scoop: scoop.chances(self.0.len()),
+            equity,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod hi_lo_case_evals_tests {
+    use super::*;
+    use crate::analysis::eval::Eval;
+    use crate::analysis::low::LowEval;
+    use crate::games::hi_lo::seat_eval::SeatEval;
+    use crate::types::arrays::five_card::FiveCard;
+
+    fn low(index: &'static str) -> LowEval {
+        LowEval::from(FiveCard::try_from(index).unwrap())
+    }
+
+    #[test]
+    fn chances() {
+        let kings = Eval::try_from("K♠ K♥ K♦ 2♣ A♠").unwrap();
+        let queens = Eval::try_from("Q♠ Q♥ Q♦ 2♣ A♠").unwrap();
+
+        // Seat 0 scoops.
+        let mut scooped = CaseEval::default();
+        scooped.push(SeatEval::new(0, kings, low("8♠ 6♥ 4♦ 2♣ A♠")));
+        scooped.push(SeatEval::new(1, queens, LowEval::default()));
+
+        // Seat 0 takes the high and seat 1 the low.
+        let mut split = CaseEval::default();
+        split.push(SeatEval::new(0, kings, LowEval::default()));
+        split.push(SeatEval::new(1, queens, low("8♠ 6♥ 4♦ 2♣ A♠")));
+
+        let mut evals = CaseEvals::default();
+        evals.push(scooped);
+        evals.push(split);
+        let chances = evals.chances();
+
+        assert_eq!(100.0, chances.high.get(0));
+        assert_eq!(0.0, chances.high.get(1));
+        assert_eq!(50.0, chances.low.get(0));
+        assert_eq!(50.0, chances.low.get(1));
+        assert_eq!(50.0, chances.scoop.get(0));
+        assert_eq!(0.0, chances.scoop.get(1));
+        assert_eq!(75.0, chances.equity.get(0));
+        assert_eq!(25.0, chances.equity.get(1));
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances__empty() {
+        assert_eq!(0.0, CaseEvals::default().chances().equity.get(0));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::analysis::chances;
+
+/// Split pot `Chances`, broken out for every seat into how often they win the high half of
+/// the pot, the low half and the whole thing, along with their overall share of it.
+#[derive(Clone, Debug, Default)]
+pub struct Chances {
+    pub high: chances::Chances,
+    pub low: chances::Chances,
+    pub scoop: chances::Chances,
+    pub equity: chances::Chances,
+}
+
+impl Chances {
+    pub fn playout(&self) {
+        for k in self.equity.keys() {
+            println!(
+                "Seat {}: {:.1}% - High: {:.1}% Low: {:.1}% Scoop: {:.1}%",
+                k,
+                self.equity.get(*k),
+                self.high.get(*k),
+                self.low.get(*k),
+                self.scoop.get(*k)
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod case_eval;
+pub mod case_evals;
+pub mod chances;
+pub mod seat_eval;
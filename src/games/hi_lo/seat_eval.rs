@@ -0,0 +1,52 @@
+use crate::analysis::eval::Eval;
+use crate::analysis::low::LowEval;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The split pot version of `holdem::seat_eval::SeatEval`, holding both the best high hand and
+/// the best qualifying low hand for a seat, for games like Omaha Hi/Lo.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SeatEval {
+    pub number: usize,
+    pub high: Eval,
+    pub low: LowEval,
+}
+
+impl SeatEval {
+    #[must_use]
+    pub fn new(number: usize, high: Eval, low: LowEval) -> SeatEval {
+        SeatEval { number, high, low }
+    }
+}
+
+impl fmt::Display for SeatEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Seat #{}: HIGH {} - LOW {}",
+            self.number, self.high.hand, self.low.rank
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod hi_lo_seat_eval_tests {
+    use super::*;
+    use crate::types::arrays::five_card::FiveCard;
+
+    #[test]
+    fn display() {
+        let high = Eval::try_from("K♥ K♣ 6♥ 6♦ A♦").unwrap();
+        let low = LowEval::from(FiveCard::try_from("8♠ 6♥ 4♦ 2♣ A♠").unwrap());
+
+        assert_eq!(
+            "Seat #1: HIGH K♥ K♣ 6♥ 6♦ A♦ - LOW 8-6-4-2-A",
+            SeatEval::new(1, high, low).to_string()
+        );
+        assert_eq!(
+            "Seat #2: HIGH K♥ K♣ 6♥ 6♦ A♦ - LOW No Low",
+            SeatEval::new(2, high, LowEval::default()).to_string()
+        );
+    }
+}
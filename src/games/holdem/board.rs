@@ -1,4 +1,5 @@
 use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::slots::flop::Flop;
@@ -8,6 +9,7 @@ use ckc_rs::hand_rank::HandRank;
 use ckc_rs::HandError;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use wyz::FmtForward;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -24,7 +26,7 @@ impl Board {
     /// # Errors
     ///
     /// Will return a `HandError::InvalidCard` error if an invalid index is passed in.
-    pub fn from_index(index: &'static str) -> Result<Board, HandError> {
+    pub fn from_index(index: &str) -> Result<Board, HandError> {
         Board::try_from(index)
     }
 
@@ -102,27 +104,30 @@ impl Display for Board {
 /// # Errors
 ///
 /// Will return a `HandError::InvalidCard` error if an invalid index is passed in.
-impl TryFrom<&'static str> for Board {
+impl TryFrom<&str> for Board {
     type Error = HandError;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
-        let poker_cards = PlayingCards::try_from(value);
-
-        // if poker_cards.is_err() {
-        //     Err(poker_cards.unwrap_err())
-        // } else {
-        //     let cards = poker_cards.unwrap();
-        //     let board = Board::default();
-        //     for card in cards.iter(){
-        //         board.take(PlayingCard::from(card));
-        //     }
-        //     Ok(board)
-        // }
-
-        match poker_cards {
-            Ok(cards) => Ok(Board::from(cards)),
-            Err(e) => Err(e),
-        }
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Board::from(PlayingCards::try_from(value)?))
+    }
+}
+
+impl TryFrom<String> for Board {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Board::try_from(value.as_str())
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index.
+impl FromStr for Board {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Board::from(PlayingCards::from_str(s)?))
     }
 }
 
@@ -193,6 +198,29 @@ mod holdem_board_tests {
         assert_eq!("4♥ K♣ 5♥ 3♠ 8♦", board.to_playing_cards().to_string());
     }
 
+    #[test]
+    fn try_from__string() {
+        let index = String::from("K♣ 5♥ 3♠ 8♦ 9♠");
+
+        let board = Board::try_from(index).unwrap();
+
+        assert_eq!("K♣ 5♥ 3♠ 8♦ 9♠", board.to_playing_cards().to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        let board: Board = "K♣ 5♥ 3♠ 8♦ 9♠".parse().unwrap();
+
+        assert!(board.is_dealt());
+    }
+
+    #[test]
+    fn from_str__invalid_card() {
+        let board = Board::from_str("9♥ 9♦ 2♦ 5♠ JK");
+
+        assert_eq!(IndexError::invalid_card("JK", 4), board.unwrap_err());
+    }
+
     #[test]
     fn from_poker_cards() {
         let poker_cards = PlayingCards::try_from("A♠ A♥ Q♠ J♠ T♠").unwrap();
@@ -16,7 +16,10 @@ impl CaseEvals {
 
     #[must_use]
     pub fn chances(&self) -> Chances {
-        let mut calc = self.primed_seat_calc();
+        let mut calc = match self.0.first() {
+            Some(case) => SeatCalc::primed(case.seats().iter().map(|seat| seat.seat.number)),
+            None => SeatCalc::default(),
+        };
         for case in &self.0 {
             for winner in case.winners().iter() {
                 calc.increment(winner.seat.number);
@@ -46,18 +49,4 @@ impl CaseEvals {
         }
         winners
     }
-
-    /// Prime the calculations so that zero chance seats aren't excluded.
-    fn primed_seat_calc(&self) -> SeatCalc {
-        match self.0.first() {
-            Some(case) => {
-                let mut calc = SeatCalc::default();
-                for seat in case.seats() {
-                    calc.touch(seat.seat.number);
-                }
-                calc
-            }
-            None => SeatCalc::default(),
-        }
-    }
 }
@@ -151,6 +151,12 @@ impl Deal {
         }
     }
 
+    /// Places a card that's out of play, such as one a player throws away, in the burned
+    /// collection of `PlayingCards`.
+    pub fn muck(&mut self, card: PlayingCard) {
+        self.burned.insert(card);
+    }
+
     pub fn number_of_players(&self) -> usize {
         self.table.players.len()
     }
@@ -292,6 +298,18 @@ mod holdem_deal_tests {
         assert_eq!(flop, deal.get_flop());
     }
 
+    #[test]
+    fn muck() {
+        let mut deal = Deal::default();
+        deal.deal(2);
+        let card = deal.peak(0).unwrap().draw_one();
+
+        deal.muck(card);
+
+        assert_eq!(deal.burned.len(), 2);
+        assert!(deal.burned.contains(&card));
+    }
+
     #[test]
     fn hand_by_seat() {
         let index_string = "2S 3D QS 3S QD KH 3C TC 9H 3H 6H 4H 2H 5S 6D 9S AD 5C 7S JS AC 6S 8H 7C JC 7H JD TS AS KS JH 5D 6C 9C QC 8D 4C 5H 4D 8S 2C AH 2D 9D TH KD 7D KC 4S 8C QH TD";
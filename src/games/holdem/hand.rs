@@ -96,8 +96,8 @@ impl fmt::Display for Hand {
     }
 }
 
-impl From<&'static str> for Hand {
-    fn from(s: &'static str) -> Self {
+impl From<&str> for Hand {
+    fn from(s: &str) -> Self {
         let v: Vec<&str> = s.split_whitespace().collect();
         if v.len() == 2 {
             Hand::new(
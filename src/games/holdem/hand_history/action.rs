@@ -0,0 +1,122 @@
+use crate::games::holdem::hand_history::street::Street;
+use crate::games::holdem::hand_history::HandHistory;
+use crate::types::playing_cards::PlayingCards;
+use std::fmt;
+
+/// What a player did, as recorded on a single line of a hand history.
+///
+/// Amounts are in chips. For `Call` and `Raise::by` the amount is what was added to the
+/// pot with the action, while `Raise::to` is the player's total bet for the street.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Move {
+    PostSmallBlind(usize),
+    PostBigBlind(usize),
+    Fold,
+    Check,
+    Call(usize),
+    Bet(usize),
+    Raise { by: usize, to: usize },
+    Show(PlayingCards),
+}
+
+impl Move {
+    /// Chips put into the pot by the move.
+    #[must_use]
+    pub fn amount(&self) -> usize {
+        match self {
+            Move::PostSmallBlind(amount)
+            | Move::PostBigBlind(amount)
+            | Move::Call(amount)
+            | Move::Bet(amount)
+            | Move::Raise { by: amount, .. } => *amount,
+            Move::Fold | Move::Check | Move::Show(_) => 0,
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::PostSmallBlind(amount) => write!(f, "posts small blind {amount}"),
+            Move::PostBigBlind(amount) => write!(f, "posts big blind {amount}"),
+            Move::Fold => write!(f, "folds"),
+            Move::Check => write!(f, "checks"),
+            Move::Call(amount) => write!(f, "calls {amount}"),
+            Move::Bet(amount) => write!(f, "bets {amount}"),
+            Move::Raise { by, to } => write!(f, "raises {by} to {to}"),
+            Move::Show(cards) => write!(f, "shows [{}]", HandHistory::cards_to_string(cards)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Action {
+    pub street: Street,
+    pub player: String,
+    pub action: Move,
+    pub all_in: bool,
+}
+
+impl Action {
+    #[must_use]
+    pub fn new(street: Street, player: &str, action: Move, all_in: bool) -> Action {
+        Action {
+            street,
+            player: player.to_string(),
+            action,
+            all_in,
+        }
+    }
+
+    #[must_use]
+    pub fn is_fold(&self) -> bool {
+        self.action == Move::Fold
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.all_in {
+            write!(f, "{}: {} and is all-in", self.player, self.action)
+        } else {
+            write!(f, "{}: {}", self.player, self.action)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod holdem_hand_history_action_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Move::PostSmallBlind(50), 50)]
+    #[case(Move::PostBigBlind(100), 100)]
+    #[case(Move::Fold, 0)]
+    #[case(Move::Check, 0)]
+    #[case(Move::Call(160), 160)]
+    #[case(Move::Bet(230), 230)]
+    #[case(Move::Raise { by: 110, to: 210 }, 110)]
+    fn amount(#[case] action: Move, #[case] expected: usize) {
+        assert_eq!(expected, action.amount());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "MrPink: raises 110 to 210",
+            Action::new(
+                Street::Preflop,
+                "MrPink",
+                Move::Raise { by: 110, to: 210 },
+                false
+            )
+            .to_string()
+        );
+        assert_eq!(
+            "MrBlue: calls 9900 and is all-in",
+            Action::new(Street::Flop, "MrBlue", Move::Call(9900), true).to_string()
+        );
+    }
+}
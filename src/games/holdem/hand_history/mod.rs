@@ -0,0 +1,998 @@
+pub mod action;
+pub mod player;
+pub mod street;
+
+use crate::games::holdem::board::Board;
+use crate::games::holdem::deal::Deal;
+use crate::games::holdem::hand_history::action::{Action, Move};
+use crate::games::holdem::hand_history::player::Player;
+use crate::games::holdem::hand_history::street::Street;
+use crate::games::holdem::seats::Seats;
+use crate::games::holdem::table::Table;
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::hand_rank::HandRank;
+use ckc_rs::{CKCNumber, PokerCard};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+
+/// A single hand parsed from a `PokerStars` formatted hand history, such as the ones
+/// under `data/pluribus_converted_logs`.
+///
+/// ```txt
+/// PokerStars Hand #100000: Hold'em No Limit (50/100) - 2019/07/12 03:46:40 ET
+/// Table 'Pluribus Session 100' 6-max (Play Money) Seat #6 is the button
+/// Seat 1: MrBlue (10000 in chips)
+/// ...
+/// ```
+///
+/// The `players` are kept in the order that they are listed in the history, which is
+/// the order used for the `Seats` of any `Table` built from the hand.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HandHistory {
+    pub id: u64,
+    pub game: String,
+    pub small_blind: usize,
+    pub big_blind: usize,
+    pub timestamp: String,
+    pub table_name: String,
+    pub max_seats: usize,
+    pub play_money: bool,
+    pub button: usize,
+    pub players: Vec<Player>,
+    pub actions: Vec<Action>,
+    pub board: PlayingCards,
+    pub showdown: bool,
+    pub uncalled_bet: Option<(String, usize)>,
+    pub collected: Vec<(String, f32)>,
+    pub total_pot: usize,
+    pub rake: usize,
+}
+
+impl HandHistory {
+    pub const HEADER: &'static str = "PokerStars Hand #";
+    pub const DEFAULT_GAME: &'static str = "Hold'em No Limit";
+    pub const DEFAULT_SMALL_BLIND: usize = 50;
+    pub const DEFAULT_BIG_BLIND: usize = 100;
+    pub const DEFAULT_STACK: usize = 10_000;
+    pub const DEFAULT_TABLE_NAME: &'static str = "fudd";
+    pub const DEFAULT_TIMESTAMP: &'static str = "1970/01/01 00:00:00 ET";
+
+    /// Creates a `HandHistory` from a `Deal`, with the passed in names and stacks for each
+    /// of its `Seats`, and the actions taken during the hand.
+    ///
+    /// The button is the last seat, so the first two seats are the blinds. Players without
+    /// a name or stack are given `PlayerN` and `HandHistory::DEFAULT_STACK`. Any seat that
+    /// folded in the `Deal` is recorded as folding preflop if the actions don't already
+    /// have them folding.
+    ///
+    /// If the `Deal` has reached the river and more than one player is left, they show
+    /// their cards and the pot is split between the winners. If only one player is left,
+    /// they collect the pot without a showdown.
+    #[must_use]
+    pub fn from_deal(deal: &Deal, players: &[(&str, usize)], actions: Vec<Action>) -> HandHistory {
+        let mut history = HandHistory {
+            game: HandHistory::DEFAULT_GAME.to_string(),
+            small_blind: HandHistory::DEFAULT_SMALL_BLIND,
+            big_blind: HandHistory::DEFAULT_BIG_BLIND,
+            timestamp: HandHistory::DEFAULT_TIMESTAMP.to_string(),
+            table_name: HandHistory::DEFAULT_TABLE_NAME.to_string(),
+            max_seats: deal.number_of_players(),
+            play_money: true,
+            button: deal.number_of_players(),
+            actions,
+            board: deal.table.board.to_playing_cards(),
+            ..HandHistory::default()
+        };
+
+        for seat in deal.table.players.iter() {
+            let (name, chips) = match players.get(seat.number) {
+                Some((name, chips)) => ((*name).to_string(), *chips),
+                None => (
+                    format!("Player{}", seat.number + 1),
+                    HandHistory::DEFAULT_STACK,
+                ),
+            };
+            let mut player = Player::new(seat.number + 1, &name, chips);
+            player.hole_cards = seat.hole_cards.clone();
+            if seat.did_fold() && !history.did_fold(&name) {
+                history
+                    .actions
+                    .push(Action::new(Street::Preflop, &name, Move::Fold, false));
+            }
+            history.players.push(player);
+        }
+
+        history.total_pot = history.pot();
+        history.showdown();
+        history
+    }
+
+    /// Writes the hands to a file, in the same format as the logs under
+    /// `data/pluribus_converted_logs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written to.
+    pub fn write(path: &str, hands: &[HandHistory]) -> Result<(), Box<dyn Error>> {
+        let text = hands
+            .iter()
+            .map(HandHistory::to_string)
+            .collect::<Vec<String>>()
+            .join("\n\n\n");
+        fs::write(path, text + "\n")?;
+        Ok(())
+    }
+
+    /// Parses the text of a single hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandHistoryError` describing the first line that couldn't be understood.
+    pub fn parse(text: &str) -> Result<HandHistory, HandHistoryError> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let mut history = HandHistory::default();
+        match lines.next() {
+            Some(line) => history.parse_header(line)?,
+            None => return Err(HandHistoryError::Empty),
+        }
+        match lines.next() {
+            Some(line) => history.parse_table(line)?,
+            None => return Err(HandHistoryError::Empty),
+        }
+
+        let mut street = Street::Preflop;
+        let mut summary = false;
+        for line in lines {
+            if let Some(marker) = line.strip_prefix("*** ") {
+                match HandHistory::parse_marker(marker)? {
+                    Some(next) => {
+                        street = next;
+                        history.showdown |= street == Street::Showdown;
+                        if street.board_len() > history.board.len() {
+                            history.board.append(&HandHistory::parse_last_cards(line)?);
+                        }
+                    }
+                    None => summary = true,
+                }
+            } else if summary {
+                history.parse_summary(line)?;
+            } else {
+                history.parse_line(street, line)?;
+            }
+        }
+
+        Ok(history)
+    }
+
+    /// Parses every hand in a block of text. Hands are split on their
+    /// `PokerStars Hand #` header lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `HandHistoryError` of the first hand that fails to parse.
+    pub fn parse_all(text: &str) -> Result<Vec<HandHistory>, HandHistoryError> {
+        let mut hands: Vec<String> = Vec::new();
+        for line in text.lines() {
+            if line.starts_with(HandHistory::HEADER) || hands.is_empty() {
+                hands.push(String::new());
+            }
+            if let Some(hand) = hands.last_mut() {
+                hand.push_str(line);
+                hand.push('\n');
+            }
+        }
+        hands
+            .iter()
+            .filter(|hand| !hand.trim().is_empty())
+            .map(|hand| HandHistory::parse(hand))
+            .collect()
+    }
+
+    /// Reads and parses every hand in a hand history file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or any of its hands can't be parsed.
+    pub fn read(path: &str) -> Result<Vec<HandHistory>, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        Ok(HandHistory::parse_all(&text)?)
+    }
+
+    //region getters
+
+    #[must_use]
+    pub fn actions_on(&self, street: Street) -> Vec<&Action> {
+        self.actions
+            .iter()
+            .filter(|action| action.street == street)
+            .collect()
+    }
+
+    /// The community cards as they stood on the passed in `Street`.
+    #[must_use]
+    pub fn board_at(&self, street: Street) -> Board {
+        Board::from(
+            self.board
+                .iter()
+                .take(street.board_len())
+                .copied()
+                .collect::<Vec<PlayingCard>>(),
+        )
+    }
+
+    /// Returns true if the player folded at any point in the hand.
+    #[must_use]
+    pub fn did_fold(&self, name: &str) -> bool {
+        self.actions
+            .iter()
+            .any(|action| action.player == name && action.is_fold())
+    }
+
+    #[must_use]
+    pub fn player(&self, name: &str) -> Option<&Player> {
+        self.players.iter().find(|player| player.name == name)
+    }
+
+    /// Returns the index of the player's `Seat` in a `Table` built from the hand.
+    #[must_use]
+    pub fn seat_number(&self, name: &str) -> Option<usize> {
+        self.players.iter().position(|player| player.name == name)
+    }
+
+    /// The last `Street` that the hand got to.
+    #[must_use]
+    pub fn street(&self) -> Street {
+        match self.actions.last() {
+            Some(action) => action.street.max(HandHistory::street_for(self.board.len())),
+            None => HandHistory::street_for(self.board.len()),
+        }
+    }
+
+    /// Returns a `Table` with every player's `HoleCards` and the complete `Board`.
+    #[must_use]
+    pub fn table(&self) -> Table {
+        self.table_at(Street::River)
+    }
+
+    /// Returns a `Table` with every player's `HoleCards` and the `Board` as it was on
+    /// the passed in `Street`.
+    #[must_use]
+    pub fn table_at(&self, street: Street) -> Table {
+        let mut players = Seats::default();
+        for player in &self.players {
+            players.add(player.hole_cards.clone());
+        }
+        Table {
+            players,
+            board: self.board_at(street),
+            dead: PlayingCards::default(),
+        }
+    }
+
+    /// Chips put into the pot by every action, less any uncalled bet.
+    #[must_use]
+    pub fn pot(&self) -> usize {
+        let uncalled = match &self.uncalled_bet {
+            Some((_, amount)) => *amount,
+            None => 0,
+        };
+        self.actions
+            .iter()
+            .map(|action| action.action.amount())
+            .sum::<usize>()
+            .saturating_sub(uncalled)
+    }
+
+    #[must_use]
+    pub fn winners(&self) -> Vec<&str> {
+        self.collected
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    //endregion
+
+    /// Formats cards the way that `PokerStars` does, e.g. `Tc Qc`.
+    #[must_use]
+    pub fn cards_to_string(cards: &PlayingCards) -> String {
+        cards
+            .iter()
+            .map(|card| {
+                format!(
+                    "{}{}",
+                    card.get_rank_char(),
+                    card.get_suit_letter().to_ascii_lowercase()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    //region writing
+
+    /// Players who were dealt in and haven't folded.
+    fn contenders(&self) -> Vec<&Player> {
+        self.players
+            .iter()
+            .filter(|player| player.hole_cards.is_dealt() && !self.did_fold(&player.name))
+            .collect()
+    }
+
+    /// Determines who collects the pot for a hand that doesn't have a result yet.
+    #[allow(clippy::cast_precision_loss)]
+    fn showdown(&mut self) {
+        let contenders = self.contenders();
+        if contenders.len() == 1 {
+            let name = contenders[0].name.clone();
+            self.collected.push((name, self.total_pot as f32));
+            return;
+        }
+        if self.board.len() < 5 {
+            return;
+        }
+
+        let mut shows: Vec<Action> = Vec::new();
+        let mut winners: Vec<String> = Vec::new();
+        let mut best = HandRank::default();
+        for player in contenders {
+            let cards = player.hole_cards.to_playing_cards();
+            let rank = match cards.combine(&self.board).eval_7cards() {
+                Ok(eval) => eval.rank,
+                Err(_) => continue,
+            };
+            shows.push(Action::new(
+                Street::Showdown,
+                &player.name,
+                Move::Show(cards),
+                false,
+            ));
+            match rank.cmp(&best) {
+                Ordering::Greater => winners = vec![player.name.clone()],
+                Ordering::Equal => winners.push(player.name.clone()),
+                Ordering::Less => {}
+            }
+            best = best.max(rank);
+        }
+
+        self.showdown = true;
+        self.actions.append(&mut shows);
+        if !winners.is_empty() {
+            let share = self.total_pot as f32 / winners.len() as f32;
+            for winner in winners {
+                self.collected.push((winner, share));
+            }
+        }
+    }
+
+    fn fmt_street(&self, f: &mut fmt::Formatter<'_>, street: Street) -> fmt::Result {
+        for action in self.actions_on(street) {
+            if !matches!(
+                action.action,
+                Move::PostSmallBlind(_) | Move::PostBigBlind(_)
+            ) {
+                writeln!(f, "{action}")?;
+            }
+        }
+        if let Some((name, amount)) = &self.uncalled_bet {
+            if self.last_betting_street() == street {
+                writeln!(f, "Uncalled bet ({amount}) returned to {name}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The `Street` of the last action that wasn't a player showing their cards.
+    fn last_betting_street(&self) -> Street {
+        self.actions
+            .iter()
+            .rev()
+            .find(|action| action.street != Street::Showdown)
+            .map_or(Street::Preflop, |action| action.street)
+    }
+
+    fn fmt_summary(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "*** SUMMARY ***")?;
+        write!(f, "Total pot {} | Rake {}", self.total_pot, self.rake)?;
+        if !self.board.is_empty() {
+            write!(f, "\nBoard [{}]", HandHistory::cards_to_string(&self.board))?;
+        }
+        if !self.showdown {
+            return Ok(());
+        }
+        // Everyone still in the hand is listed as having shown, even if they mucked, but
+        // only the players that actually showed are listed as having won.
+        let shown: Vec<&str> = self
+            .actions_on(Street::Showdown)
+            .iter()
+            .map(|action| action.player.as_str())
+            .collect();
+        for player in self.contenders() {
+            write!(
+                f,
+                "\nSeat {}: {} showed [{}] and ",
+                player.seat,
+                player.name,
+                HandHistory::cards_to_string(&player.hole_cards.to_playing_cards())
+            )?;
+            match self.collected.iter().find(|(name, _)| *name == player.name) {
+                Some((_, amount)) if shown.contains(&player.name.as_str()) => {
+                    write!(f, "won ({amount:.1})")?;
+                }
+                _ => write!(f, "lost")?,
+            }
+        }
+        Ok(())
+    }
+
+    //endregion
+
+    //region parsing
+
+    fn parse_header(&mut self, line: &str) -> Result<(), HandHistoryError> {
+        let invalid = || HandHistoryError::InvalidHeader(line.to_string());
+
+        let (id, rest) = line
+            .strip_prefix(HandHistory::HEADER)
+            .and_then(|s| s.split_once(": "))
+            .ok_or_else(invalid)?;
+        let (game, rest) = rest.split_once(" (").ok_or_else(invalid)?;
+        let (blinds, timestamp) = rest.split_once(") - ").ok_or_else(invalid)?;
+        let (small_blind, big_blind) = blinds.split_once('/').ok_or_else(invalid)?;
+
+        self.id = id.parse().map_err(|_| invalid())?;
+        self.game = game.to_string();
+        self.small_blind = small_blind.parse().map_err(|_| invalid())?;
+        self.big_blind = big_blind.parse().map_err(|_| invalid())?;
+        self.timestamp = timestamp.to_string();
+        Ok(())
+    }
+
+    fn parse_table(&mut self, line: &str) -> Result<(), HandHistoryError> {
+        let invalid = || HandHistoryError::InvalidHeader(line.to_string());
+
+        let (name, rest) = line
+            .strip_prefix("Table '")
+            .and_then(|s| s.split_once("' "))
+            .ok_or_else(invalid)?;
+        let (max_seats, rest) = rest.split_once("-max").ok_or_else(invalid)?;
+        let (_, button) = rest.split_once("Seat #").ok_or_else(invalid)?;
+        let button = button.strip_suffix(" is the button").ok_or_else(invalid)?;
+
+        self.table_name = name.to_string();
+        self.max_seats = max_seats.parse().map_err(|_| invalid())?;
+        self.play_money = rest.contains("(Play Money)");
+        self.button = button.parse().map_err(|_| invalid())?;
+        Ok(())
+    }
+
+    /// Returns the `Street` that a `*** MARKER ***` line starts, or `None` for the summary.
+    fn parse_marker(marker: &str) -> Result<Option<Street>, HandHistoryError> {
+        let name = marker.split(" ***").next().unwrap_or_default();
+        match name {
+            "HOLE CARDS" => Ok(Some(Street::Preflop)),
+            "FLOP" => Ok(Some(Street::Flop)),
+            "TURN" => Ok(Some(Street::Turn)),
+            "RIVER" => Ok(Some(Street::River)),
+            "SHOWDOWN" => Ok(Some(Street::Showdown)),
+            "SUMMARY" => Ok(None),
+            _ => Err(HandHistoryError::InvalidLine(format!("*** {marker}"))),
+        }
+    }
+
+    fn parse_line(&mut self, street: Street, line: &str) -> Result<(), HandHistoryError> {
+        let invalid = || HandHistoryError::InvalidLine(line.to_string());
+
+        if let Some(rest) = line.strip_prefix("Seat ") {
+            let (seat, rest) = rest.split_once(": ").ok_or_else(invalid)?;
+            let (name, chips) = rest.rsplit_once(" (").ok_or_else(invalid)?;
+            let chips = chips.strip_suffix(" in chips)").ok_or_else(invalid)?;
+            self.players.push(Player::new(
+                seat.parse().map_err(|_| invalid())?,
+                name,
+                chips.parse().map_err(|_| invalid())?,
+            ));
+        } else if let Some(rest) = line.strip_prefix("Dealt to ") {
+            let (name, _) = rest.split_once(" [").ok_or_else(invalid)?;
+            let cards = HandHistory::parse_last_cards(line)?;
+            let player = self
+                .players
+                .iter()
+                .find(|player| player.name == name)
+                .ok_or_else(|| HandHistoryError::UnknownPlayer(name.to_string()))?;
+            for card in cards.iter() {
+                player.hole_cards.take(*card);
+            }
+        } else if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            let (amount, name) = rest.split_once(") returned to ").ok_or_else(invalid)?;
+            self.uncalled_bet = Some((name.to_string(), amount.parse().map_err(|_| invalid())?));
+        } else if let Some((name, amount)) = line.split_once(" collected ") {
+            let amount = amount.strip_suffix(" from pot").ok_or_else(invalid)?;
+            self.collected
+                .push((name.to_string(), amount.parse().map_err(|_| invalid())?));
+        } else if let Some((name, rest)) = line.split_once(": ") {
+            if self.player(name).is_none() {
+                return Err(HandHistoryError::UnknownPlayer(name.to_string()));
+            }
+            let (rest, all_in) = match rest.strip_suffix(" and is all-in") {
+                Some(rest) => (rest, true),
+                None => (rest, false),
+            };
+            let action = HandHistory::parse_move(rest).ok_or_else(invalid)?;
+            self.actions.push(Action::new(street, name, action, all_in));
+        } else {
+            return Err(invalid());
+        }
+        Ok(())
+    }
+
+    fn parse_move(text: &str) -> Option<Move> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        match words.as_slice() {
+            ["folds"] => Some(Move::Fold),
+            ["checks"] => Some(Move::Check),
+            ["calls", amount] => Some(Move::Call(amount.parse().ok()?)),
+            ["bets", amount] => Some(Move::Bet(amount.parse().ok()?)),
+            ["raises", by, "to", to] => Some(Move::Raise {
+                by: by.parse().ok()?,
+                to: to.parse().ok()?,
+            }),
+            ["posts", "small", "blind", amount] => Some(Move::PostSmallBlind(amount.parse().ok()?)),
+            ["posts", "big", "blind", amount] => Some(Move::PostBigBlind(amount.parse().ok()?)),
+            ["shows", ..] => HandHistory::parse_last_cards(text).ok().map(Move::Show),
+            _ => None,
+        }
+    }
+
+    fn parse_summary(&mut self, line: &str) -> Result<(), HandHistoryError> {
+        let invalid = || HandHistoryError::InvalidLine(line.to_string());
+
+        if let Some(rest) = line.strip_prefix("Total pot ") {
+            let (pot, rake) = rest.split_once(" | Rake ").ok_or_else(invalid)?;
+            self.total_pot = pot.parse().map_err(|_| invalid())?;
+            self.rake = rake.parse().map_err(|_| invalid())?;
+        }
+        // The remaining summary lines repeat what was already recorded for the hand.
+        Ok(())
+    }
+
+    /// Parses the cards inside of the last set of brackets on a line, so that
+    /// `*** TURN *** [7d 5h 9d] [7c]` returns just the `7c`.
+    fn parse_last_cards(line: &str) -> Result<PlayingCards, HandHistoryError> {
+        let invalid = || HandHistoryError::InvalidLine(line.to_string());
+
+        let (_, rest) = line.rsplit_once('[').ok_or_else(invalid)?;
+        let (index, _) = rest.split_once(']').ok_or_else(invalid)?;
+
+        let mut cards = PlayingCards::default();
+        for token in index.split_whitespace() {
+            let card = PlayingCard::from(CKCNumber::from_index(token));
+            if card.is_blank() || !cards.insert(card) {
+                return Err(HandHistoryError::InvalidCard(token.to_string()));
+            }
+        }
+        Ok(cards)
+    }
+
+    fn street_for(board_len: usize) -> Street {
+        match board_len {
+            0..=2 => Street::Preflop,
+            3 => Street::Flop,
+            4 => Street::Turn,
+            _ => Street::River,
+        }
+    }
+
+    //endregion
+}
+
+/// Writes the hand in the same `PokerStars` format that it's parsed from.
+impl fmt::Display for HandHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}{}: {} ({}/{}) - {}",
+            HandHistory::HEADER,
+            self.id,
+            self.game,
+            self.small_blind,
+            self.big_blind,
+            self.timestamp
+        )?;
+        writeln!(
+            f,
+            "Table '{}' {}-max {}Seat #{} is the button",
+            self.table_name,
+            self.max_seats,
+            if self.play_money { "(Play Money) " } else { "" },
+            self.button
+        )?;
+        for player in &self.players {
+            writeln!(f, "{player}")?;
+        }
+        for action in self.actions_on(Street::Preflop) {
+            if matches!(
+                action.action,
+                Move::PostSmallBlind(_) | Move::PostBigBlind(_)
+            ) {
+                writeln!(f, "{action}")?;
+            }
+        }
+
+        writeln!(f, "*** HOLE CARDS ***")?;
+        for player in &self.players {
+            if player.hole_cards.is_dealt() {
+                writeln!(
+                    f,
+                    "Dealt to {} [{}]",
+                    player.name,
+                    HandHistory::cards_to_string(&player.hole_cards.to_playing_cards())
+                )?;
+            }
+        }
+        self.fmt_street(f, Street::Preflop)?;
+
+        let mut dealt = 0;
+        let mut shown: Vec<String> = Vec::new();
+        for street in [Street::Flop, Street::Turn, Street::River] {
+            if self.board.len() < street.board_len() {
+                break;
+            }
+            let mut cards = PlayingCards::default();
+            for card in self.board.iter().take(street.board_len()).skip(dealt) {
+                cards.insert(*card);
+            }
+            dealt = street.board_len();
+            shown.push(format!("[{}]", HandHistory::cards_to_string(&cards)));
+
+            writeln!(
+                f,
+                "*** {} *** {}",
+                street.to_string().to_uppercase(),
+                shown.join(" ")
+            )?;
+            self.fmt_street(f, street)?;
+        }
+
+        if self.showdown {
+            writeln!(f, "*** SHOWDOWN ***")?;
+            self.fmt_street(f, Street::Showdown)?;
+        }
+        for (name, amount) in &self.collected {
+            writeln!(f, "{name} collected {amount:.1} from pot")?;
+        }
+        self.fmt_summary(f)
+    }
+}
+
+impl From<&Deal> for HandHistory {
+    /// Creates a `HandHistory` with default names and stacks, where the only actions are
+    /// the blinds being posted.
+    fn from(deal: &Deal) -> Self {
+        let mut actions = Vec::new();
+        if deal.number_of_players() > 1 {
+            actions.push(Action::new(
+                Street::Preflop,
+                "Player1",
+                Move::PostSmallBlind(HandHistory::DEFAULT_SMALL_BLIND),
+                false,
+            ));
+            actions.push(Action::new(
+                Street::Preflop,
+                "Player2",
+                Move::PostBigBlind(HandHistory::DEFAULT_BIG_BLIND),
+                false,
+            ));
+        }
+        HandHistory::from_deal(deal, &[], actions)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HandHistoryError {
+    Empty,
+    InvalidCard(String),
+    InvalidHeader(String),
+    InvalidLine(String),
+    UnknownPlayer(String),
+}
+
+impl fmt::Display for HandHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandHistoryError::Empty => write!(f, "empty hand history"),
+            HandHistoryError::InvalidCard(card) => write!(f, "invalid card: {card}"),
+            HandHistoryError::InvalidHeader(line) => write!(f, "invalid header: {line}"),
+            HandHistoryError::InvalidLine(line) => write!(f, "invalid line: {line}"),
+            HandHistoryError::UnknownPlayer(name) => write!(f, "unknown player: {name}"),
+        }
+    }
+}
+
+impl Error for HandHistoryError {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod holdem_hand_history_tests {
+    use super::*;
+    use crate::games::holdem::hand_history::street::Street;
+
+    const HAND: &str = "PokerStars Hand #100000: Hold'em No Limit (50/100) - 2019/07/12 03:46:40 ET
+Table 'Pluribus Session 100' 6-max (Play Money) Seat #6 is the button
+Seat 1: MrBlue (10000 in chips)
+Seat 2: MrBlonde (10000 in chips)
+Seat 3: MrWhite (10000 in chips)
+Seat 4: MrPink (10000 in chips)
+Seat 5: MrBrown (10000 in chips)
+Seat 6: Pluribus (10000 in chips)
+MrBlue: posts small blind 50
+MrBlonde: posts big blind 100
+*** HOLE CARDS ***
+Dealt to MrBlue [Tc Qc]
+Dealt to MrBlonde [8s 4c]
+Dealt to MrWhite [9c 3d]
+Dealt to MrPink [Ah 4h]
+Dealt to MrBrown [Th 5s]
+Dealt to Pluribus [6c 7s]
+MrWhite: folds
+MrPink: raises 110 to 210
+MrBrown: folds
+Pluribus: folds
+MrBlue: calls 160
+MrBlonde: folds
+*** FLOP *** [7d 5h 9d]
+MrBlue: checks
+MrPink: checks
+*** TURN *** [7d 5h 9d] [7c]
+MrBlue: checks
+MrPink: checks
+*** RIVER *** [7d 5h 9d] [7c] [Qh]
+MrBlue: bets 230
+MrPink: folds
+Uncalled bet (230) returned to MrBlue
+MrBlue collected 520.0 from pot
+*** SUMMARY ***
+Total pot 520 | Rake 0
+Board [7d 5h 9d 7c Qh]
+";
+
+    #[test]
+    fn parse() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        assert_eq!(100000, hand.id);
+        assert_eq!("Hold'em No Limit", hand.game);
+        assert_eq!(50, hand.small_blind);
+        assert_eq!(100, hand.big_blind);
+        assert_eq!("2019/07/12 03:46:40 ET", hand.timestamp);
+        assert_eq!("Pluribus Session 100", hand.table_name);
+        assert_eq!(6, hand.max_seats);
+        assert!(hand.play_money);
+        assert_eq!(6, hand.button);
+        assert_eq!(6, hand.players.len());
+        assert_eq!(14, hand.actions.len());
+        assert_eq!("7♦ 5♥ 9♦ 7♣ Q♥", hand.board.to_string());
+        assert_eq!(Some(("MrBlue".to_string(), 230)), hand.uncalled_bet);
+        assert_eq!(vec![("MrBlue".to_string(), 520.0)], hand.collected);
+        assert_eq!(520, hand.total_pot);
+        assert_eq!(0, hand.rake);
+    }
+
+    #[test]
+    fn parse__players() {
+        let hand = HandHistory::parse(HAND).unwrap();
+        let pink = hand.player("MrPink").unwrap();
+
+        assert_eq!(4, pink.seat);
+        assert_eq!(10000, pink.chips);
+        assert_eq!("A♥ 4♥", pink.hole_cards.to_string());
+        assert_eq!(Some(3), hand.seat_number("MrPink"));
+        assert!(hand.player("MrOrange").is_none());
+    }
+
+    #[test]
+    fn parse__actions() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        let preflop = hand.actions_on(Street::Preflop);
+        assert_eq!(8, preflop.len());
+        assert_eq!(Move::PostSmallBlind(50), preflop[0].action);
+        assert_eq!(Move::Raise { by: 110, to: 210 }, preflop[3].action);
+        assert_eq!(
+            4,
+            hand.actions_on(Street::Flop).len() + hand.actions_on(Street::Turn).len()
+        );
+        assert_eq!(
+            "MrBlue: bets 230",
+            hand.actions_on(Street::River)[0].to_string()
+        );
+        assert!(hand.did_fold("MrPink"));
+        assert!(!hand.did_fold("MrBlue"));
+        assert_eq!(Street::River, hand.street());
+        assert_eq!(vec!["MrBlue"], hand.winners());
+    }
+
+    #[test]
+    fn parse__empty() {
+        assert_eq!(HandHistoryError::Empty, HandHistory::parse("").unwrap_err());
+    }
+
+    #[test]
+    fn parse__invalid_header() {
+        assert_eq!(
+            HandHistoryError::InvalidHeader("Full Tilt Hand #1".to_string()),
+            HandHistory::parse("Full Tilt Hand #1\nTable 'x' 2-max Seat #1 is the button")
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse__invalid_card() {
+        let hand = HAND.replace("[Tc Qc]", "[Tc Qx]");
+
+        assert_eq!(
+            HandHistoryError::InvalidCard("Qx".to_string()),
+            HandHistory::parse(&hand).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse__unknown_player() {
+        let hand = HAND.replace("MrWhite: folds", "MrOrange: folds");
+
+        assert_eq!(
+            HandHistoryError::UnknownPlayer("MrOrange".to_string()),
+            HandHistory::parse(&hand).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn board_at() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        assert_eq!(0, hand.board_at(Street::Preflop).len());
+        assert_eq!(
+            "7♦ 5♥ 9♦",
+            hand.board_at(Street::Flop).to_playing_cards().to_string()
+        );
+        assert_eq!(4, hand.board_at(Street::Turn).len());
+        assert!(hand.board_at(Street::River).is_dealt());
+    }
+
+    #[test]
+    fn table() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        let table = hand.table();
+
+        assert_eq!(
+            "T♣ Q♣ 8♠ 4♣ 9♣ 3♦ A♥ 4♥ T♥ 5♠ 6♣ 7♠ 7♦ 5♥ 9♦ 7♣ Q♥",
+            table.dealt().to_string()
+        );
+        assert_eq!(6, table.players.len());
+    }
+
+    #[test]
+    fn table_at__flop() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        let table = hand.table_at(Street::Flop);
+        let chances = table.chances_at_flop();
+
+        assert_eq!("7♦ 5♥ 9♦", table.board.to_playing_cards().to_string());
+        assert!(!table.board.turn.is_dealt());
+        assert_eq!(6, chances.keys().len());
+    }
+
+    #[test]
+    fn read() {
+        let hands = HandHistory::read("data/pluribus_converted_logs/pluribus_100.txt").unwrap();
+        let hand = hands.first().unwrap();
+
+        assert_eq!(100000, hand.id);
+        assert_eq!("MrBlue", hand.players[0].name);
+        assert!(hands.iter().all(|hand| hand.players.len() == 6));
+    }
+
+    #[test]
+    fn parse_all__all_logs() {
+        for entry in fs::read_dir("data/pluribus_converted_logs").unwrap() {
+            let path = entry.unwrap().path();
+            let hands = HandHistory::read(path.to_str().unwrap()).unwrap();
+            assert!(!hands.is_empty());
+        }
+    }
+
+    #[test]
+    fn display() {
+        let hand = HandHistory::parse(HAND).unwrap();
+
+        assert_eq!(HAND.trim(), hand.to_string());
+    }
+
+    #[test]
+    fn display__all_logs() {
+        for entry in fs::read_dir("data/pluribus_converted_logs").unwrap() {
+            let text = fs::read_to_string(entry.unwrap().path()).unwrap();
+            let hands = HandHistory::parse_all(&text).unwrap();
+
+            let written = hands
+                .iter()
+                .map(HandHistory::to_string)
+                .collect::<Vec<String>>()
+                .join("\n\n\n");
+
+            assert_eq!(text.replace("\r\n", "\n").trim(), written);
+        }
+    }
+
+    #[test]
+    fn from_deal() {
+        let deck = PlayingCards::try_from("2♣ A♠ K♥ A♥ K♠ 3♣ 8♦ 9♦ T♦ 4♣ Q♣ 5♣ 2♥ 3♥ 4♥ 5♥ 6♥ 7♥ 8♥ 9♥ T♥ J♥ Q♥ 2♠ 3♠ 4♠ 5♠ 6♠ 7♠ 8♠ 9♠ T♠ J♠ Q♠ 2♦ 3♦ 4♦ 5♦ 6♦ 7♦ J♦ Q♦ K♦ A♦ 6♣ 7♣ 8♣ 9♣ T♣ J♣ K♣ A♣").unwrap();
+        let mut deal = Deal::from_deck(deck).unwrap();
+        deal.deal(2);
+        deal.fast_forward();
+
+        let hand = HandHistory::from(&deal);
+
+        assert_eq!("A♠ A♥", hand.players[0].hole_cards.to_string());
+        assert_eq!("K♥ K♠", hand.players[1].hole_cards.to_string());
+        assert_eq!("8♦ 9♦ T♦ Q♣ 2♥", hand.board.to_string());
+        assert_eq!(150, hand.total_pot);
+        assert_eq!(vec!["Player1"], hand.winners());
+        assert_eq!(2, hand.actions_on(Street::Showdown).len());
+        assert_eq!(hand, HandHistory::parse(&hand.to_string()).unwrap());
+    }
+
+    #[test]
+    fn from_deal__folded() {
+        let mut deal = Deal::new();
+        deal.deal(3);
+        deal.fold(1);
+        deal.fold(2);
+
+        let hand = HandHistory::from_deal(
+            &deal,
+            &[("MrBlue", 5000), ("MrPink", 20000)],
+            vec![
+                Action::new(Street::Preflop, "MrBlue", Move::PostSmallBlind(50), false),
+                Action::new(Street::Preflop, "MrPink", Move::PostBigBlind(100), false),
+            ],
+        );
+
+        assert_eq!(5000, hand.player("MrBlue").unwrap().chips);
+        assert_eq!(
+            HandHistory::DEFAULT_STACK,
+            hand.player("Player3").unwrap().chips
+        );
+        assert!(hand.did_fold("MrPink"));
+        assert!(hand.did_fold("Player3"));
+        assert_eq!(vec![("MrBlue".to_string(), 150.0)], hand.collected);
+        assert!(hand.actions_on(Street::Showdown).is_empty());
+        assert_eq!(hand, HandHistory::parse(&hand.to_string()).unwrap());
+    }
+
+    #[test]
+    fn write() {
+        let path = std::env::temp_dir().join("fudd_hand_history_write.txt");
+        let path = path.to_str().unwrap();
+        let hands = HandHistory::parse_all(&HAND.repeat(2)).unwrap();
+
+        HandHistory::write(path, &hands).unwrap();
+
+        assert_eq!(hands, HandHistory::read(path).unwrap());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn cards_to_string() {
+        let cards = PlayingCards::try_from("T♣ Q♣").unwrap();
+
+        assert_eq!("Tc Qc", HandHistory::cards_to_string(&cards));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::types::slots::hole_cards::HoleCards;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A player sitting at the table at the start of a hand, along with the
+/// `HoleCards` they were dealt.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Player {
+    pub seat: usize,
+    pub name: String,
+    pub chips: usize,
+    pub hole_cards: HoleCards,
+}
+
+impl Player {
+    #[must_use]
+    pub fn new(seat: usize, name: &str, chips: usize) -> Player {
+        Player {
+            seat,
+            name: name.to_string(),
+            chips,
+            hole_cards: HoleCards::default(),
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Seat {}: {} ({} in chips)",
+            self.seat, self.name, self.chips
+        )
+    }
+}
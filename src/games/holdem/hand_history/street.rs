@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use strum_macros::EnumIter;
+
+/// The betting rounds recorded in a hand history. `Showdown` collects the
+/// `shows` lines that follow the `*** SHOWDOWN ***` marker.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    EnumIter,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+impl Street {
+    /// Number of community cards that are visible once the `Street` has been reached.
+    #[must_use]
+    pub fn board_len(&self) -> usize {
+        match self {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn => 4,
+            Street::River | Street::Showdown => 5,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod holdem_hand_history_street_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Street::Preflop, 0)]
+    #[case(Street::Flop, 3)]
+    #[case(Street::Turn, 4)]
+    #[case(Street::River, 5)]
+    #[case(Street::Showdown, 5)]
+    fn board_len(#[case] street: Street, #[case] expected: usize) {
+        assert_eq!(expected, street.board_len());
+    }
+}
@@ -1,16 +1,24 @@
 use crate::analysis::eval::Eval;
 use crate::games::holdem::hand::Hand;
-use crate::types::arrays::Vectorable;
+use crate::types::arrays::{Evaluable, Vectorable};
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::poker_cards::PokerCards;
+use crate::types::suit_permutation::{SuitIsomorphic, SuitPermutation};
 use crate::types::{PileOfCards, U32Card};
-use ckc_rs::PokerCard;
+use ckc_rs::hand_rank::HandRank;
+use ckc_rs::{HandError, PokerCard};
 use itertools::Itertools;
 use log::debug;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use serde::{Deserialize, Serialize};
-use std::sync::mpsc;
-use std::{fmt, mem};
-use wincounter::{Win, Wins};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use wincounter::{Count, Win, Wins};
 
 #[derive(
     Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd,
@@ -39,7 +47,7 @@ impl HeadsUp {
     }
 
     #[must_use]
-    pub fn from_index(index: &'static str) -> HeadsUp {
+    pub fn from_index(index: &str) -> HeadsUp {
         HeadsUp::from(index)
     }
 
@@ -48,6 +56,55 @@ impl HeadsUp {
         self.has(PlayingCard::BLANK)
     }
 
+    /// Returns true if relabelling the suits can swap the two hands for each other, so that
+    /// they both have the same odds.
+    #[must_use]
+    pub fn is_mirror(&self) -> bool {
+        SuitPermutation::all().iter().any(|permutation| {
+            permute_hand(self.first, permutation) == self.second
+                && permute_hand(self.second, permutation) == self.first
+        })
+    }
+
+    /// Returns the representative of every heads up matchup that plays the same once the
+    /// suits are relabelled, which is always `to_canonical()`, along with whether the hands
+    /// swap places in it, which is when the odds for the first hand are the odds for the
+    /// representative's second. Matchups that are their own mirror image, such as
+    /// `A♠ K♦ A♥ K♣`, are never flipped.
+    #[must_use]
+    pub fn canonical(&self) -> (HeadsUp, bool) {
+        if self.is_blank() {
+            return (*self, false);
+        }
+        let permutation = self.canonical_permutation();
+        let canonical = self.permute(&permutation);
+        let flipped = canonical.first != permute_hand(self.first, &permutation);
+        (canonical, flipped && !canonical.is_mirror())
+    }
+
+    /// Returns one of each of the heads up matchups that are distinct once the suits are
+    /// taken out of the picture, in order.
+    #[must_use]
+    pub fn canonical_matchups() -> Vec<HeadsUp> {
+        let hands: Vec<Hand> = PlayingCards::deck()
+            .combinations(2)
+            .map(|cards| Hand::new(*cards[0], *cards[1]))
+            .collect();
+        let mut matchups = BTreeSet::new();
+        for (i, first) in hands.iter().enumerate() {
+            for second in &hands[i + 1..] {
+                if !first
+                    .as_playing_cards()
+                    .is_disjoint(&second.as_playing_cards())
+                {
+                    continue;
+                }
+                matchups.insert(HeadsUp::new(*first, *second).canonical().0);
+            }
+        }
+        matchups.into_iter().collect()
+    }
+
     #[must_use]
     pub fn seven_from(&self, five: &PlayingCards) -> (PlayingCards, PlayingCards) {
         (
@@ -68,6 +125,21 @@ impl HeadsUp {
         )
     }
 
+    /// Like `best_from_seven`, but only looks up the `HandRank` for each player, which is
+    /// all that's needed to know who wins.
+    ///
+    /// # Panics
+    ///
+    /// If the board doesn't have five cards that aren't in either hand.
+    #[must_use]
+    pub fn hand_ranks_from_seven(&self, five: &PlayingCards) -> (HandRank, HandRank) {
+        let (first_seven, second_seven) = self.seven_from(five);
+        (
+            first_seven.to_seven_array().unwrap().hand_rank(),
+            second_seven.to_seven_array().unwrap().hand_rank(),
+        )
+    }
+
     //region -> Result Preflop <-
 
     #[must_use]
@@ -106,45 +178,66 @@ impl HeadsUp {
         self.wins_preflop_with_worker_count(HeadsUp::DEFAULT_WORKER_COUNT)
     }
 
-    #[allow(unused_must_use, clippy::comparison_chain)]
+    /// Runs every possible board out across a thread pool with the number of workers, falling
+    /// back on rayon's global pool if it can't be built.
     #[must_use]
     pub fn wins_preflop_with_worker_count(&self, worker_count: usize) -> Wins {
-        let mut wins = Wins::default();
-        let remaining = self.remaining();
-        let combos = remaining.combinations(5);
-
-        let chunks = combos.chunks((HeadsUp::PREFLOP_COMBO_COUNT / worker_count).max(1));
-        let (sender, receiver) = mpsc::channel();
-
-        for chunk in &chunks {
-            for combo in chunk {
-                let sender = sender.clone();
-
-                let board = PlayingCards::from(combo);
-                let (eval1, eval2) = self.best_from_seven(&board);
-
-                if eval1.rank > eval2.rank {
-                    sender.send(Win::FIRST);
-                } else if eval2.rank > eval1.rank {
-                    debug!("   Player 2 Wins: {} - {}", board, eval2);
-                    sender.send(Win::SECOND);
-                } else {
-                    debug!("   Tie: {} - {} / {}", board, eval1, eval2);
-                    sender.send(Win::FIRST | Win::SECOND);
-                }
-            }
+        match ThreadPoolBuilder::new().num_threads(worker_count).build() {
+            Ok(pool) => self.wins_preflop_in(&pool),
+            Err(_) => self.par_wins_preflop(),
         }
+    }
 
-        mem::drop(sender);
+    /// Splits the runouts up by their first card, so that each of the pool's threads counts
+    /// its own `Wins`, which are merged once they're all done. The merged `Wins` are in the
+    /// same order as if they had been counted one board at a time.
+    #[must_use]
+    pub fn wins_preflop_in(&self, pool: &ThreadPool) -> Wins {
+        pool.install(|| self.par_wins_preflop())
+    }
 
-        for received in receiver {
-            wins.add_win(received);
-        }
+    //endregion -> Result Preflop <-
 
-        wins
+    //region private functions
+
+    fn par_wins_preflop(&self) -> Wins {
+        let cards = self.remaining().to_vec();
+        (0..cards.len().saturating_sub(4))
+            .into_par_iter()
+            .map(|first| {
+                let mut wins = Wins::default();
+                for rest in cards[first + 1..].iter().combinations(4) {
+                    let mut board = PlayingCards::from(cards[first]);
+                    for card in rest {
+                        board.insert(*card);
+                    }
+                    wins.add_win(self.win(&board));
+                }
+                wins
+            })
+            .reduce(Wins::default, |mut wins, other| {
+                wins.extend(&other);
+                wins
+            })
     }
 
-    //endregion -> Result Preflop <-
+    fn win(&self, board: &PlayingCards) -> Count {
+        let (rank1, rank2) = self.hand_ranks_from_seven(board);
+
+        match rank1.cmp(&rank2) {
+            Ordering::Greater => Win::FIRST,
+            Ordering::Less => {
+                debug!("   Player 2 Wins: {board} - {rank2}");
+                Win::SECOND
+            }
+            Ordering::Equal => {
+                debug!("   Tie: {board} - {rank1} / {rank2}");
+                Win::FIRST | Win::SECOND
+            }
+        }
+    }
+
+    //endregion
 
     // pub fn types() -> Vec<&str> {
     //     vec![
@@ -163,8 +256,8 @@ impl fmt::Display for HeadsUp {
     }
 }
 
-impl From<&'static str> for HeadsUp {
-    fn from(s: &'static str) -> Self {
+impl From<&str> for HeadsUp {
+    fn from(s: &str) -> Self {
         let v: Vec<&str> = s.split_whitespace().collect();
         if v.len() == 4 {
             HeadsUp::new(
@@ -183,6 +276,55 @@ impl From<&'static str> for HeadsUp {
     }
 }
 
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index,
+/// `HandError::InvalidCardCount` if there aren't exactly four cards, and
+/// `HandError::DuplicateCard` if a card is repeated.
+impl FromStr for HeadsUp {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = PokerCards::from_str(s)?;
+        if cards.len() != 4 {
+            return Err(IndexError::Hand(HandError::InvalidCardCount));
+        }
+        if PlayingCards::from(&cards).len() != 4 {
+            return Err(IndexError::Hand(HandError::DuplicateCard));
+        }
+        let v = cards.to_vec();
+        Ok(HeadsUp::new(
+            Hand::new(PlayingCard::from(v[0]), PlayingCard::from(v[1])),
+            Hand::new(PlayingCard::from(v[2]), PlayingCard::from(v[3])),
+        ))
+    }
+}
+
+/// Which hand comes first doesn't matter, so the class of a matchup is the same as
+/// `HeadsUp::canonical()`, without saying whether the hands were flipped.
+impl SuitIsomorphic for HeadsUp {
+    fn permute(&self, permutation: &SuitPermutation) -> Self {
+        if self.is_blank() {
+            return *self;
+        }
+        HeadsUp::new(
+            permute_hand(self.first, permutation),
+            permute_hand(self.second, permutation),
+        )
+    }
+
+    fn suit_key(&self) -> Vec<U32Card> {
+        self.to_vec()
+    }
+}
+
+fn permute_hand(hand: Hand, permutation: &SuitPermutation) -> Hand {
+    Hand::new(
+        permutation.apply(hand.first()),
+        permutation.apply(hand.second()),
+    )
+}
+
 impl PileOfCards<PlayingCard> for HeadsUp {
     fn has(&self, playing_card: PlayingCard) -> bool {
         self.first.has(playing_card) || self.second.has(playing_card)
@@ -258,6 +400,77 @@ mod games_holdem_heads_up_tests {
         .is_blank());
     }
 
+    #[test]
+    fn canonical() {
+        let (canonical, flipped) = HeadsUp::from("A♠ A♥ 7♦ 7♣").canonical();
+
+        assert_eq!(
+            (canonical, flipped),
+            HeadsUp::from("A♦ A♣ 7♠ 7♥").canonical()
+        );
+        assert_eq!(canonical, HeadsUp::from("A♥ A♦ 7♠ 7♣").canonical().0);
+        assert_ne!(canonical, HeadsUp::from("A♠ A♥ 7♠ 7♣").canonical().0);
+        assert!(!flipped);
+        assert_eq!((HeadsUp::default(), false), HeadsUp::default().canonical());
+    }
+
+    #[test]
+    fn canonical__flipped() {
+        let (canonical, flipped) = HeadsUp::from("A♠ Q♦ A♥ K♥").canonical();
+        let (other, other_flipped) = HeadsUp::from("A♠ K♠ A♥ Q♦").canonical();
+
+        assert_eq!(canonical, other);
+        assert_ne!(flipped, other_flipped);
+    }
+
+    #[test]
+    fn canonical__to_canonical() {
+        for index in [
+            "A♠ Q♦ A♥ K♥",
+            "A♠ K♠ A♥ Q♦",
+            "A♦ A♣ 7♠ 7♥",
+            "A♥ K♣ A♠ K♦",
+            "7♣ 2♦ 7♦ 2♣",
+            "K♦ Q♦ J♣ T♣",
+        ] {
+            let heads_up = HeadsUp::from(index);
+
+            assert_eq!(heads_up.to_canonical(), heads_up.canonical().0);
+        }
+    }
+
+    #[test]
+    fn canonical__mirror() {
+        let heads_up = HeadsUp::from("A♥ K♣ A♠ K♦");
+
+        assert!(heads_up.is_mirror());
+        assert!(!HeadsUp::from("A♠ K♠ A♥ Q♦").is_mirror());
+        assert!(!heads_up.canonical().1);
+    }
+
+    #[test]
+    fn suit_isomorphic() {
+        let heads_up = HeadsUp::from("A♦ A♣ 7♠ 7♥");
+
+        assert_eq!(HeadsUp::from("A♠ A♥ 7♦ 7♣"), heads_up.to_canonical());
+        assert_eq!(heads_up.canonical().0, heads_up.to_canonical());
+        assert_eq!(6, heads_up.multiplicity());
+        assert_eq!(12, HeadsUp::from("A♠ K♦ 7♥ 7♣").multiplicity());
+        assert_eq!(24, HeadsUp::from("A♠ K♦ Q♥ J♣").multiplicity());
+        assert_eq!(12, HeadsUp::from("A♠ K♦ A♥ K♣").multiplicity());
+    }
+
+    #[test]
+    #[ignore]
+    fn canonical_matchups() {
+        let matchups = HeadsUp::canonical_matchups();
+
+        assert_eq!(47_008, matchups.len());
+        assert!(matchups
+            .iter()
+            .all(|heads_up| heads_up.canonical() == (*heads_up, false)));
+    }
+
     #[test]
     fn display() {
         assert_eq!(
@@ -300,6 +513,39 @@ mod games_holdem_heads_up_tests {
         );
     }
 
+    #[test]
+    fn from_str() {
+        let hup: HeadsUp = "5♦ 5♣ 6♠ 6♥".parse().unwrap();
+
+        assert_eq!(HeadsUp::from("6♠ 6♥ 5♦ 5♣"), hup);
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        assert_eq!(
+            IndexError::invalid_card("5X", 2),
+            HeadsUp::from_str("6♠ 6♥ 5X 5♣").unwrap_err()
+        );
+        assert_eq!(
+            IndexError::Hand(HandError::InvalidCardCount),
+            HeadsUp::from_str("6♠ 6♥ 5♦").unwrap_err()
+        );
+        assert_eq!(
+            IndexError::Hand(HandError::DuplicateCard),
+            HeadsUp::from_str("6♠ 6♥ 6♠ 5♣").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn hand_ranks_from_seven() {
+        let hup = HeadsUp::from("6♠ 6♥ 5♦ 5♣");
+        let board = PlayingCards::try_from("9♣ 6♦ 5♥ 5♠ 8♠").unwrap();
+
+        let (first, second) = hup.best_from_seven(&board);
+
+        assert_eq!((first.rank, second.rank), hup.hand_ranks_from_seven(&board));
+    }
+
     #[test]
     fn odds_to_string() {
         let hup = HeadsUp::from("6♠ 6♥ 5♦ 5♣");
@@ -311,6 +557,16 @@ mod games_holdem_heads_up_tests {
         );
     }
 
+    #[test]
+    #[ignore]
+    fn odds_preflop_with_worker_count() {
+        let hup = HeadsUp::from("6♠ 6♥ 5♦ 5♣");
+        let expected = wincounter::result::HeadsUp::new(1365284, 314904, 32116);
+
+        assert_eq!(expected, hup.odds_preflop_with_worker_count(1));
+        assert_eq!(expected, hup.odds_preflop_with_worker_count(4));
+    }
+
     #[test]
     fn vectorable__remaining() {
         let hup = HeadsUp::from("6♠ 6♥ 5♦ 5♣");
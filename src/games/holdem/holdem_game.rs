@@ -0,0 +1,22 @@
+use crate::games::variant::showdown::Showdown;
+use crate::games::variant::PokerVariant;
+use serde::{Deserialize, Serialize};
+
+/// [Texas hold'em](https://en.wikipedia.org/wiki/Texas_hold_%27em) as a `PokerVariant`, with
+/// two hole cards, a flop, turn and river, and the best five of the seven cards winning.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HoldemGame;
+
+impl PokerVariant for HoldemGame {
+    fn hole_cards(&self) -> usize {
+        2
+    }
+
+    fn streets(&self) -> Vec<usize> {
+        vec![3, 1, 1]
+    }
+
+    fn showdown(&self) -> Showdown {
+        Showdown::High
+    }
+}
@@ -3,7 +3,9 @@ pub mod case_eval;
 pub mod case_evals;
 pub mod deal;
 pub mod hand;
+pub mod hand_history;
 pub mod heads_up;
+pub mod holdem_game;
 pub mod seat;
 pub mod seat_eval;
 pub mod seats;
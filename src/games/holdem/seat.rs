@@ -1,16 +1,16 @@
 use crate::types::card_slot::CardSlot;
+use crate::types::flag::Flag;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::slots::hole_cards::HoleCards;
 use ckc_rs::PokerCard;
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
 use std::fmt;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
 pub struct Seat {
     pub number: usize,
-    folded: Cell<bool>,
+    folded: Flag,
     pub hole_cards: HoleCards,
 }
 
@@ -21,14 +21,14 @@ impl Seat {
     }
 
     #[must_use]
-    pub fn from_index(number: usize, index: &'static str) -> Seat {
+    pub fn from_index(number: usize, index: &str) -> Seat {
         Seat::new_with_hole_cards(number, HoleCards::from(index))
     }
 
     pub fn new_with_hole_cards(number: usize, hole_cards: HoleCards) -> Seat {
         Seat {
             number,
-            folded: Cell::new(false),
+            folded: Flag::new(false),
             hole_cards,
         }
     }
@@ -76,7 +76,7 @@ mod holdem_seat_tests {
     fn default() {
         let seat = Seat {
             number: 0,
-            folded: Cell::new(false),
+            folded: Flag::new(false),
             hole_cards: HoleCards::default(),
         };
 
@@ -4,6 +4,7 @@ use crate::games::holdem::seat::Seat;
 use crate::games::holdem::seat_eval::SeatEval;
 use crate::types::card_slot;
 use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::poker_cards::PokerCards;
@@ -13,6 +14,7 @@ use itertools::Itertools;
 use log::debug;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Seats(Vec<Seat>);
@@ -27,11 +29,6 @@ impl Seats {
         players
     }
 
-    /// This alias is needed because, for some reason, the method used in the examples
-    /// of turning command line user input into static strings returns mutable static strings,
-    /// which is something that I didn't know was possible. For some reason, Rust has no problem
-    /// with that input here, but chokes when it comes directly from implementing the `TryFrom` trait.
-    ///
     /// Takes a Card index string and returns a `Players` entity with all
     /// the cards dealt to them.
     ///
@@ -41,7 +38,7 @@ impl Seats {
     ///
     /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
     /// isn't divisible by 2. (There must be two cards for each `Player`.)
-    pub fn from_index(index: &'static str) -> Result<Seats, HandError> {
+    pub fn from_index(index: &str) -> Result<Seats, HandError> {
         Seats::try_from(index)
     }
 
@@ -143,7 +140,7 @@ impl Seats {
             .push(Seat::new_with_hole_cards(self.len(), hole_cards));
     }
 
-    pub fn add_from_index(&mut self, index: &'static str) {
+    pub fn add_from_index(&mut self, index: &str) {
         self.0.push(Seat::from_index(self.len(), index));
     }
 
@@ -249,15 +246,33 @@ impl From<Vec<Seat>> for Seats {
 ///
 /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
 /// isn't divisible by 2. (There must be two cards for each `Player`.)
-impl TryFrom<&'static str> for Seats {
+impl TryFrom<&str> for Seats {
     type Error = HandError;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
-        let poker_cards = PokerCards::try_from(value);
-        match poker_cards {
-            Ok(cards) => Seats::try_from(cards),
-            Err(e) => Err(e),
-        }
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Seats::from_str(value)?)
+    }
+}
+
+impl TryFrom<String> for Seats {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Seats::try_from(value.as_str())
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index.
+///
+/// Will return an `IndexError::Hand(HandError::InvalidIndex)` if the number of cards passed in
+/// isn't divisible by 2. (There must be two cards for each `Player`.)
+impl FromStr for Seats {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Seats::try_from(PokerCards::from_str(s)?)?)
     }
 }
 
@@ -353,6 +368,39 @@ mod holdem_players_tests {
         assert_eq!(players.unwrap_err(), HandError::InvalidIndex)
     }
 
+    #[test]
+    fn try_from__string() {
+        let index = String::from("4♥ K♣ 5♥ 3♠");
+
+        let players = Seats::try_from(index).unwrap();
+
+        assert_eq!("[Seat 0: 4♥ K♣, Seat 1: 5♥ 3♠]", players.to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        let players: Seats = "4♥ K♣ 5♥ 3♠".parse().unwrap();
+
+        assert_eq!("[Seat 0: 4♥ K♣, Seat 1: 5♥ 3♠]", players.to_string());
+    }
+
+    #[test]
+    fn from_str__invalid_card() {
+        let players = Seats::from_str("4♥ K♣ 5♥ JK");
+
+        assert_eq!(IndexError::invalid_card("JK", 3), players.unwrap_err());
+    }
+
+    #[test]
+    fn from_str__wrong_number_of_cards() {
+        let players = Seats::from_str("4♥ K♣ 5♥");
+
+        assert_eq!(
+            IndexError::Hand(HandError::InvalidIndex),
+            players.unwrap_err()
+        );
+    }
+
     #[test]
     fn is_active() {
         let players = Seats::seat(2);
@@ -1,25 +1,41 @@
 use crate::analysis::chances::Chances;
 use crate::analysis::eval::Eval;
+use crate::analysis::monte_carlo::MonteCarlo;
 use crate::analysis::outs::Outs;
+use crate::analysis::sampled_chances::SampledChances;
+use crate::analysis::seat_calc::SeatCalc;
 use crate::games::holdem::board::Board;
 use crate::games::holdem::case_eval::CaseEval;
 use crate::games::holdem::case_evals::CaseEvals;
+use crate::games::holdem::holdem_game::HoldemGame;
 use crate::games::holdem::seat_eval::SeatEval;
 use crate::games::holdem::seats::Seats;
+use crate::games::variant;
 use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
-use ckc_rs::HandError;
+use crate::types::slots::single_card::SingleCard;
+use crate::types::suit_permutation::{SuitIsomorphic, SuitPermutation};
+use crate::types::U32Card;
+use ckc_rs::{HandError, PokerCard};
+use itertools::Itertools;
 use rand::Rng;
-// use rayon::prelude::*;
+use rayon::prelude::*;
+use rayon::ThreadPool;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use wyz::FmtForward;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Table {
     pub players: Seats,
     pub board: Board,
+    /// Cards that are out of play without being on the `Board` or in anyone's hand, such as
+    /// the ones thrown away in Pineapple, so they can never come on a later street.
+    #[serde(default)]
+    pub dead: PlayingCards,
 }
 
 impl Table {
@@ -28,6 +44,7 @@ impl Table {
         Table {
             players: Seats::seat(number),
             board: Board::default(),
+            dead: PlayingCards::default(),
         }
     }
 
@@ -40,7 +57,7 @@ impl Table {
     /// five for the `Board` and the remaining for the `Players`, which must be
     /// divisible by 2.
     ///
-    pub fn from_index(index: &'static str) -> Result<Table, HandError> {
+    pub fn from_index(index: &str) -> Result<Table, HandError> {
         Table::try_from(index)
     }
 
@@ -72,6 +89,29 @@ impl Table {
         self.eval_at_flop().chances()
     }
 
+    /// The parallel version of `chances_at_deal()`, splitting the boards up between the
+    /// threads in the pool.
+    pub fn chances_at_deal_in(&self, pool: &ThreadPool) -> Chances {
+        if !self.players.is_dealt() {
+            return Chances::default();
+        }
+        self.par_chances(pool, &PlayingCards::default(), &self.remaining_at_deal(), 5)
+    }
+
+    /// The parallel version of `chances_at_flop()`, splitting the boards up between the
+    /// threads in the pool.
+    pub fn chances_at_flop_in(&self, pool: &ThreadPool) -> Chances {
+        if !self.board.flop.is_dealt() {
+            return Chances::default();
+        }
+        self.par_chances(
+            pool,
+            &self.board.flop.to_playing_cards(),
+            &self.remaining_at_flop(),
+            2,
+        )
+    }
+
     pub fn chances_at_turn(&self) -> Chances {
         self.eval_at_turn().chances()
     }
@@ -80,6 +120,59 @@ impl Table {
         self.eval_at_river().chances()
     }
 
+    /// Estimates each seat's equity from the deal by running out the number of random boards,
+    /// instead of all 1.7 million of them. The same seed always deals the same boards.
+    pub fn chances_at_deal_sampled(&self, samples: usize, seed: u64) -> SampledChances {
+        self.monte_carlo_at_deal(&MonteCarlo::new(samples, seed))
+    }
+
+    pub fn chances_at_flop_sampled(&self, samples: usize, seed: u64) -> SampledChances {
+        self.monte_carlo_at_flop(&MonteCarlo::new(samples, seed))
+    }
+
+    pub fn chances_at_turn_sampled(&self, samples: usize, seed: u64) -> SampledChances {
+        self.monte_carlo_at_turn(&MonteCarlo::new(samples, seed))
+    }
+
+    /// The `MonteCarlo` version of `chances_at_deal_sampled()`, which can stop as soon as the
+    /// estimate is precise enough.
+    pub fn monte_carlo_at_deal(&self, monte_carlo: &MonteCarlo) -> SampledChances {
+        if !self.players.is_dealt() {
+            return SampledChances::default();
+        }
+        self.monte_carlo(
+            monte_carlo,
+            &PlayingCards::default(),
+            &self.remaining_at_deal(),
+        )
+    }
+
+    pub fn monte_carlo_at_flop(&self, monte_carlo: &MonteCarlo) -> SampledChances {
+        if !self.board.flop.is_dealt() {
+            return SampledChances::default();
+        }
+        self.monte_carlo(
+            monte_carlo,
+            &self.board.flop.to_playing_cards(),
+            &self.remaining_at_flop(),
+        )
+    }
+
+    pub fn monte_carlo_at_turn(&self, monte_carlo: &MonteCarlo) -> SampledChances {
+        if !self.board.turn.is_dealt() {
+            return SampledChances::default();
+        }
+        self.monte_carlo(
+            monte_carlo,
+            &self
+                .board
+                .flop
+                .to_playing_cards()
+                .combine(&self.board.turn.to_playing_cards()),
+            &self.remaining_at_turn(),
+        )
+    }
+
     pub fn dealt(&self) -> PlayingCards {
         PlayingCards::default()
             .combine(&self.players.dealt())
@@ -200,21 +293,20 @@ impl Table {
     }
 
     pub fn remaining_at_deal(&self) -> PlayingCards {
-        self.remaining()
+        self.live()
             .combine(&self.board.flop.to_playing_cards())
             .combine(&self.board.turn.to_playing_cards())
             .combine(&self.board.river.to_playing_cards())
     }
 
     pub fn remaining_at_flop(&self) -> PlayingCards {
-        self.remaining()
+        self.live()
             .combine(&self.board.turn.to_playing_cards())
             .combine(&self.board.river.to_playing_cards())
     }
 
     pub fn remaining_at_turn(&self) -> PlayingCards {
-        self.remaining()
-            .combine(&self.board.river.to_playing_cards())
+        self.live().combine(&self.board.river.to_playing_cards())
     }
 
     pub fn tied_or_better_at_flop(&self, eval: &Eval) -> CaseEval {
@@ -390,6 +482,99 @@ impl Table {
     pub fn who_flopped_the_nuts(&self) -> CaseEval {
         self.tied_or_better_at_flop(&self.nuts_at_flop())
     }
+
+    /// Returns the `PokerVariant` version of the `Table`, with the same hands, board and dead
+    /// cards.
+    #[must_use]
+    pub fn to_variant(&self) -> variant::table::Table<HoldemGame> {
+        variant::table::Table {
+            variant: HoldemGame,
+            hands: self
+                .players
+                .iter()
+                .map(CardSlot::to_playing_cards)
+                .collect(),
+            board: self.board.to_playing_cards(),
+            dead: self.dead.clone(),
+        }
+    }
+
+    //region private functions
+
+    /// The cards that aren't held by the `Players`, on the `Board`, or dead.
+    fn live(&self) -> PlayingCards {
+        self.remaining().minus(&self.dead)
+    }
+
+    /// Runs the rest of the board out from the remaining cards, splitting the pot between the
+    /// winners of each sample.
+    #[allow(clippy::cast_precision_loss)]
+    fn monte_carlo(
+        &self,
+        monte_carlo: &MonteCarlo,
+        board: &PlayingCards,
+        remaining: &PlayingCards,
+    ) -> SampledChances {
+        monte_carlo.run(remaining, 5 - board.len(), |cards| {
+            let case = self.players.case_eval(&board.combine(cards));
+            let winners = case.winners();
+            case.iter()
+                .map(|seat| {
+                    let share = if winners.has_seat(seat.seat.number) {
+                        1.0 / winners.len() as f32
+                    } else {
+                        0.0
+                    };
+                    (seat.seat.number, share)
+                })
+                .collect()
+        })
+    }
+
+    /// Counts the wins over every way of drawing the cards from the remaining ones onto the
+    /// board, with each of the pool's tasks folding all of the draws that start with a
+    /// particular card into a `SeatCalc`, so none of the cases are held on to.
+    fn par_chances(
+        &self,
+        pool: &ThreadPool,
+        board: &PlayingCards,
+        remaining: &PlayingCards,
+        draw: usize,
+    ) -> Chances {
+        let cards = remaining.to_vec();
+        let seats: Vec<usize> = self.players.iter().map(|seat| seat.number).collect();
+
+        let (calc, cases) = pool.install(|| {
+            (0..(cards.len() + 1).saturating_sub(draw))
+                .into_par_iter()
+                .map(|first| {
+                    let mut calc = SeatCalc::primed(seats.iter().copied());
+                    let mut cases = 0;
+                    for rest in cards[first + 1..].iter().combinations(draw - 1) {
+                        let mut cycle = PlayingCards::from(cards[first]);
+                        for card in rest {
+                            cycle.insert(*card);
+                        }
+                        cycle.append(board);
+                        for winner in self.players.case_eval(&cycle).winners().iter() {
+                            calc.increment(winner.seat.number);
+                        }
+                        cases += 1;
+                    }
+                    (calc, cases)
+                })
+                .reduce(
+                    || (SeatCalc::primed(seats.iter().copied()), 0),
+                    |(mut calc, cases), (other, more)| {
+                        calc.extend(&other);
+                        (calc, cases + more)
+                    },
+                )
+        });
+        calc.chances(cases)
+    }
+
+    //endregion
 }
 
 impl CardSlot for Table {
@@ -422,6 +607,45 @@ impl CardSlot for Table {
     }
 }
 
+/// Each seat keeps its place, since who holds which cards matters, but the order of the cards in
+/// each seat's hand and in the flop doesn't.
+impl SuitIsomorphic for Table {
+    fn permute(&self, permutation: &SuitPermutation) -> Self {
+        let players = self.players.clone();
+        for seat in players.iter() {
+            let hole_cards = &seat.hole_cards;
+            hole_cards.take_first_card(permutation.apply(hole_cards.get_first_card()));
+            hole_cards.take_second_card(permutation.apply(hole_cards.get_second_card()));
+        }
+        Table {
+            players,
+            board: Board {
+                flop: self.board.flop.permute(permutation),
+                turn: SingleCard::new(permutation.apply(self.board.turn.get())),
+                river: SingleCard::new(permutation.apply(self.board.river.get())),
+            },
+            dead: self.dead.permute(permutation),
+        }
+    }
+
+    fn suit_key(&self) -> Vec<U32Card> {
+        let mut key = Vec::new();
+        for seat in self.players.iter() {
+            let (first, second) = (
+                seat.hole_cards.get_first_card(),
+                seat.hole_cards.get_second_card(),
+            );
+            key.push(first.max(second).as_u32());
+            key.push(first.min(second).as_u32());
+        }
+        key.extend(self.board.flop.suit_key());
+        key.push(self.board.turn.get().as_u32());
+        key.push(self.board.river.get().as_u32());
+        key.extend(self.dead.suit_key());
+        key
+    }
+}
+
 impl Display for Table {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
         let mut out = fmt.debug_list();
@@ -433,7 +657,7 @@ impl Display for Table {
     }
 }
 
-impl TryFrom<&'static str> for Table {
+impl TryFrom<&str> for Table {
     type Error = HandError;
 
     /// Takes a Card index string and returns a `Table` entity with the `Players`
@@ -445,34 +669,63 @@ impl TryFrom<&'static str> for Table {
     /// five for the `Board` and the remaining for the `Players`, which must be
     /// divisible by 2.
     ///
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
-        let playing_cards = PlayingCards::try_from(value);
-        match playing_cards {
-            Ok(mut cards) => {
-                // There have to be at least 9 cards to be a valid Table.
-                if cards.len() < 9 {
-                    Err(HandError::NotEnoughCards)
-                } else if cards.len() % 2 != 1 {
-                    Err(HandError::InvalidCardCount)
-                } else {
-                    let mut table = Table::default();
-                    table
-                        .board
-                        .take_from_playing_cards(&cards.draw_from_the_bottom(5));
-                    match Seats::try_from(cards) {
-                        Ok(players) => {
-                            table.players = players;
-                            Ok(table)
-                        }
-                        _ => Err(HandError::InvalidIndex),
-                    }
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Table::try_from(PlayingCards::try_from(value)?)
+    }
+}
+
+impl TryFrom<String> for Table {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Table::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<PlayingCards> for Table {
+    type Error = HandError;
+
+    /// The last five cards are dealt to the `Board` and the rest to the `Players`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if there aren't at least nine cards, or if the number of
+    /// cards for the `Players` isn't divisible by 2.
+    fn try_from(value: PlayingCards) -> Result<Self, Self::Error> {
+        let mut cards = value;
+        // There have to be at least 9 cards to be a valid Table.
+        if cards.len() < 9 {
+            Err(HandError::NotEnoughCards)
+        } else if cards.len() % 2 != 1 {
+            Err(HandError::InvalidCardCount)
+        } else {
+            let mut table = Table::default();
+            table
+                .board
+                .take_from_playing_cards(&cards.draw_from_the_bottom(5));
+            match Seats::try_from(cards) {
+                Ok(players) => {
+                    table.players = players;
+                    Ok(table)
                 }
+                _ => Err(HandError::InvalidIndex),
             }
-            Err(e) => Err(e),
         }
     }
 }
 
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index, or
+/// an `IndexError::Hand` if the cards can't be dealt out to a `Table`.
+impl FromStr for Table {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Table::try_from(PlayingCards::from_str(s)?)?)
+    }
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod holdem_table_tests {
@@ -493,6 +746,32 @@ mod holdem_table_tests {
         assert_eq!(903, evals.len())
     }
 
+    #[test]
+    fn suit_isomorphic() {
+        let table = Table::from_index("A♦ A♣ 7♠ 7♥ 9♦ 5♣ 2♦ K♥ 3♠").unwrap();
+
+        let canonical = table.to_canonical();
+
+        assert_eq!("A♠ A♥ 7♣ 7♦ 9♠ 5♥ 2♠ K♦ 3♣", canonical.dealt().to_string());
+        assert!(canonical.is_canonical());
+        assert!(!table.is_canonical());
+        assert_eq!(24, table.multiplicity());
+        let (chances, canonical_chances) = (table.chances_at_flop(), canonical.chances_at_flop());
+        assert_eq!(chances.get(0), canonical_chances.get(0));
+        assert_eq!(chances.get(1), canonical_chances.get(1));
+    }
+
+    #[test]
+    fn suit_isomorphic__folded() {
+        let table = Table::from_index("A♦ A♣ 7♠ 7♥ 9♦ 5♣ 2♦ K♥ 3♠").unwrap();
+        table.players.fold_player(1);
+
+        let canonical = table.to_canonical();
+
+        assert!(!canonical.players.is_active(1));
+        assert_eq!(table.multiplicity(), canonical.multiplicity());
+    }
+
     #[test]
     fn from_index() {
         let index = "6♣ J♥ 7♠ 8♠ 9♦ 2♣ 6♠ T♥ 3♥ 9♥ 9♠";
@@ -540,6 +819,42 @@ mod holdem_table_tests {
         assert_eq!(HandError::InvalidCardCount, table.unwrap_err());
     }
 
+    #[test]
+    fn try_from__string() {
+        let index = String::from("6♣ J♥ 7♠ 8♠ 9♦ 2♣ 6♠ T♥ 3♥ 9♥ 9♠");
+
+        let table = Table::try_from(index.clone()).unwrap();
+
+        assert_eq!(index, table.dealt().to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        let table: Table = "6♣ J♥ 7♠ 8♠ 9♦ 2♣ 6♠ T♥ 3♥ 9♥ 9♠".parse().unwrap();
+
+        assert_eq!(
+            "[Seat 0: 6♣ J♥, Seat 1: 7♠ 8♠, Seat 2: 9♦ 2♣]",
+            table.players.to_string()
+        );
+    }
+
+    #[test]
+    fn from_str__invalid_card() {
+        let table = Table::from_str("6♣ J♥ 7♠ 8♠ 9♦ 2♣ 6♠ T♥ 3♥ 9♥ 9X");
+
+        assert_eq!(IndexError::invalid_card("9X", 10), table.unwrap_err());
+    }
+
+    #[test]
+    fn from_str__not_enough_cards() {
+        let table = Table::from_str("9♦ 2♣ 6♠ T♥ 3♥ 9♥ 9♠");
+
+        assert_eq!(
+            IndexError::Hand(HandError::NotEnoughCards),
+            table.unwrap_err()
+        );
+    }
+
     #[test]
     fn take() {
         let table = Table::seat(3);
@@ -593,6 +908,121 @@ mod holdem_table_tests {
         );
     }
 
+    fn aces_v_kings() -> Table {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥");
+        table
+    }
+
+    #[test]
+    fn chances_at_deal_sampled() {
+        let chances = aces_v_kings().chances_at_deal_sampled(2_000, 42);
+        let (low, high) = chances.interval(0);
+
+        assert_eq!(2_000, chances.samples());
+        assert!(low < 82.0 && 82.0 < high);
+        assert!(chances.standard_error(0) < 1.0);
+        assert!(chances.chances().keeping_it_100());
+    }
+
+    #[test]
+    fn chances_at_deal_sampled__seeded() {
+        let table = aces_v_kings();
+
+        let first = table.chances_at_deal_sampled(300, 7);
+        let second = table.chances_at_deal_sampled(300, 7);
+
+        assert_eq!(first.equity(0), second.equity(0));
+        assert_eq!(first.standard_error(1), second.standard_error(1));
+    }
+
+    #[test]
+    fn chances_at_flop_sampled() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ 9♣ 7♠");
+        let expected = table.chances_at_flop();
+
+        let chances = table.chances_at_flop_sampled(1_000, 42);
+        let (low, high) = chances.interval(1);
+
+        assert!(low < expected.get(1) && expected.get(1) < high);
+    }
+
+    #[test]
+    fn chances_at_turn_sampled__not_dealt() {
+        assert_eq!(0, aces_v_kings().chances_at_turn_sampled(100, 42).samples());
+        assert_eq!(0, Table::seat(2).chances_at_deal_sampled(100, 42).samples());
+    }
+
+    #[test]
+    fn monte_carlo_at_deal() {
+        let chances =
+            aces_v_kings().monte_carlo_at_deal(&MonteCarlo::new(100_000, 42).with_precision(1.5));
+
+        assert!(chances.samples() < 100_000);
+        assert!(chances.max_standard_error() <= 1.5);
+    }
+
+    fn pool(threads: usize) -> ThreadPool {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn chances_at_flop_in() {
+        let table = Table::seat(3);
+        table.take_from_index("A♠ K♠ 8♦ A♥ K♥ 7♦ A♦ 9♣ 6♦");
+        let expected = table.chances_at_flop();
+
+        let chances = table.chances_at_flop_in(&pool(4));
+
+        for seat in 0..3 {
+            assert_eq!(expected.get(seat), chances.get(seat));
+        }
+    }
+
+    /// The `Table` can be shared between threads, each running its own analysis of it.
+    #[test]
+    fn sync() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥ A♦ 9♣ 7♠");
+        let expected = table.chances_at_flop();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..3)
+                .map(|_| scope.spawn(|| table.chances_at_flop()))
+                .collect();
+            for handle in handles {
+                let chances = handle.join().unwrap();
+                assert_eq!(expected.get(0), chances.get(0));
+                assert_eq!(expected.get(1), chances.get(1));
+            }
+        });
+    }
+
+    #[test]
+    fn chances_at_flop_in__not_dealt() {
+        assert!(aces_v_kings()
+            .chances_at_flop_in(&pool(2))
+            .seats()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    #[ignore]
+    fn chances_at_deal_in() {
+        let table = aces_v_kings();
+        let expected = table.chances_at_deal();
+
+        let chances = table.chances_at_deal_in(&pool(4));
+
+        assert_eq!(expected.get(0), chances.get(0));
+        assert_eq!(expected.get(1), chances.get(1));
+    }
+
     #[test]
     #[ignore]
     fn playout_from_turn() {
@@ -604,4 +1034,16 @@ mod holdem_table_tests {
         table.play_out_turn();
         table.play_out_river();
     }
+
+    #[test]
+    fn to_variant() {
+        let mut table = Table::from_index("A♠ K♠ 8♥ 8♦ K♦ 9♠ 4♣ 3♥ 5♦").unwrap();
+        table.board.river.fold();
+        table.dead = PlayingCards::try_from("8♣").unwrap();
+
+        let variant = table.to_variant();
+
+        assert_eq!(table.remaining_at_turn(), variant.remaining());
+        assert!((table.chances_at_turn().get(1) - variant.chances().get(1)).abs() < 0.01);
+    }
 }
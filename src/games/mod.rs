@@ -1,2 +1,10 @@
+pub mod double_board;
+pub mod draw;
+pub mod hi_lo;
 pub mod holdem;
+pub mod omaha;
+pub mod pineapple;
 pub mod seat_name;
+pub mod short_deck;
+pub mod stud;
+pub mod variant;
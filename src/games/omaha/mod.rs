@@ -0,0 +1,4 @@
+pub mod omaha_game;
+pub mod seat;
+pub mod seats;
+pub mod table;
@@ -0,0 +1,84 @@
+use crate::games::variant::showdown::Showdown;
+use crate::games::variant::PokerVariant;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The Omaha games that fudd knows how to deal and evaluate. They all play exactly two of the
+/// hole cards with exactly three from the board, and only differ in how many hole cards each
+/// player gets, and whether the pot is split with an eight or better low.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum OmahaGame {
+    /// [Omaha hold 'em](https://en.wikipedia.org/wiki/Omaha_hold_%27em), with four hole cards.
+    #[default]
+    Omaha,
+    /// Omaha Hi/Lo, eight or better.
+    OmahaHiLo,
+    /// Five card Omaha, also known as PLO5.
+    FiveCardOmaha,
+    /// [Big O](https://en.wikipedia.org/wiki/Omaha_hold_%27em#Big_O), which is five card
+    /// Omaha Hi/Lo.
+    BigO,
+    /// Six card Omaha, also known as PLO6.
+    SixCardOmaha,
+}
+
+impl OmahaGame {
+    /// Returns the number of hole cards each player is dealt.
+    #[must_use]
+    pub fn hand_size(&self) -> usize {
+        match self {
+            OmahaGame::Omaha | OmahaGame::OmahaHiLo => 4,
+            OmahaGame::FiveCardOmaha | OmahaGame::BigO => 5,
+            OmahaGame::SixCardOmaha => 6,
+        }
+    }
+
+    /// Returns true if the pot is split between the best high hand and the best low.
+    #[must_use]
+    pub fn is_hi_lo(&self) -> bool {
+        matches!(self, OmahaGame::OmahaHiLo | OmahaGame::BigO)
+    }
+}
+
+impl PokerVariant for OmahaGame {
+    fn hole_cards(&self) -> usize {
+        self.hand_size()
+    }
+
+    fn streets(&self) -> Vec<usize> {
+        vec![3, 1, 1]
+    }
+
+    fn showdown(&self) -> Showdown {
+        if self.is_hi_lo() {
+            Showdown::OmahaHiLo
+        } else {
+            Showdown::Omaha
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod omaha_omaha_game_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(OmahaGame::Omaha, 4, false)]
+    #[case(OmahaGame::OmahaHiLo, 4, true)]
+    #[case(OmahaGame::FiveCardOmaha, 5, false)]
+    #[case(OmahaGame::BigO, 5, true)]
+    #[case(OmahaGame::SixCardOmaha, 6, false)]
+    fn hand_size(#[case] game: OmahaGame, #[case] size: usize, #[case] hi_lo: bool) {
+        assert_eq!(size, game.hand_size());
+        assert_eq!(hi_lo, game.is_hi_lo());
+        assert_eq!(size, game.hole_cards());
+    }
+
+    #[test]
+    fn showdown() {
+        assert_eq!(Showdown::Omaha, OmahaGame::SixCardOmaha.showdown());
+        assert_eq!(Showdown::OmahaHiLo, OmahaGame::BigO.showdown());
+    }
+}
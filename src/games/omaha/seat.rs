@@ -0,0 +1,121 @@
+use crate::analysis::eval::Eval;
+use crate::games::omaha::omaha_game::OmahaGame;
+use crate::types::card_slot::CardSlot;
+use crate::types::flag::Flag;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::omaha_hand::OmahaHand;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Omaha version of `holdem::seat::Seat`, with four hole cards instead of two, or five or
+/// six for the bigger Omaha games.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Seat {
+    pub number: usize,
+    folded: Flag,
+    pub hand: OmahaHand,
+}
+
+impl Seat {
+    #[must_use]
+    pub fn new(number: usize) -> Seat {
+        Seat::new_with_hand(number, OmahaHand::default())
+    }
+
+    /// Returns a `Seat` with room for the number of hole cards the `OmahaGame` deals.
+    #[must_use]
+    pub fn for_game(number: usize, game: OmahaGame) -> Seat {
+        Seat::new_with_hand(number, OmahaHand::with_size(game.hand_size()))
+    }
+
+    #[must_use]
+    pub fn from_index(number: usize, index: &str) -> Seat {
+        Seat::new_with_hand(number, OmahaHand::from(index))
+    }
+
+    #[must_use]
+    pub fn new_with_hand(number: usize, hand: OmahaHand) -> Seat {
+        Seat {
+            number,
+            folded: Flag::new(false),
+            hand,
+        }
+    }
+
+    pub fn did_fold(&self) -> bool {
+        self.folded.get()
+    }
+
+    /// Returns the best hand the `Seat` can make from two of their cards and three
+    /// from the board.
+    #[must_use]
+    pub fn eval_with_board(&self, board: &PlayingCards) -> Eval {
+        self.hand.eval_with_board(board)
+    }
+}
+
+impl CardSlot for Seat {
+    fn take(&self, card: PlayingCard) -> bool {
+        self.hand.take(card)
+    }
+
+    fn fold(&self) -> PlayingCards {
+        self.folded.set(true);
+        self.hand.fold()
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.hand.is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.hand.to_playing_cards()
+    }
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Seat {}: {}", self.number, self.hand)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod omaha_seat_tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let seat = Seat::new(1);
+
+        assert!(seat.take(PlayingCard::from("A♠")));
+        assert!(seat.take(PlayingCard::from("K♠")));
+        assert!(seat.take(PlayingCard::from("Q♠")));
+        assert!(!seat.is_dealt());
+        assert!(seat.take(PlayingCard::from("J♠")));
+        assert!(seat.is_dealt());
+        assert!(!seat.take(PlayingCard::from("T♠")));
+        assert_eq!("Seat 1: A♠ K♠ Q♠ J♠", seat.to_string());
+    }
+
+    #[test]
+    fn for_game() {
+        let seat = Seat::for_game(0, OmahaGame::SixCardOmaha);
+
+        assert!(seat.take_from_index("A♠ K♠ Q♠ J♠ T♠"));
+        assert!(!seat.is_dealt());
+        assert!(seat.take(PlayingCard::from("9♠")));
+        assert!(seat.is_dealt());
+        assert_eq!("Seat 0: A♠ K♠ Q♠ J♠ T♠ 9♠", seat.to_string());
+    }
+
+    #[test]
+    fn fold() {
+        let seat = Seat::from_index(0, "A♠ K♠ Q♠ J♠");
+
+        assert_eq!("A♠ K♠ Q♠ J♠", seat.fold().to_string());
+        assert!(seat.did_fold());
+        assert!(!seat.is_dealt());
+    }
+}
@@ -0,0 +1,370 @@
+use crate::games::holdem::case_eval::CaseEval;
+use crate::games::holdem::seat_eval::SeatEval;
+use crate::games::omaha::omaha_game::OmahaGame;
+use crate::games::omaha::seat::Seat;
+use crate::games::{hi_lo, holdem};
+use crate::types::card_slot;
+use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::omaha_hand::OmahaHand;
+use ckc_rs::HandError;
+use itertools::Itertools;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The Omaha version of `holdem::seats::Seats`, dealing four cards to each `Seat`, or however
+/// many the `OmahaGame` calls for.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Seats(Vec<Seat>);
+
+impl Seats {
+    pub const HAND_SIZE: usize = 4;
+
+    #[must_use]
+    pub fn seat(number: usize) -> Seats {
+        Seats((0..number).map(Seat::new).collect())
+    }
+
+    #[must_use]
+    pub fn seat_for_game(game: OmahaGame, number: usize) -> Seats {
+        Seats((0..number).map(|i| Seat::for_game(i, game)).collect())
+    }
+
+    /// Takes a Card index string and returns a `Seats` entity with all
+    /// the cards dealt to them.
+    ///
+    /// # Errors
+    ///
+    /// Will throw a `HandError::InvalidCard` if an invalid index is passed in.
+    ///
+    /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
+    /// isn't divisible by 4. (There must be four cards for each `Seat`.)
+    pub fn from_index(index: &str) -> Result<Seats, HandError> {
+        Seats::try_from(index)
+    }
+
+    /// The `OmahaGame` version of `from_index`, where each `Seat` takes the number of cards
+    /// the game deals.
+    ///
+    /// # Errors
+    ///
+    /// Will throw a `HandError::InvalidCard` if an invalid index is passed in.
+    ///
+    /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
+    /// isn't divisible by the `OmahaGame's` hand size.
+    pub fn from_index_for_game(game: OmahaGame, index: &str) -> Result<Seats, HandError> {
+        Seats::deal_for_game(game, PlayingCards::try_from(index)?)
+    }
+
+    /// Each `Seat` takes the next however many cards the `OmahaGame` deals, in order.
+    ///
+    /// # Errors
+    ///
+    /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
+    /// isn't divisible by the `OmahaGame's` hand size.
+    pub fn deal_for_game(game: OmahaGame, cards: PlayingCards) -> Result<Seats, HandError> {
+        let mut cards = cards;
+        let size = game.hand_size();
+        if cards.len() % size != 0 {
+            return Err(HandError::InvalidIndex);
+        }
+        let seats = Seats::seat_for_game(game, cards.len() / size);
+        for i in 0..seats.len() {
+            for _ in 0..size {
+                let _ = seats.take_for_player(i, cards.draw_one());
+            }
+        }
+        Ok(seats)
+    }
+
+    /// Returns a `CaseEval` with the best hand each `Seat` can make out of exactly two of
+    /// their cards and exactly three of the cards in the case, which are the cards on the
+    /// board, be it at the flop, turn or river.
+    ///
+    /// The `SeatEvals` hold a `holdem::seat::Seat` with the same number, so that the rest of
+    /// the analysis, such as `CaseEvals::chances()`, works the same as it does for hold'em.
+    #[must_use]
+    pub fn case_eval(&self, cycle: &PlayingCards) -> CaseEval {
+        let mut case_eval = CaseEval::default();
+        debug!("Case Eval: {cycle}");
+        for seat in &self.0 {
+            let eval = seat.eval_with_board(cycle);
+            debug!("   Player {} {}", seat.number, eval);
+            case_eval.push(SeatEval::new_from_eval(
+                holdem::seat::Seat::new(seat.number),
+                eval,
+            ));
+        }
+        case_eval
+    }
+
+    /// The split pot version of `case_eval`, with the best high hand and the best eight or
+    /// better low each `Seat` can make from the case.
+    #[must_use]
+    pub fn case_eval_hi_lo(&self, cycle: &PlayingCards) -> hi_lo::case_eval::CaseEval {
+        let mut case_eval = hi_lo::case_eval::CaseEval::default();
+        debug!("Case Eval Hi/Lo: {cycle}");
+        for seat in &self.0 {
+            let seat_eval = hi_lo::seat_eval::SeatEval::new(
+                seat.number,
+                seat.eval_with_board(cycle),
+                seat.hand.eval_low_with_board(cycle),
+            );
+            debug!("   {seat_eval}");
+            case_eval.push(seat_eval);
+        }
+        case_eval
+    }
+
+    #[must_use]
+    pub fn dealt(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for seat in self.iter() {
+            cards.append(&seat.to_playing_cards());
+        }
+        cards
+    }
+
+    #[must_use]
+    pub fn get(&self, seat: usize) -> Option<&Seat> {
+        self.0.get(seat)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Seat> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn len_for_player(&self, player: usize) -> Option<usize> {
+        self.0.get(player).map(card_slot::CardSlot::len)
+    }
+
+    #[must_use]
+    pub fn take_for_player(&self, player: usize, card: PlayingCard) -> bool {
+        match self.0.get(player) {
+            Some(seat) => seat.take(card),
+            None => false,
+        }
+    }
+
+    #[must_use]
+    pub fn fold_player(&self, player: usize) -> Option<PlayingCards> {
+        self.0.get(player).map(card_slot::CardSlot::fold)
+    }
+}
+
+impl CardSlot for Seats {
+    /// Deals the card to the first open slot, going around the table one card at a time
+    /// like a poker dealer would, so every `Seat` gets their first card before anyone
+    /// gets their second.
+    fn take(&self, card: PlayingCard) -> bool {
+        for slot in 0..OmahaHand::MAX_CARDS {
+            for seat in &self.0 {
+                if slot < seat.hand.size() && seat.len() == slot {
+                    return seat.take(card);
+                }
+            }
+        }
+        false
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for seat in &self.0 {
+            cards.append(&seat.fold());
+        }
+        cards
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.0.iter().all(CardSlot::is_dealt)
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.dealt()
+    }
+}
+
+impl fmt::Display for Seats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let joined = Itertools::join(&mut self.0.iter(), ", ");
+        write!(f, "[{joined}]")
+    }
+}
+
+impl From<Vec<Seat>> for Seats {
+    fn from(value: Vec<Seat>) -> Self {
+        Seats(value)
+    }
+}
+
+impl TryFrom<&str> for Seats {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Seats::from_str(value)?)
+    }
+}
+
+impl TryFrom<String> for Seats {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Seats::try_from(value.as_str())
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index.
+///
+/// Will return an `IndexError::Hand(HandError::InvalidIndex)` if the number of cards passed in
+/// isn't divisible by 4. (There must be four cards for each `Seat`.)
+impl FromStr for Seats {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Seats::try_from(PlayingCards::from_str(s)?)?)
+    }
+}
+
+/// Each `Seat` takes the next four cards, in order.
+///
+/// # Errors
+///
+/// Will throw a `HandError::InvalidIndex` if the number of cards passed in
+/// isn't divisible by 4. (There must be four cards for each `Seat`.)
+impl TryFrom<PlayingCards> for Seats {
+    type Error = HandError;
+
+    fn try_from(value: PlayingCards) -> Result<Self, Self::Error> {
+        Seats::deal_for_game(OmahaGame::Omaha, value)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod omaha_seats_tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let seats = Seats::seat(2);
+        let mut cards = PlayingCards::try_from("A♠ K♠ Q♠ J♠ T♠ 9♠ 8♠ 7♠ 6♠").unwrap();
+
+        for _ in 0..8 {
+            assert!(seats.take(cards.draw_one()));
+        }
+
+        assert!(seats.is_dealt());
+        assert!(!seats.take(cards.draw_one()));
+        assert_eq!(
+            "[Seat 0: A♠ Q♠ T♠ 8♠, Seat 1: K♠ J♠ 9♠ 7♠]",
+            seats.to_string()
+        );
+    }
+
+    #[test]
+    fn case_eval() {
+        let seats = Seats::from_index("A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣").unwrap();
+        let board = PlayingCards::try_from("A♦ K♦ 8♠ 8♥ 3♣").unwrap();
+
+        let case_eval = seats.case_eval(&board);
+        let winners = case_eval.winners();
+
+        assert_eq!(2, case_eval.seats().len());
+        assert_eq!(1, winners.seats().len());
+        assert_eq!(0, winners.seats()[0].seat.number);
+        assert_eq!("A♠ A♥ A♦ 8♠ 8♥", winners.seats()[0].eval.hand.to_string());
+    }
+
+    #[test]
+    fn case_eval_hi_lo() {
+        let seats = Seats::from_index("A♠ 2♥ K♦ K♣ A♦ A♣ Q♥ J♥").unwrap();
+        let board = PlayingCards::try_from("3♠ 4♥ 8♦ K♥ J♠").unwrap();
+
+        let case_eval = seats.case_eval_hi_lo(&board);
+
+        assert_eq!(vec![0], case_eval.high_winners());
+        assert_eq!(vec![0], case_eval.low_winners());
+        assert_eq!(Some(0), case_eval.scooper());
+        assert_eq!("8-4-3-2-A", case_eval.seats()[0].low.rank.to_string());
+        assert!(case_eval.seats()[1].low.is_none());
+    }
+
+    #[test]
+    fn take__for_game() {
+        let seats = Seats::seat_for_game(OmahaGame::BigO, 2);
+        let mut cards = PlayingCards::try_from("A♠ K♠ Q♠ J♠ T♠ 9♠ 8♠ 7♠ 6♠ 5♠ 4♠").unwrap();
+
+        for _ in 0..10 {
+            assert!(seats.take(cards.draw_one()));
+        }
+
+        assert!(seats.is_dealt());
+        assert!(!seats.take(cards.draw_one()));
+        assert_eq!(
+            "[Seat 0: A♠ Q♠ T♠ 8♠ 6♠, Seat 1: K♠ J♠ 9♠ 7♠ 5♠]",
+            seats.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index_for_game() {
+        let seats = Seats::from_index_for_game(
+            OmahaGame::SixCardOmaha,
+            "A♠ A♥ 7♦ 2♣ 3♣ 4♣ K♠ K♥ Q♦ J♣ T♣ 9♣",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "[Seat 0: A♠ A♥ 7♦ 2♣ 3♣ 4♣, Seat 1: K♠ K♥ Q♦ J♣ T♣ 9♣]",
+            seats.to_string()
+        );
+        assert_eq!(
+            HandError::InvalidIndex,
+            Seats::from_index_for_game(OmahaGame::FiveCardOmaha, "A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣")
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_index() {
+        let seats = Seats::from_index("A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣").unwrap();
+
+        assert_eq!(
+            "[Seat 0: A♠ A♥ 7♦ 2♣, Seat 1: K♠ K♥ Q♦ J♣]",
+            seats.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index__invalid_index() {
+        assert_eq!(
+            HandError::InvalidIndex,
+            Seats::from_index("A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_str__invalid_card() {
+        assert_eq!(
+            IndexError::invalid_card("KX", 5),
+            Seats::from_str("A♠ A♥ 7♦ 2♣ K♠ KX Q♦ J♣").unwrap_err()
+        );
+    }
+}
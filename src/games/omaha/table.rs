@@ -0,0 +1,541 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::outs::Outs;
+use crate::games::holdem::board::Board;
+use crate::games::holdem::case_evals::CaseEvals;
+use crate::games::omaha::omaha_game::OmahaGame;
+use crate::games::omaha::seats::Seats;
+use crate::games::{hi_lo, variant};
+use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::HandError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use wyz::FmtForward;
+
+/// The Omaha version of `holdem::table::Table`, where every `Seat` is dealt four cards and
+/// has to play exactly two of them with exactly three from the `Board`.
+///
+/// The `for_game` constructors deal five or six cards for the bigger `OmahaGames`, such as PLO5
+/// and Big O, with everything else working the same way.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Table {
+    pub players: Seats,
+    pub board: Board,
+}
+
+impl Table {
+    #[must_use]
+    pub fn seat(number: usize) -> Table {
+        Table {
+            players: Seats::seat(number),
+            board: Board::default(),
+        }
+    }
+
+    /// Takes a Card index string and returns a `Table` entity with the `Players`
+    /// and `Board` entities all having cards dealt to them.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if the cards passed in aren't valid and at least thirteen;
+    /// five for the `Board` and the remaining for the `Players`, which must be
+    /// divisible by 4.
+    pub fn from_index(index: &str) -> Result<Table, HandError> {
+        Table::try_from(index)
+    }
+
+    #[must_use]
+    pub fn seat_for_game(game: OmahaGame, number: usize) -> Table {
+        Table {
+            players: Seats::seat_for_game(game, number),
+            board: Board::default(),
+        }
+    }
+
+    /// The `OmahaGame` version of `from_index`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if the cards passed in aren't valid, and enough for the `Board` and
+    /// at least two `Players`, with the cards for the `Players` divisible by the
+    /// `OmahaGame's` hand size.
+    pub fn from_index_for_game(game: OmahaGame, index: &str) -> Result<Table, HandError> {
+        Table::deal_for_game(game, PlayingCards::try_from(index)?)
+    }
+
+    #[must_use]
+    pub fn sample_number(player_count: usize) -> Table {
+        Table::sample_number_for_game(OmahaGame::Omaha, player_count)
+    }
+
+    #[must_use]
+    pub fn sample_number_for_game(game: OmahaGame, player_count: usize) -> Table {
+        let table = Table::seat_for_game(game, player_count);
+        let mut cards = PlayingCards::deck_shuffled();
+
+        for _ in 0..(player_count * game.hand_size()) + 5 {
+            table.take(cards.draw_one());
+        }
+        table
+    }
+
+    pub fn chances_at_flop(&self) -> Chances {
+        self.eval_at_flop().chances()
+    }
+
+    pub fn chances_at_turn(&self) -> Chances {
+        self.eval_at_turn().chances()
+    }
+
+    pub fn chances_at_river(&self) -> Chances {
+        self.eval_at_river().chances()
+    }
+
+    pub fn dealt(&self) -> PlayingCards {
+        PlayingCards::default()
+            .combine(&self.players.dealt())
+            .combine(&self.board.dealt())
+    }
+
+    /// Permutates through every possible turn and river based upon a specific `Flop` and
+    /// all the remaining `PlayingCards` that are not in play.
+    ///
+    /// **NOTE** Like `holdem::table::Table`, this doesn't take into account any burned cards.
+    pub fn eval_at_flop(&self) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        for cycle in self.cases_at_flop() {
+            evals.push(self.players.case_eval(&cycle));
+        }
+        evals
+    }
+
+    pub fn eval_at_turn(&self) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        for cycle in self.cases_at_turn() {
+            evals.push(self.players.case_eval(&cycle));
+        }
+        evals
+    }
+
+    pub fn eval_at_river(&self) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        for cycle in self.cases_at_river() {
+            evals.push(self.players.case_eval(&cycle));
+        }
+        evals
+    }
+
+    pub fn chances_hi_lo_at_flop(&self) -> hi_lo::chances::Chances {
+        self.eval_hi_lo_at_flop().chances()
+    }
+
+    pub fn chances_hi_lo_at_turn(&self) -> hi_lo::chances::Chances {
+        self.eval_hi_lo_at_turn().chances()
+    }
+
+    pub fn chances_hi_lo_at_river(&self) -> hi_lo::chances::Chances {
+        self.eval_hi_lo_at_river().chances()
+    }
+
+    /// The Omaha Hi/Lo, eight or better, version of `eval_at_flop`.
+    pub fn eval_hi_lo_at_flop(&self) -> hi_lo::case_evals::CaseEvals {
+        self.eval_hi_lo(self.cases_at_flop())
+    }
+
+    pub fn eval_hi_lo_at_turn(&self) -> hi_lo::case_evals::CaseEvals {
+        self.eval_hi_lo(self.cases_at_turn())
+    }
+
+    pub fn eval_hi_lo_at_river(&self) -> hi_lo::case_evals::CaseEvals {
+        self.eval_hi_lo(self.cases_at_river())
+    }
+
+    pub fn remaining_at_flop(&self) -> PlayingCards {
+        self.remaining()
+            .combine(&self.board.turn.to_playing_cards())
+            .combine(&self.board.river.to_playing_cards())
+    }
+
+    pub fn remaining_at_turn(&self) -> PlayingCards {
+        self.remaining()
+            .combine(&self.board.river.to_playing_cards())
+    }
+
+    /// Returns the cards on the next street that would have each player winning or tied, once
+    /// the turn or the river is all that's left to come. For the hi/lo games that's any card
+    /// that gets them a share of either half of the pot.
+    #[must_use]
+    pub fn outs(&self, game: OmahaGame) -> Outs {
+        self.to_variant(game).outs()
+    }
+
+    /// Returns the `PokerVariant` version of the `Table`, played as the `OmahaGame`.
+    #[must_use]
+    pub fn to_variant(&self, game: OmahaGame) -> variant::table::Table<OmahaGame> {
+        variant::table::Table {
+            variant: game,
+            hands: self
+                .players
+                .iter()
+                .map(CardSlot::to_playing_cards)
+                .collect(),
+            board: self.board.to_playing_cards(),
+            dead: PlayingCards::default(),
+        }
+    }
+
+    //region private functions
+
+    /// The last five cards are dealt to the `Board` and the rest to the `Players`.
+    fn deal_for_game(game: OmahaGame, cards: PlayingCards) -> Result<Table, HandError> {
+        let mut cards = cards;
+        // There have to be enough cards for the board and two players to be a valid Table.
+        if cards.len() < (game.hand_size() * 2) + 5 {
+            Err(HandError::NotEnoughCards)
+        } else if (cards.len() - 5) % game.hand_size() != 0 {
+            Err(HandError::InvalidCardCount)
+        } else {
+            let mut table = Table::default();
+            table
+                .board
+                .take_from_playing_cards(&cards.draw_from_the_bottom(5));
+            table.players = Seats::deal_for_game(game, cards)?;
+            Ok(table)
+        }
+    }
+
+    /// Every possible board once the turn and river are dealt from the remaining cards.
+    fn cases_at_flop(&self) -> Vec<PlayingCards> {
+        if !self.board.flop.is_dealt() {
+            return Vec::new();
+        }
+        self.remaining_at_flop()
+            .combinations(2)
+            .map(|v| PlayingCards::from(v).combine(&self.board.flop.to_playing_cards()))
+            .collect()
+    }
+
+    /// Every possible board once the river is dealt from the remaining cards.
+    fn cases_at_turn(&self) -> Vec<PlayingCards> {
+        if !self.board.turn.is_dealt() {
+            return Vec::new();
+        }
+        self.remaining_at_turn()
+            .iter()
+            .map(|card| {
+                PlayingCards::from(*card)
+                    .combine(&self.board.flop.to_playing_cards())
+                    .combine(&self.board.turn.to_playing_cards())
+            })
+            .collect()
+    }
+
+    fn cases_at_river(&self) -> Vec<PlayingCards> {
+        if !self.board.river.is_dealt() {
+            return Vec::new();
+        }
+        vec![self.board.to_playing_cards()]
+    }
+
+    fn eval_hi_lo(&self, cases: Vec<PlayingCards>) -> hi_lo::case_evals::CaseEvals {
+        let mut evals = hi_lo::case_evals::CaseEvals::default();
+        for cycle in cases {
+            evals.push(self.players.case_eval_hi_lo(&cycle));
+        }
+        evals
+    }
+
+    //endregion
+}
+
+impl CardSlot for Table {
+    fn take(&self, card: PlayingCard) -> bool {
+        if self.players.is_dealt() {
+            self.board.take(card)
+        } else {
+            self.players.take(card)
+        }
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        cards.append(&self.players.fold());
+        cards.append(&self.board.fold());
+        cards
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.players.is_dealt() && self.board.is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        cards.append(&self.players.to_playing_cards());
+        cards.append(&self.board.to_playing_cards());
+        cards
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("PLAYERS: {}", self.players)).fmt_display());
+        out.entry(&(format!("BOARD: {}", self.board)).fmt_display());
+
+        out.finish()
+    }
+}
+
+impl TryFrom<&str> for Table {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Table::try_from(PlayingCards::try_from(value)?)
+    }
+}
+
+impl TryFrom<String> for Table {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Table::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<PlayingCards> for Table {
+    type Error = HandError;
+
+    /// The last five cards are dealt to the `Board` and the rest to the `Players`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if there aren't at least thirteen cards, or if the number of
+    /// cards for the `Players` isn't divisible by 4.
+    fn try_from(value: PlayingCards) -> Result<Self, Self::Error> {
+        Table::deal_for_game(OmahaGame::Omaha, value)
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index, or
+/// an `IndexError::Hand` if the cards can't be dealt out to a `Table`.
+impl FromStr for Table {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Table::try_from(PlayingCards::from_str(s)?)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod omaha_table_tests {
+    use super::*;
+
+    const INDEX: &str = "A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣ A♦ K♦ 8♠ 8♥ 3♣";
+
+    #[test]
+    fn from_index() {
+        let table = Table::from_index(INDEX).unwrap();
+
+        assert_eq!(INDEX, table.dealt().to_string());
+        assert_eq!(
+            "[Seat 0: A♠ A♥ 7♦ 2♣, Seat 1: K♠ K♥ Q♦ J♣]",
+            table.players.to_string()
+        );
+        assert_eq!(
+            "[FLOP:  A♦ K♦ 8♠, TURN:  8♥, RIVER: 3♣]",
+            table.board.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index__not_enough_cards() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            Table::from_index("A♠ A♥ 7♦ 2♣ A♦ K♦ 8♠ 8♥").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_index__invalid_card_count() {
+        assert_eq!(
+            HandError::InvalidCardCount,
+            Table::from_index("A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ A♦ K♦ 8♠ 8♥ 3♣ 4♣ 5♣").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_str__invalid_card() {
+        assert_eq!(
+            IndexError::invalid_card("3X", 12),
+            Table::from_str("A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣ A♦ K♦ 8♠ 8♥ 3X").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn take() {
+        let table = Table::seat(2);
+        let mut cards = PlayingCards::try_from(INDEX).unwrap();
+
+        for _ in 0..8 {
+            table.take(cards.draw_one());
+        }
+        assert!(table.players.is_dealt());
+        assert!(!table.board.is_dealt());
+
+        for _ in 0..5 {
+            table.take(cards.draw_one());
+        }
+        assert!(table.is_dealt());
+        assert_eq!(
+            "[Seat 0: A♠ 7♦ K♠ Q♦, Seat 1: A♥ 2♣ K♥ J♣]",
+            table.players.to_string()
+        );
+    }
+
+    #[test]
+    fn eval_at_flop() {
+        let table = Table::from_index(INDEX).unwrap();
+
+        // 52 - 8 - 3 = 41 cards left, so 41 choose 2 turns and rivers.
+        assert_eq!(820, table.eval_at_flop().len());
+    }
+
+    #[test]
+    fn eval_at_turn() {
+        let table = Table::from_index(INDEX).unwrap();
+
+        assert_eq!(40, table.eval_at_turn().len());
+    }
+
+    #[test]
+    fn chances_at_river() {
+        let table = Table::from_index(INDEX).unwrap();
+
+        let winners = table.eval_at_river().winners();
+
+        assert_eq!(1, winners.seats().len());
+        assert_eq!(0, winners.seats()[0].seat.number);
+        assert_eq!(1, table.eval_at_river().len());
+    }
+
+    #[test]
+    fn chances_at_turn() {
+        // Seat 0 has a full house, and seat 1 can only win with the last king.
+        let table = Table::from_index(INDEX).unwrap();
+
+        let chances = table.chances_at_turn();
+
+        assert!(chances.get(0) > chances.get(1));
+    }
+
+    #[test]
+    fn chances_hi_lo_at_turn() {
+        let table = Table::from_index("A♠ 2♥ K♦ K♣ A♦ A♣ Q♥ J♥ 3♠ 4♥ 8♦ K♥ 9♣").unwrap();
+
+        let chances = table.chances_hi_lo_at_turn();
+
+        // Seat 0 has top set and the nut low, and seat 1 can't make a low, so seat 1 can only
+        // take the high half with one of the six hearts that make a flush without pairing
+        // the board.
+        assert_eq!(100.0, chances.low.get(0));
+        assert_eq!(0.0, chances.low.get(1));
+        assert_eq!(15.0, chances.high.get(1));
+        assert_eq!(85.0, chances.scoop.get(0));
+        assert_eq!(92.5, chances.equity.get(0));
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_hi_lo_at_turn__big_o() {
+        let table = Table::from_index_for_game(
+            OmahaGame::BigO,
+            "A♠ 2♥ K♦ K♣ 7♣ A♦ A♣ Q♥ J♥ 5♥ 3♠ 4♥ 8♦ K♥ 9♣",
+        )
+        .unwrap();
+
+        let chances = table.chances_hi_lo_at_turn();
+
+        // With a fifth card, seat 1 has a low too, and takes it with a deuce on the river.
+        assert_eq!(
+            "[Seat 0: A♠ 2♥ K♦ K♣ 7♣, Seat 1: A♦ A♣ Q♥ J♥ 5♥]",
+            table.players.to_string()
+        );
+        assert!(chances.low.get(0) > chances.low.get(1));
+        assert!(chances.low.get(1) > 0.0);
+        assert!(chances.equity.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_at_flop__six_card_omaha() {
+        let table = Table::from_index_for_game(
+            OmahaGame::SixCardOmaha,
+            "A♠ A♥ 7♦ 2♣ 3♣ 4♦ K♠ K♥ Q♦ J♣ 6♣ 5♦ A♦ K♦ 8♠ 8♥ 3♥",
+        )
+        .unwrap();
+
+        let chances = table.chances_at_flop();
+
+        // Top set against middle set.
+        assert!(chances.get(0) > chances.get(1));
+        assert!(chances.keeping_it_100());
+    }
+
+    #[test]
+    fn from_index_for_game__errors() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            Table::from_index_for_game(OmahaGame::FiveCardOmaha, INDEX).unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidCardCount,
+            Table::from_index_for_game(
+                OmahaGame::FiveCardOmaha,
+                "A♠ A♥ 7♦ 2♣ K♠ K♥ Q♦ J♣ 2♦ 3♦ 4♦ A♦ K♦ 8♠ 8♥ 3♣"
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn sample_number_for_game() {
+        let table = Table::sample_number_for_game(OmahaGame::FiveCardOmaha, 3);
+
+        assert_eq!(20, table.dealt().len());
+        assert!(table.is_dealt());
+    }
+
+    #[test]
+    fn eval_hi_lo_at_flop() {
+        let table = Table::from_index(INDEX).unwrap();
+
+        assert_eq!(820, table.eval_hi_lo_at_flop().len());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "[PLAYERS: [], BOARD: [FLOP:  __ __ __, TURN:  __, RIVER: __]]",
+            Table::default().to_string()
+        );
+    }
+
+    #[test]
+    fn outs() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ K♠ A♥ K♥ 7♦ Q♦ 2♣ J♣ A♦ K♦ 8♠ 8♥");
+
+        let outs = table.outs(OmahaGame::Omaha);
+
+        assert_eq!(
+            vec![PlayingCard::from("K♣")],
+            outs.get_as_poker_cards(1).to_vec()
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::games::holdem;
+use crate::games::holdem::case_eval::CaseEval;
+use crate::games::holdem::deal::StageInTheHand;
+use crate::games::pineapple::pineapple_game::PineappleGame;
+use crate::games::pineapple::table::Table;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::single_card::SingleCard;
+use ckc_rs::HandError;
+
+/// The Pineapple version of `holdem::deal::Deal`, which deals the hand with a hold'em `Deal`
+/// and adds each player's third card, and a discard step at the `StageInTheHand` the
+/// `PineappleGame` calls for.
+#[derive(Clone, Debug)]
+pub struct Deal {
+    pub game: PineappleGame,
+    pub deal: holdem::deal::Deal,
+    extras: Vec<SingleCard>,
+    discards: Vec<SingleCard>,
+}
+
+impl Deal {
+    #[must_use]
+    pub fn new(game: PineappleGame) -> Deal {
+        Deal {
+            game,
+            deal: holdem::deal::Deal::new(),
+            extras: Vec::new(),
+            discards: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn from_deck(game: PineappleGame, deck: PlayingCards) -> Option<Deal> {
+        holdem::deal::Deal::from_deck(deck).map(|deal| Deal {
+            deal,
+            ..Deal::new(game)
+        })
+    }
+
+    /// Deals each seat their two hold'em hole cards, and then goes around the table once more
+    /// with their third card.
+    pub fn deal(&mut self, seats: usize) {
+        self.deal.deal(seats);
+        self.extras = (0..seats)
+            .map(|_| SingleCard::new(self.deal.deck.draw_one()))
+            .collect();
+        self.discards = vec![SingleCard::default(); seats];
+    }
+
+    /// Has the player in the seat throw away the card, which is mucked with the burned cards.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::Incomplete` if the hand isn't at the `StageInTheHand` where the
+    /// `PineappleGame` has the players discard, as well as any of the errors from
+    /// `Table::discard()`.
+    pub fn discard(&mut self, seat: usize, card: PlayingCard) -> Result<(), HandError> {
+        if self.get_stage() != self.game.discards_at() {
+            return Err(HandError::Incomplete);
+        }
+        let mut table = self.table();
+        table.discard(seat, card)?;
+        self.deal.table = table.holdem;
+        self.extras = table.extras;
+        self.discards = table.discards;
+        self.deal.muck(card);
+        Ok(())
+    }
+
+    pub fn flop(&mut self) -> PlayingCards {
+        self.deal.flop()
+    }
+
+    pub fn get_stage(&self) -> StageInTheHand {
+        self.deal.get_stage()
+    }
+
+    pub fn number_of_remaining_cards(&self) -> usize {
+        self.deal.number_of_remaining_cards()
+    }
+
+    /// Returns the Pineapple `Table` as it's been dealt so far.
+    #[must_use]
+    pub fn table(&self) -> Table {
+        Table {
+            game: self.game,
+            holdem: self.deal.table.clone(),
+            extras: self.extras.clone(),
+            discards: self.discards.clone(),
+        }
+    }
+
+    pub fn turn(&mut self) -> PlayingCard {
+        self.deal.turn()
+    }
+
+    pub fn river(&mut self) -> PlayingCard {
+        self.deal.river()
+    }
+
+    /// Returns the `SeatEvals` of the winners, or an empty `CaseEval` if the deal isn't at the
+    /// river yet.
+    pub fn winner(&self) -> CaseEval {
+        if self.get_stage() != StageInTheHand::TheRiver {
+            return CaseEval::default();
+        }
+        self.table().eval_at_river().winners()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod pineapple_deal_tests {
+    use super::*;
+
+    const INDEX: &str = "2S AS 8H KS 8D 2D 7C 3C KD 9S 4C 5H 3H 6H 5D JS AC 6S 8S 7S JC 7H JD TS QS TC JH QD 6C 9C QC AH 4S 5C 4H 5S 4D QH 2C 2H AD 9D TH KH 7D KC 3S 6D 8C 3D 9H TD";
+
+    fn dealt(game: PineappleGame) -> Deal {
+        let mut deal = Deal::from_deck(game, PlayingCards::try_from(INDEX).unwrap()).unwrap();
+        deal.deal(2);
+        deal
+    }
+
+    #[test]
+    fn deal() {
+        let deal = dealt(PineappleGame::default());
+
+        assert_eq!(StageInTheHand::Deal, deal.get_stage());
+        assert_eq!(45, deal.number_of_remaining_cards());
+        assert_eq!(
+            "[Pineapple, PLAYERS: [Seat 0: A♠ K♠ 2♦, Seat 1: 8♥ 8♦ 7♣], BOARD: [FLOP:  __ __ __, TURN:  __, RIVER: __]]",
+            deal.table().to_string()
+        );
+    }
+
+    #[test]
+    fn discard() {
+        let mut deal = dealt(PineappleGame::default());
+
+        deal.discard(0, PlayingCard::from("2♦")).unwrap();
+        deal.discard(1, PlayingCard::from("7♣")).unwrap();
+        deal.flop();
+
+        assert!(deal.table().has_discarded());
+        assert_eq!("2♦ 7♣", deal.deal.table.dead.to_string());
+        assert_eq!(41, deal.number_of_remaining_cards());
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(0, PlayingCard::from("A♠")).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn discard__crazy_pineapple() {
+        let mut deal = dealt(PineappleGame::CrazyPineapple);
+
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(0, PlayingCard::from("2♦")).unwrap_err()
+        );
+
+        deal.flop();
+        deal.discard(0, PlayingCard::from("2♦")).unwrap();
+        deal.discard(1, PlayingCard::from("7♣")).unwrap();
+        deal.turn();
+        deal.river();
+        let winner = deal.winner();
+
+        assert_eq!(1, winner.len());
+        assert_eq!(0, winner.seats()[0].seat.number);
+    }
+
+    #[test]
+    fn winner__not_at_the_river() {
+        let deal = dealt(PineappleGame::default());
+
+        assert!(deal.winner().is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod deal;
+pub mod pineapple_game;
+pub mod table;
@@ -0,0 +1,72 @@
+use crate::games::holdem::deal::StageInTheHand;
+use crate::games::variant::showdown::Showdown;
+use crate::games::variant::PokerVariant;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The three hole card versions of hold'em, where each player has to throw one of their cards
+/// away, and then plays the two that are left exactly like hold'em.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum PineappleGame {
+    /// [Pineapple](https://en.wikipedia.org/wiki/Pineapple_(poker)), where the discard is made
+    /// before the flop.
+    #[default]
+    Pineapple,
+    /// Crazy Pineapple, where the discard is made after the flop.
+    CrazyPineapple,
+}
+
+impl PineappleGame {
+    /// Returns the `StageInTheHand` where the players have to make their discard.
+    #[must_use]
+    pub fn discards_at(&self) -> StageInTheHand {
+        match self {
+            PineappleGame::Pineapple => StageInTheHand::Deal,
+            PineappleGame::CrazyPineapple => StageInTheHand::Flop,
+        }
+    }
+}
+
+/// Players are dealt three cards and throw one of them away, so it's the two that they keep
+/// that are played at the showdown.
+impl PokerVariant for PineappleGame {
+    fn hole_cards(&self) -> usize {
+        3
+    }
+
+    fn streets(&self) -> Vec<usize> {
+        vec![3, 1, 1]
+    }
+
+    fn showdown(&self) -> Showdown {
+        Showdown::High
+    }
+
+    fn discards(&self) -> usize {
+        1
+    }
+
+    fn discards_after(&self) -> usize {
+        match self.discards_at() {
+            StageInTheHand::Flop => 3,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod pineapple_pineapple_game_tests {
+    use super::*;
+
+    #[test]
+    fn discards_at() {
+        assert_eq!(StageInTheHand::Deal, PineappleGame::default().discards_at());
+        assert_eq!(
+            StageInTheHand::Flop,
+            PineappleGame::CrazyPineapple.discards_at()
+        );
+        assert_eq!(0, PineappleGame::default().discards_after());
+        assert_eq!(3, PineappleGame::CrazyPineapple.discards_after());
+    }
+}
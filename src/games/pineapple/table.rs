@@ -0,0 +1,575 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::outs::Outs;
+use crate::games::holdem::case_evals::CaseEvals;
+use crate::games::holdem::seats::Seats;
+use crate::games::pineapple::pineapple_game::PineappleGame;
+use crate::games::variant::PokerVariant;
+use crate::games::{holdem, variant};
+use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::hole_cards::HoleCards;
+use crate::types::slots::single_card::SingleCard;
+use ckc_rs::{HandError, PokerCard};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use wyz::FmtForward;
+
+/// The Pineapple version of `holdem::table::Table`, where every seat is dealt three cards and
+/// throws one of them away, before the flop in Pineapple and after it in Crazy Pineapple.
+///
+/// The first two cards each player is dealt are held in a hold'em `Table`, with the third off to
+/// the side until they discard. Every discard is dead in the hold'em `Table`, so once everyone
+/// has discarded the odds are worked out exactly like hold'em, without the discards ever coming
+/// on a later street. Until then, each seat that still has three cards is assumed to make their
+/// `best_discards()`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Table {
+    pub game: PineappleGame,
+    pub holdem: holdem::table::Table,
+    /// The third card for each seat, until they discard.
+    pub extras: Vec<SingleCard>,
+    /// The card each seat threw away, once they have.
+    pub discards: Vec<SingleCard>,
+}
+
+impl Table {
+    #[must_use]
+    pub fn seat(game: PineappleGame, number: usize) -> Table {
+        Table {
+            game,
+            holdem: holdem::table::Table::seat(number),
+            extras: vec![SingleCard::default(); number],
+            discards: vec![SingleCard::default(); number],
+        }
+    }
+
+    /// Takes a Card index string and returns a `Table` entity with the players and `Board`
+    /// all having cards dealt to them.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError` if the cards passed in aren't valid and at least eleven;
+    /// five for the `Board` and the remaining for the players, which must be
+    /// divisible by 3.
+    pub fn from_index(game: PineappleGame, index: &str) -> Result<Table, HandError> {
+        let mut table = Table::try_from(index)?;
+        table.game = game;
+        Ok(table)
+    }
+
+    pub fn chances_at_flop(&self) -> Chances {
+        self.eval_at_flop().chances()
+    }
+
+    pub fn chances_at_turn(&self) -> Chances {
+        self.eval_at_turn().chances()
+    }
+
+    pub fn chances_at_river(&self) -> Chances {
+        self.eval_at_river().chances()
+    }
+
+    pub fn dealt(&self) -> PlayingCards {
+        self.holdem.dealt().combine(&self.extra_cards())
+    }
+
+    /// Has the player in the seat throw away the card, which is then dead.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` if there's no player in the seat,
+    /// `HandError::Incomplete` if it's Crazy Pineapple and the flop hasn't been dealt yet, and
+    /// `HandError::InvalidCard` if the player isn't holding the card or has already discarded.
+    pub fn discard(&mut self, seat: usize, card: PlayingCard) -> Result<(), HandError> {
+        let (Some(hole_cards), Some(extra)) =
+            (self.holdem.players.get(seat), self.extras.get(seat))
+        else {
+            return Err(HandError::InvalidIndex);
+        };
+        if self.holdem.board.dealt().len() < self.game.discards_after() {
+            return Err(HandError::Incomplete);
+        }
+        if card.is_blank() || !extra.is_dealt() {
+            return Err(HandError::InvalidCard);
+        }
+        if hole_cards.get_first_card() == card {
+            hole_cards.take_first_card(extra.get());
+        } else if hole_cards.get_second_card() == card {
+            hole_cards.take_second_card(extra.get());
+        } else if extra.get() != card {
+            return Err(HandError::InvalidCard);
+        }
+        extra.fold();
+        self.discards[seat].take(card);
+        self.holdem.dead.insert(card);
+        Ok(())
+    }
+
+    /// Returns the card that each seat still holding three cards should throw away, going
+    /// by their odds of winning at the flop.
+    ///
+    /// Each of them starts off keeping the two cards that make the best hand on the flop. Then,
+    /// going around the table, each one switches to whichever discard gives them the best
+    /// `Chances` against what everyone else is holding at that point.
+    pub fn best_discards(&self) -> Vec<(usize, PlayingCard)> {
+        self.best_holds()
+            .into_iter()
+            .enumerate()
+            .filter(|(seat, _)| !self.has_discarded_in(*seat))
+            .map(|(seat, (card, _))| (seat, card))
+            .collect()
+    }
+
+    /// Permutates through every possible turn and river, once everyone has discarded, reusing
+    /// `holdem::table::Table::eval_at_flop()`.
+    ///
+    /// Anyone that hasn't discarded yet is evaluated as if they made their `best_discards()`
+    /// card, so in Crazy Pineapple this gives the odds on the flop before the discards.
+    pub fn eval_at_flop(&self) -> CaseEvals {
+        self.to_holdem()
+            .map(|table| table.eval_at_flop())
+            .unwrap_or_default()
+    }
+
+    pub fn eval_at_turn(&self) -> CaseEvals {
+        self.to_holdem()
+            .map(|table| table.eval_at_turn())
+            .unwrap_or_default()
+    }
+
+    pub fn eval_at_river(&self) -> CaseEvals {
+        self.to_holdem()
+            .map(|table| table.eval_at_river())
+            .unwrap_or_default()
+    }
+
+    /// Returns true once every seat is down to two cards.
+    #[must_use]
+    pub fn has_discarded(&self) -> bool {
+        (0..self.discards.len()).all(|seat| self.has_discarded_in(seat))
+    }
+
+    /// Returns true once the player in the seat has thrown away their card.
+    #[must_use]
+    pub fn has_discarded_in(&self, seat: usize) -> bool {
+        self.discards.get(seat).map_or(false, CardSlot::is_dealt)
+    }
+
+    /// Returns every discard that the player in the seat can make, along with the `HoleCards`
+    /// they'd be left with, or nothing once they've discarded.
+    #[must_use]
+    pub fn holdings(&self, seat: usize) -> Vec<(PlayingCard, HoleCards)> {
+        let (Some(hole_cards), Some(extra)) =
+            (self.holdem.players.get(seat), self.extras.get(seat))
+        else {
+            return Vec::new();
+        };
+        if !extra.is_dealt() {
+            return Vec::new();
+        }
+        let first = hole_cards.get_first_card();
+        let second = hole_cards.get_second_card();
+        let extra = extra.get();
+        vec![
+            (first, HoleCards::new(second, extra)),
+            (second, HoleCards::new(first, extra)),
+            (extra, HoleCards::new(first, second)),
+        ]
+    }
+
+    /// Returns the hold'em `Table` that's left once the discards are made, with every discard
+    /// dead. Anyone still holding three cards makes their `best_discards()` card.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::Incomplete` if someone still has to discard and there isn't a flop
+    /// to decide what they'd throw away.
+    pub fn to_holdem(&self) -> Result<holdem::table::Table, HandError> {
+        if self.has_discarded() {
+            return Ok(self.holdem.clone());
+        }
+        if !self.holdem.board.flop.is_dealt() {
+            return Err(HandError::Incomplete);
+        }
+        Ok(self.holdem_table(&self.best_holds()))
+    }
+
+    /// Returns the cards on the next street that would have each player winning or tied, once
+    /// everyone has discarded and the turn or the river is all that's left to come.
+    #[must_use]
+    pub fn outs(&self) -> Outs {
+        self.to_variant().outs()
+    }
+
+    /// Returns the `PokerVariant` version of the `Table`, with each seat that hasn't discarded
+    /// yet still holding all three of their cards, and the discards dead.
+    #[must_use]
+    pub fn to_variant(&self) -> variant::table::Table<PineappleGame> {
+        variant::table::Table {
+            variant: self.game,
+            hands: self
+                .holdem
+                .players
+                .iter()
+                .zip(&self.extras)
+                .map(|(seat, extra)| {
+                    let mut hand = seat.to_playing_cards();
+                    hand.insert(extra.get());
+                    hand
+                })
+                .collect(),
+            board: self.holdem.board.to_playing_cards(),
+            dead: self.holdem.dead.clone(),
+        }
+    }
+
+    //region private functions
+
+    /// The discard and `HoleCards` that each seat ends up with after `best_discards()`. Anyone
+    /// who has already discarded keeps what they're holding, with a blank discard, since their
+    /// card is already dead.
+    fn best_holds(&self) -> Vec<(PlayingCard, HoleCards)> {
+        if !self.holdem.board.flop.is_dealt() {
+            return Vec::new();
+        }
+        let mut holds: Vec<(PlayingCard, HoleCards)> = (0..self.extras.len())
+            .map(|seat| {
+                self.holdings(seat)
+                    .into_iter()
+                    .max_by_key(|(_, hole_cards)| self.holdem.board.eval_against_flop(hole_cards))
+                    .unwrap_or_else(|| {
+                        let held = self.holdem.players.get(seat).cloned().unwrap_or_default();
+                        (PlayingCard::default(), held)
+                    })
+            })
+            .collect();
+        for seat in 0..self.extras.len() {
+            let mut best: Option<(f32, (PlayingCard, HoleCards))> = None;
+            for holding in self.holdings(seat) {
+                holds[seat] = holding.clone();
+                let chances = self.holdem_table(&holds).chances_at_flop().get(seat);
+                if best.as_ref().map_or(true, |(most, _)| chances > *most) {
+                    best = Some((chances, holding));
+                }
+            }
+            if let Some((_, holding)) = best {
+                holds[seat] = holding;
+            }
+        }
+        holds
+    }
+
+    fn extra_cards(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for extra in &self.extras {
+            cards.insert(extra.get());
+        }
+        cards
+    }
+
+    /// The hold'em `Table` with each seat holding the `HoleCards` they're given, and the cards
+    /// they throw away dead along with anything already discarded.
+    fn holdem_table(&self, holds: &[(PlayingCard, HoleCards)]) -> holdem::table::Table {
+        let mut players = Seats::default();
+        let mut dead = self.holdem.dead.clone();
+        for (discard, hole_cards) in holds {
+            players.add(hole_cards.clone());
+            dead.insert(*discard);
+        }
+        holdem::table::Table {
+            players,
+            board: self.holdem.board.clone(),
+            dead,
+        }
+    }
+
+    //endregion
+}
+
+impl CardSlot for Table {
+    /// Deals each seat their two `HoleCards` and then their third card, one at a time around
+    /// the table, before moving on to the `Board`.
+    fn take(&self, card: PlayingCard) -> bool {
+        if !self.holdem.players.is_dealt() {
+            return self.holdem.players.take(card);
+        }
+        if !self.discards.iter().any(CardSlot::is_dealt) {
+            if let Some(extra) = self.extras.iter().find(|extra| !extra.is_dealt()) {
+                return extra.take(card);
+            }
+        }
+        self.holdem.board.take(card)
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let mut cards = self.holdem.fold();
+        for extra in &self.extras {
+            cards.append(&extra.fold());
+        }
+        cards
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.holdem.is_dealt()
+            && (0..self.extras.len())
+                .all(|seat| self.extras[seat].is_dealt() || self.has_discarded_in(seat))
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.holdem.to_playing_cards().combine(&self.extra_cards())
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let players = self
+            .holdem
+            .players
+            .iter()
+            .zip(&self.extras)
+            .map(|(seat, extra)| {
+                let mut cards = seat.to_playing_cards();
+                cards.insert(extra.get());
+                format!("Seat {}: {cards}", seat.number)
+            })
+            .join(", ");
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("{}", self.game)).fmt_display());
+        out.entry(&(format!("PLAYERS: [{players}]")).fmt_display());
+        out.entry(&(format!("BOARD: {}", self.holdem.board)).fmt_display());
+
+        out.finish()
+    }
+}
+
+impl TryFrom<&str> for Table {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Table::from_str(value)?)
+    }
+}
+
+impl TryFrom<String> for Table {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Table::try_from(value.as_str())
+    }
+}
+
+/// The last five cards are dealt to the `Board` and the rest to the players, three at a time,
+/// for a game of Pineapple.
+///
+/// # Errors
+///
+/// Throws a `HandError::NotEnoughCards` if there aren't at least eleven cards, or a
+/// `HandError::InvalidIndex` if the number of cards for the players isn't divisible by 3.
+impl TryFrom<PlayingCards> for Table {
+    type Error = HandError;
+
+    fn try_from(value: PlayingCards) -> Result<Self, Self::Error> {
+        let mut cards = value;
+        if cards.len() < 11 {
+            return Err(HandError::NotEnoughCards);
+        }
+        let board = cards.draw_from_the_bottom(5);
+        if cards.len() % 3 != 0 {
+            return Err(HandError::InvalidIndex);
+        }
+        let table = Table::seat(PineappleGame::default(), cards.len() / 3);
+        for seat in 0..table.extras.len() {
+            let _ = table.holdem.players.take_for_player(seat, cards.draw_one());
+            let _ = table.holdem.players.take_for_player(seat, cards.draw_one());
+            table.extras[seat].take(cards.draw_one());
+        }
+        table.holdem.board.take_from_playing_cards(&board);
+        Ok(table)
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index, or
+/// an `IndexError::Hand` if the cards can't be dealt out to a `Table`.
+impl FromStr for Table {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Table::try_from(PlayingCards::from_str(s)?)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod pineapple_table_tests {
+    use super::*;
+
+    const INDEX: &str = "A♠ K♠ 2♦ 8♥ 8♦ 7♣ K♦ 9♠ 4♣ 3♥ 5♦";
+
+    #[test]
+    fn from_index() {
+        let table = Table::from_index(PineappleGame::CrazyPineapple, INDEX).unwrap();
+
+        assert_eq!(
+            "[CrazyPineapple, PLAYERS: [Seat 0: A♠ K♠ 2♦, Seat 1: 8♥ 8♦ 7♣], BOARD: [FLOP:  K♦ 9♠ 4♣, TURN:  3♥, RIVER: 5♦]]",
+            table.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index__errors() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            Table::from_index(PineappleGame::default(), "A♠ K♠ 2♦ 8♥ 8♦ K♦ 9♠ 4♣ 3♥ 5♦")
+                .unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidIndex,
+            Table::from_index(
+                PineappleGame::default(),
+                "A♠ K♠ 2♦ 8♥ 8♦ 7♣ 6♣ K♦ 9♠ 4♣ 3♥ 5♦"
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn take() {
+        let table = Table::seat(PineappleGame::default(), 2);
+
+        table.take_from_index("A♠ 8♥ K♠ 8♦ 2♦ 7♣ K♦ 9♠ 4♣");
+
+        assert!(!table.is_dealt());
+        assert_eq!(
+            "[Pineapple, PLAYERS: [Seat 0: A♠ K♠ 2♦, Seat 1: 8♥ 8♦ 7♣], BOARD: [FLOP:  K♦ 9♠ 4♣, TURN:  __, RIVER: __]]",
+            table.to_string()
+        );
+    }
+
+    #[test]
+    fn discard() {
+        let mut table = Table::seat(PineappleGame::CrazyPineapple, 2);
+        table.take_from_index("A♠ 8♥ K♠ 8♦ 2♦ 7♣");
+
+        assert_eq!(
+            HandError::Incomplete,
+            table.discard(0, PlayingCard::from("2♦")).unwrap_err()
+        );
+
+        table.take_from_index("K♦ 9♠ 4♣");
+
+        assert_eq!(
+            HandError::InvalidIndex,
+            table.discard(2, PlayingCard::from("2♦")).unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidCard,
+            table.discard(0, PlayingCard::from("7♣")).unwrap_err()
+        );
+        assert!(table.discard(0, PlayingCard::from("A♠")).is_ok());
+        assert!(table.has_discarded_in(0));
+        assert!(!table.has_discarded());
+        assert!(table.holdings(0).is_empty());
+        assert_eq!(
+            HandError::InvalidCard,
+            table.discard(0, PlayingCard::from("K♠")).unwrap_err()
+        );
+        assert_eq!("2♦ K♠", table.holdem.players.get(0).unwrap().to_string());
+        assert_eq!("A♠", table.holdem.dead.to_string());
+    }
+
+    #[test]
+    fn holdings() {
+        let table = Table::from_index(PineappleGame::default(), INDEX).unwrap();
+
+        let holdings = table.holdings(0);
+
+        assert_eq!(3, holdings.len());
+        assert_eq!(PlayingCard::from("2♦"), holdings[2].0);
+        assert_eq!("A♠ K♠", holdings[2].1.to_string());
+        assert!(table.holdings(2).is_empty());
+    }
+
+    #[test]
+    fn best_discards() {
+        let table = Table::from_index(PineappleGame::CrazyPineapple, INDEX).unwrap();
+
+        // Seat 0 keeps top pair, top kicker, and seat 1 is better off with two outs to a set
+        // than with a runner-runner straight draw.
+        assert_eq!(
+            vec![(0, PlayingCard::from("2♦")), (1, PlayingCard::from("7♣"))],
+            table.best_discards()
+        );
+    }
+
+    #[test]
+    fn best_discards__no_flop() {
+        let table = Table::seat(PineappleGame::default(), 2);
+        table.take_from_index("A♠ 8♥ K♠ 8♦ 2♦ 7♣");
+
+        assert!(table.best_discards().is_empty());
+        assert_eq!(HandError::Incomplete, table.to_holdem().unwrap_err());
+        assert!(table.eval_at_flop().is_empty());
+    }
+
+    #[test]
+    fn chances_at_flop() {
+        let mut table = Table::from_index(PineappleGame::CrazyPineapple, INDEX).unwrap();
+        let before = table.chances_at_flop();
+
+        table.discard(0, PlayingCard::from("2♦")).unwrap();
+        table.discard(1, PlayingCard::from("7♣")).unwrap();
+        let after = table.chances_at_flop();
+
+        // Everyone made the best discard, so nothing changes.
+        assert!(table.has_discarded());
+        assert_eq!(before.get(0), after.get(0));
+        assert_eq!(before.get(1), after.get(1));
+        assert!(after.get(0) > after.get(1));
+    }
+
+    #[test]
+    fn chances_at_flop__after_discards() {
+        let mut table = Table::from_index(PineappleGame::default(), INDEX).unwrap();
+        table.discard(0, PlayingCard::from("K♠")).unwrap();
+        table.discard(1, PlayingCard::from("8♦")).unwrap();
+
+        let evals = table.eval_at_flop();
+        let mut holdem = holdem::table::Table::from_index("A♠ 2♦ 8♥ 7♣ K♦ 9♠ 4♣ 3♥ 5♦").unwrap();
+        holdem.dead = PlayingCards::try_from("K♠ 8♦").unwrap();
+
+        // Seat 1 threw away one of their outs to trips, so it can never come on the turn or
+        // river: 52 cards, less the four held, the flop and the two discards, leave 43.
+        assert_eq!(903, evals.len());
+        assert!(!table
+            .holdem
+            .remaining_at_flop()
+            .contains(&PlayingCard::from("8♦")));
+        assert_eq!(holdem.chances_at_flop().get(0), evals.chances().get(0));
+        assert_eq!(holdem.chances_at_flop().get(1), evals.chances().get(1));
+    }
+
+    #[test]
+    fn outs() {
+        let mut table = Table::seat(PineappleGame::CrazyPineapple, 2);
+        table.take_from_index("A♠ 8♥ K♠ 8♦ 2♦ 7♣ K♦ 9♠ 4♣ 3♥");
+
+        assert_eq!("A♠ K♠ 2♦", table.to_variant().hands[0].to_string());
+        assert!(table.outs().iter().next().is_none());
+
+        table.discard(0, PlayingCard::from("2♦")).unwrap();
+        table.discard(1, PlayingCard::from("7♣")).unwrap();
+
+        assert_eq!(
+            vec![PlayingCard::from("8♠"), PlayingCard::from("8♣")],
+            table.outs().get_as_poker_cards(1).to_vec()
+        );
+        assert_eq!(42, table.to_variant().remaining().len());
+    }
+}
@@ -0,0 +1,49 @@
+use crate::analysis::short_deck_eval::ShortDeckEval;
+use crate::games::short_deck::seat_eval::SeatEval;
+use serde::{Deserialize, Serialize};
+
+/// The short deck version of `holdem::case_eval::CaseEval`, holding the best hand for every
+/// seat for a specific case, and picking out the winners by short deck hand order.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CaseEval(Vec<SeatEval>);
+
+impl CaseEval {
+    pub fn push(&mut self, seat_eval: SeatEval) {
+        self.0.push(seat_eval);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SeatEval> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn seats(&self) -> &Vec<SeatEval> {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn winning_eval(&self) -> ShortDeckEval {
+        self.iter().map(|seat| seat.eval).max().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn winners(&self) -> CaseEval {
+        let winning_eval = self.winning_eval();
+        CaseEval(
+            self.iter()
+                .filter(|seat| seat.eval.cmp(&winning_eval).is_eq())
+                .copied()
+                .collect(),
+        )
+    }
+}
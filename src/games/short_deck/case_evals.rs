@@ -0,0 +1,37 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::seat_calc::SeatCalc;
+use crate::games::short_deck::case_eval::CaseEval;
+
+/// The short deck version of `holdem::case_evals::CaseEvals`.
+#[derive(Clone, Debug, Default)]
+pub struct CaseEvals(Vec<CaseEval>);
+
+impl CaseEvals {
+    pub fn push(&mut self, case_eval: CaseEval) {
+        self.0.push(case_eval);
+    }
+
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let mut calc = SeatCalc::default();
+        for case in &self.0 {
+            for seat in case.iter() {
+                calc.touch(seat.number);
+            }
+            for winner in case.winners().iter() {
+                calc.increment(winner.number);
+            }
+        }
+        calc.chances(self.0.len())
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod case_eval;
+pub mod case_evals;
+pub mod seat_eval;
+pub mod short_deck_game;
+pub mod table;
@@ -0,0 +1,27 @@
+use crate::analysis::short_deck_eval::ShortDeckEval;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The short deck version of `holdem::seat_eval::SeatEval`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SeatEval {
+    pub number: usize,
+    pub eval: ShortDeckEval,
+}
+
+impl SeatEval {
+    #[must_use]
+    pub fn new(number: usize, eval: ShortDeckEval) -> SeatEval {
+        SeatEval { number, eval }
+    }
+}
+
+impl fmt::Display for SeatEval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Seat #{}: {} - {}",
+            self.number, self.eval.hand, self.eval.rank
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use crate::analysis::short_deck_eval::ShortDeckRules;
+use crate::games::variant::showdown::Showdown;
+use crate::games::variant::PokerVariant;
+use crate::types::playing_cards::PlayingCards;
+use serde::{Deserialize, Serialize};
+
+/// [Short deck hold'em](https://en.wikipedia.org/wiki/Six-plus_hold_%27em) as a
+/// `PokerVariant`, dealt like hold'em out of a 36 card deck, with hands ranked under the
+/// `ShortDeckRules`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ShortDeckGame {
+    pub rules: ShortDeckRules,
+}
+
+impl ShortDeckGame {
+    #[must_use]
+    pub fn new(rules: ShortDeckRules) -> ShortDeckGame {
+        ShortDeckGame { rules }
+    }
+}
+
+impl PokerVariant for ShortDeckGame {
+    fn hole_cards(&self) -> usize {
+        2
+    }
+
+    fn streets(&self) -> Vec<usize> {
+        vec![3, 1, 1]
+    }
+
+    fn showdown(&self) -> Showdown {
+        Showdown::ShortDeck(self.rules)
+    }
+
+    fn deck(&self) -> PlayingCards {
+        PlayingCards::short_deck()
+    }
+}
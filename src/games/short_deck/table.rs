@@ -0,0 +1,380 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::outs::Outs;
+use crate::analysis::short_deck_eval::{ShortDeckEval, ShortDeckRules};
+use crate::games::holdem;
+use crate::games::holdem::board::Board;
+use crate::games::holdem::seats::Seats;
+use crate::games::short_deck::case_eval::CaseEval;
+use crate::games::short_deck::case_evals::CaseEvals;
+use crate::games::short_deck::seat_eval::SeatEval;
+use crate::games::short_deck::short_deck_game::ShortDeckGame;
+use crate::games::variant;
+use crate::games::variant::PokerVariant;
+use crate::types::card_slot::CardSlot;
+use crate::types::index_error::IndexError;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::short_deck::ShortDeck;
+use crate::types::U32Card;
+use ckc_rs::{HandError, PokerCard};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use wyz::FmtForward;
+
+/// The short deck version of `holdem::table::Table`. The players and the board are dealt just
+/// like they are in hold'em, but the remaining cards come out of a 36 card `ShortDeck`, and
+/// hands are compared with `ShortDeckEval` under the `Table's` `ShortDeckRules`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Table {
+    pub players: Seats,
+    pub board: Board,
+    pub rules: ShortDeckRules,
+}
+
+impl Table {
+    #[must_use]
+    pub fn seat(number: usize) -> Table {
+        Table {
+            players: Seats::seat(number),
+            board: Board::default(),
+            rules: ShortDeckRules::default(),
+        }
+    }
+
+    /// Takes a Card index string and returns a `Table` entity with the `Players`
+    /// and `Board` entities all having cards dealt to them.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidCard` if any of the cards aren't in a `ShortDeck`, as well as
+    /// any of the errors from `holdem::table::Table::from_index()`.
+    pub fn from_index(index: &str) -> Result<Table, HandError> {
+        Table::try_from(index)
+    }
+
+    #[must_use]
+    pub fn with_rules(mut self, rules: ShortDeckRules) -> Table {
+        self.rules = rules;
+        self
+    }
+
+    #[must_use]
+    pub fn sample_number(player_count: usize) -> Table {
+        let table = Table::seat(player_count);
+        let mut cards = PlayingCards::short_deck_shuffled();
+
+        for _ in 0..(player_count * 2) + 5 {
+            table.take(cards.draw_one());
+        }
+        table
+    }
+
+    /// Returns a `CaseEval` with the best short deck hand each player can make with their
+    /// hole cards and the cards in the case.
+    #[must_use]
+    pub fn case_eval(&self, cycle: &PlayingCards) -> CaseEval {
+        let mut case_eval = CaseEval::default();
+        debug!("Case Eval: {cycle}");
+        for seat in self.players.iter() {
+            let cards: Vec<U32Card> = cycle
+                .clone()
+                .combine(&seat.to_playing_cards())
+                .iter()
+                .map(PlayingCard::as_u32)
+                .collect();
+            let eval = ShortDeckEval::best(&cards, self.rules);
+            debug!("   Player {} {}", seat.number, eval);
+            case_eval.push(SeatEval::new(seat.number, eval));
+        }
+        case_eval
+    }
+
+    pub fn chances_at_flop(&self) -> Chances {
+        self.eval_at_flop().chances()
+    }
+
+    pub fn chances_at_turn(&self) -> Chances {
+        self.eval_at_turn().chances()
+    }
+
+    pub fn chances_at_river(&self) -> Chances {
+        self.eval_at_river().chances()
+    }
+
+    pub fn dealt(&self) -> PlayingCards {
+        PlayingCards::default()
+            .combine(&self.players.dealt())
+            .combine(&self.board.dealt())
+    }
+
+    /// Permutates through every possible turn and river left in the `ShortDeck` after the
+    /// `Flop`.
+    pub fn eval_at_flop(&self) -> CaseEvals {
+        if !self.board.flop.is_dealt() {
+            return CaseEvals::default();
+        }
+        let mut evals = CaseEvals::default();
+        for v in self.remaining_at_flop().combinations(2) {
+            let mut cycle = PlayingCards::from(v);
+            cycle.append(&self.board.flop.to_playing_cards());
+            evals.push(self.case_eval(&cycle));
+        }
+        evals
+    }
+
+    pub fn eval_at_turn(&self) -> CaseEvals {
+        if !self.board.turn.is_dealt() {
+            return CaseEvals::default();
+        }
+        let mut evals = CaseEvals::default();
+        for card in self.remaining_at_turn().iter() {
+            let mut cycle = PlayingCards::from(*card);
+            cycle.append(&self.board.flop.to_playing_cards());
+            cycle.append(&self.board.turn.to_playing_cards());
+            evals.push(self.case_eval(&cycle));
+        }
+        evals
+    }
+
+    pub fn eval_at_river(&self) -> CaseEvals {
+        if !self.board.river.is_dealt() {
+            return CaseEvals::default();
+        }
+        let mut evals = CaseEvals::default();
+        evals.push(self.case_eval(&self.board.to_playing_cards()));
+        evals
+    }
+
+    pub fn remaining_at_flop(&self) -> PlayingCards {
+        self.remaining()
+            .combine(&self.board.turn.to_playing_cards())
+            .combine(&self.board.river.to_playing_cards())
+    }
+
+    pub fn remaining_at_turn(&self) -> PlayingCards {
+        self.remaining()
+            .combine(&self.board.river.to_playing_cards())
+    }
+
+    /// Returns the cards on the next street that would have each player winning or tied, once
+    /// the turn or the river is all that's left to come.
+    #[must_use]
+    pub fn outs(&self) -> Outs {
+        self.to_variant().outs()
+    }
+
+    /// Returns the `PokerVariant` version of the `Table`, playing under the same
+    /// `ShortDeckRules`.
+    #[must_use]
+    pub fn to_variant(&self) -> variant::table::Table<ShortDeckGame> {
+        variant::table::Table {
+            variant: ShortDeckGame::new(self.rules),
+            hands: self
+                .players
+                .iter()
+                .map(CardSlot::to_playing_cards)
+                .collect(),
+            board: self.board.to_playing_cards(),
+            dead: PlayingCards::default(),
+        }
+    }
+}
+
+impl CardSlot for Table {
+    fn take(&self, card: PlayingCard) -> bool {
+        if self.players.is_dealt() {
+            self.board.take(card)
+        } else {
+            self.players.take(card)
+        }
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        cards.append(&self.players.fold());
+        cards.append(&self.board.fold());
+        cards
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.players.is_dealt() && self.board.is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        cards.append(&self.players.to_playing_cards());
+        cards.append(&self.board.to_playing_cards());
+        cards
+    }
+
+    /// Returns all the `PlayingCards` from a `ShortDeck` that aren't on the `Table`.
+    fn remaining(&self) -> PlayingCards {
+        ShortDeckGame::new(self.rules)
+            .deck()
+            .minus(&self.to_playing_cards())
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("PLAYERS: {}", self.players)).fmt_display());
+        out.entry(&(format!("BOARD: {}", self.board)).fmt_display());
+
+        out.finish()
+    }
+}
+
+impl TryFrom<&str> for Table {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Table::try_from(PlayingCards::try_from(value)?)
+    }
+}
+
+impl TryFrom<String> for Table {
+    type Error = HandError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Table::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<PlayingCards> for Table {
+    type Error = HandError;
+
+    /// Deals the cards out the same way as `holdem::table::Table`.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidCard` if any of the cards are a five or lower, otherwise
+    /// the same errors as `holdem::table::Table`.
+    fn try_from(value: PlayingCards) -> Result<Self, Self::Error> {
+        if !value.iter().all(|card| ShortDeck::contains(card.as_u32())) {
+            return Err(HandError::InvalidCard);
+        }
+        let table = holdem::table::Table::try_from(value)?;
+        Ok(Table {
+            players: table.players,
+            board: table.board,
+            rules: ShortDeckRules::default(),
+        })
+    }
+}
+
+/// # Errors
+///
+/// Will return an `IndexError::InvalidCard` naming the first invalid card in the index, or
+/// an `IndexError::Hand` if the cards can't be dealt out to a short deck `Table`.
+impl FromStr for Table {
+    type Err = IndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Table::try_from(PlayingCards::from_str(s)?)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod short_deck_table_tests {
+    use super::*;
+
+    #[test]
+    fn from_index() {
+        let table = Table::from_index("A♠ K♠ 9♦ 9♣ Q♠ J♠ 6♥ 7♦ 8♣").unwrap();
+
+        assert_eq!("[Seat 0: A♠ K♠, Seat 1: 9♦ 9♣]", table.players.to_string());
+        assert_eq!(
+            "[FLOP:  Q♠ J♠ 6♥, TURN:  7♦, RIVER: 8♣]",
+            table.board.to_string()
+        );
+        assert_eq!(ShortDeckRules::TripsBeatStraights, table.rules);
+    }
+
+    #[test]
+    fn from_index__not_in_the_short_deck() {
+        assert_eq!(
+            HandError::InvalidCard,
+            Table::from_index("A♠ K♠ 9♦ 9♣ Q♠ J♠ 5♥ 7♦ 8♣").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn remaining() {
+        let table = Table::from_index("A♠ K♠ 9♦ 9♣ Q♠ J♠ 6♥ 7♦ 8♣").unwrap();
+
+        assert_eq!(27, table.remaining().len());
+        assert_eq!(29, table.remaining_at_flop().len());
+        assert_eq!(28, table.remaining_at_turn().len());
+    }
+
+    #[test]
+    fn eval_at_flop() {
+        let table = Table::from_index("A♠ K♠ 9♦ 9♣ Q♠ J♠ 6♥ 7♦ 8♣").unwrap();
+
+        // 29 choose 2 turns and rivers left in the short deck.
+        assert_eq!(406, table.eval_at_flop().len());
+        assert!(table.chances_at_flop().keeping_it_100());
+    }
+
+    #[test]
+    fn chances_at_river__ace_to_nine_straight() {
+        let over_kings = Table::from_index("A♠ 9♠ K♦ K♣ 6♥ 7♦ 8♣ Q♥ J♦").unwrap();
+        let under_ten_high = Table::from_index("A♠ 9♠ T♦ 9♦ 6♥ 7♦ 8♣ Q♥ J♦").unwrap();
+
+        assert_eq!(100.0, over_kings.chances_at_river().get(0));
+        assert_eq!(100.0, under_ten_high.chances_at_river().get(1));
+    }
+
+    #[test]
+    fn chances_at_river__rules() {
+        // Seat 0 makes a straight and seat 1 a set of nines.
+        let table = Table::from_index("T♠ J♥ 9♦ 9♣ Q♠ K♦ 9♥ 6♣ 7♦").unwrap();
+
+        assert_eq!(100.0, table.chances_at_river().get(1));
+        assert_eq!(
+            100.0,
+            table
+                .with_rules(ShortDeckRules::StraightsBeatTrips)
+                .chances_at_river()
+                .get(0)
+        );
+    }
+
+    #[test]
+    fn chances_at_turn() {
+        let table = Table::from_index("A♠ K♠ 9♦ 9♣ Q♠ J♠ 6♥ 7♦ 8♣").unwrap();
+
+        let chances = table.chances_at_turn();
+
+        assert_eq!(28, table.eval_at_turn().len());
+        assert!(chances.keeping_it_100());
+    }
+
+    #[test]
+    fn sample_number() {
+        let table = Table::sample_number(3);
+
+        assert!(table.is_dealt());
+        assert!(table
+            .to_playing_cards()
+            .iter()
+            .all(|card| ShortDeck::contains(card.as_u32())));
+    }
+
+    #[test]
+    fn outs() {
+        let table = Table::seat(2);
+        table.take_from_index("A♠ 9♦ K♠ 9♣ Q♠ J♠ 6♥ 7♦");
+
+        let outs = table.outs();
+
+        assert_eq!(28, table.to_variant().remaining().len());
+        assert!(outs.get(0).unwrap().contains(&PlayingCard::from("A♥")));
+        assert!(!outs.get(1).unwrap().contains(&PlayingCard::from("A♥")));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod seat;
+pub mod seats;
+pub mod street;
+pub mod stud_game;
+pub mod table;
@@ -0,0 +1,81 @@
+use crate::types::card_slot::CardSlot;
+use crate::types::flag::Flag;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::stud_hand::StudHand;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The stud version of `holdem::seat::Seat`, holding a `StudHand`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Seat {
+    pub number: usize,
+    folded: Flag,
+    pub hand: StudHand,
+}
+
+impl Seat {
+    #[must_use]
+    pub fn new(number: usize) -> Seat {
+        Seat::new_with_hand(number, StudHand::default())
+    }
+
+    #[must_use]
+    pub fn from_index(number: usize, index: &str) -> Seat {
+        Seat::new_with_hand(number, StudHand::from(index))
+    }
+
+    #[must_use]
+    pub fn new_with_hand(number: usize, hand: StudHand) -> Seat {
+        Seat {
+            number,
+            folded: Flag::new(false),
+            hand,
+        }
+    }
+
+    #[must_use]
+    pub fn did_fold(&self) -> bool {
+        self.folded.get()
+    }
+}
+
+impl CardSlot for Seat {
+    fn take(&self, card: PlayingCard) -> bool {
+        self.hand.take(card)
+    }
+
+    fn fold(&self) -> PlayingCards {
+        self.folded.set(true);
+        self.hand.fold()
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.hand.is_dealt()
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.hand.to_playing_cards()
+    }
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Seat {}: {}", self.number, self.hand)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stud_seat_tests {
+    use super::*;
+
+    #[test]
+    fn fold() {
+        let seat = Seat::from_index(2, "A♠ 2♥ 3♦ 4♣");
+
+        assert_eq!("Seat 2: [A♠ 2♥] 3♦ 4♣", seat.to_string());
+        assert_eq!("A♠ 2♥ 3♦ 4♣", seat.fold().to_string());
+        assert!(seat.did_fold());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::games::stud::seat::Seat;
+use crate::games::stud::street::Street;
+use crate::games::stud::stud_game::StudGame;
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The stud version of `holdem::seats::Seats`. Cards are only dealt to the `Seats` that are
+/// still in the hand.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Seats(Vec<Seat>);
+
+impl Seats {
+    #[must_use]
+    pub fn seat(number: usize) -> Seats {
+        Seats((0..number).map(Seat::new).collect())
+    }
+
+    pub fn add_from_index(&mut self, index: &str) {
+        self.0.push(Seat::from_index(self.len(), index));
+    }
+
+    /// Returns the number of the `Seat` that has to bring it in, based on the door cards of
+    /// the `Seats` that are still in the hand.
+    #[must_use]
+    pub fn bring_in(&self, game: StudGame) -> Option<usize> {
+        let doors: Vec<(usize, PlayingCard)> = self
+            .live()
+            .map(|seat| (seat.number, seat.hand.door_card()))
+            .collect();
+        game.bring_in(&doors)
+    }
+
+    #[must_use]
+    pub fn get(&self, seat: usize) -> Option<&Seat> {
+        self.0.get(seat)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Seat> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the `Seats` that haven't folded.
+    pub fn live(&self) -> impl Iterator<Item = &Seat> {
+        self.0.iter().filter(|seat| !seat.did_fold())
+    }
+
+    /// Returns the `Street` the hand is on, based on the fewest cards any live `Seat` has.
+    #[must_use]
+    pub fn street(&self) -> Option<Street> {
+        self.live()
+            .map(CardSlot::len)
+            .min()
+            .and_then(Street::from_cards)
+    }
+}
+
+impl CardSlot for Seats {
+    /// Deals the card to the first live `Seat` with the fewest cards, so that the cards go
+    /// around the table one at a time like a dealer would.
+    fn take(&self, card: PlayingCard) -> bool {
+        match self
+            .live()
+            .filter(|seat| !seat.is_dealt())
+            .min_by_key(|seat| seat.len())
+        {
+            Some(seat) => seat.take(card),
+            None => false,
+        }
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for seat in &self.0 {
+            cards.append(&seat.fold());
+        }
+        cards
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.live().all(CardSlot::is_dealt)
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for seat in &self.0 {
+            cards.append(&seat.to_playing_cards());
+        }
+        cards
+    }
+}
+
+impl fmt::Display for Seats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let joined = Itertools::join(&mut self.0.iter(), ", ");
+        write!(f, "[{joined}]")
+    }
+}
+
+impl From<Vec<Seat>> for Seats {
+    fn from(value: Vec<Seat>) -> Self {
+        Seats(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stud_seats_tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let seats = Seats::seat(3);
+        let mut cards = PlayingCards::try_from("A♠ K♠ Q♠ J♠ T♠ 9♠ 8♠ 7♠ 6♠ 5♠").unwrap();
+
+        for _ in 0..9 {
+            assert!(seats.take(cards.draw_one()));
+        }
+        assert_eq!(Some(Street::Third), seats.street());
+
+        seats.get(1).unwrap().fold();
+        assert!(seats.take(cards.draw_one()));
+        assert_eq!(
+            "[Seat 0: [A♠ J♠] 8♠ 5♠, Seat 1: , Seat 2: [Q♠ 9♠] 6♠]",
+            seats.to_string()
+        );
+    }
+
+    #[test]
+    fn bring_in() {
+        let mut seats = Seats::default();
+        seats.add_from_index("A♠ A♥ 2♦");
+        seats.add_from_index("K♠ Q♥ K♦");
+        seats.add_from_index("7♠ 8♥ 2♣");
+
+        assert_eq!(Some(2), seats.bring_in(StudGame::SevenCardStud));
+        assert_eq!(Some(1), seats.bring_in(StudGame::Razz));
+
+        seats.get(2).unwrap().fold();
+        assert_eq!(Some(0), seats.bring_in(StudGame::SevenCardStud));
+    }
+
+    #[test]
+    fn street() {
+        let mut seats = Seats::default();
+        seats.add_from_index("A♠ A♥ 2♦ 3♦");
+        seats.add_from_index("K♠ Q♥ K♦ 5♣");
+
+        assert_eq!(Some(Street::Fourth), seats.street());
+        assert_eq!(None, Seats::default().street());
+    }
+}
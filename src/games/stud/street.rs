@@ -0,0 +1,66 @@
+use strum_macros::Display;
+use strum_macros::EnumIter;
+
+/// The betting rounds of seven card stud, named for how many cards each player has.
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Street {
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+}
+
+impl Street {
+    /// Returns the `Street` once each player has been dealt the number of cards.
+    #[must_use]
+    pub fn from_cards(count: usize) -> Option<Street> {
+        match count {
+            3 => Some(Street::Third),
+            4 => Some(Street::Fourth),
+            5 => Some(Street::Fifth),
+            6 => Some(Street::Sixth),
+            7 => Some(Street::Seventh),
+            _ => None,
+        }
+    }
+
+    /// Returns how many cards each player has been dealt by the `Street`.
+    #[must_use]
+    pub fn cards(&self) -> usize {
+        match self {
+            Street::Third => 3,
+            Street::Fourth => 4,
+            Street::Fifth => 5,
+            Street::Sixth => 6,
+            Street::Seventh => 7,
+        }
+    }
+
+    #[must_use]
+    pub fn next(&self) -> Option<Street> {
+        Street::from_cards(self.cards() + 1)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stud_street_tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn from_cards() {
+        for street in Street::iter() {
+            assert_eq!(Some(street), Street::from_cards(street.cards()));
+        }
+        assert_eq!(None, Street::from_cards(2));
+        assert_eq!(None, Street::from_cards(8));
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(Some(Street::Fourth), Street::Third.next());
+        assert_eq!(None, Street::Seventh.next());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::analysis::low::{LowEval, LowRank};
+use crate::analysis::seat_calc::SeatCalc;
+use crate::types::arrays::seven_card::SevenCard;
+use crate::types::arrays::Evaluable;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::hand_rank::HandRank;
+use ckc_rs::PokerCard;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The seven card stud games that fudd knows how to deal and evaluate.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum StudGame {
+    /// [Seven card stud](https://en.wikipedia.org/wiki/Seven-card_stud), played for high.
+    #[default]
+    SevenCardStud,
+    /// [Razz](https://en.wikipedia.org/wiki/Razz_(poker)), played for ace-to-five low.
+    Razz,
+    /// [Seven card stud high-low split](https://en.wikipedia.org/wiki/Seven-card_stud#Seven-card_stud_high-low_split),
+    /// eight or better, also known as Stud8.
+    SevenCardStudHiLo,
+}
+
+impl StudGame {
+    /// Returns the number of the seat that has to bring it in on third street. In stud that's
+    /// the lowest door card, with aces high, and in Razz it's the highest, with aces low. Ties
+    /// are broken by suit, from clubs at the bottom, through diamonds and hearts, to spades.
+    /// Stud8 brings it in the same way as stud.
+    #[must_use]
+    pub fn bring_in(&self, door_cards: &[(usize, PlayingCard)]) -> Option<usize> {
+        let doors = door_cards.iter().filter(|(_, card)| !card.is_blank());
+        match self {
+            StudGame::SevenCardStud | StudGame::SevenCardStudHiLo => doors
+                .min_by_key(|(_, card)| (card.get_rank_bit(), card.get_suit_bit()))
+                .map(|(seat, _)| *seat),
+            StudGame::Razz => doors
+                .max_by_key(|(_, card)| (LowRank::ace_low_rank(card.as_u32()), card.get_suit_bit()))
+                .map(|(seat, _)| *seat),
+        }
+    }
+
+    /// Returns true for the games where the pot is split between the best high and low hands.
+    #[must_use]
+    pub fn is_hi_lo(&self) -> bool {
+        *self == StudGame::SevenCardStudHiLo
+    }
+
+    /// Returns the numbers of the seats with the best hand at showdown, or tied for it. In
+    /// Stud8, that's everyone who wins at least part of the pot, with either the best high or
+    /// the best eight or better low.
+    #[must_use]
+    pub fn winners(&self, hands: &[(usize, PlayingCards)]) -> Vec<usize> {
+        match self {
+            StudGame::SevenCardStud => {
+                let ranks: Vec<(usize, HandRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, StudGame::high(cards)))
+                    .collect();
+                SeatCalc::best(&ranks)
+            }
+            StudGame::Razz => {
+                let ranks: Vec<(usize, LowRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, StudGame::low(cards)))
+                    .collect();
+                SeatCalc::best(&ranks)
+            }
+            StudGame::SevenCardStudHiLo => {
+                let mut winners = StudGame::SevenCardStud.winners(hands);
+                let lows: Vec<(usize, LowRank)> = hands
+                    .iter()
+                    .map(|(seat, cards)| (*seat, StudGame::low(cards).eight_or_better()))
+                    .filter(|(_, low)| !low.is_none())
+                    .collect();
+                winners.extend(SeatCalc::best(&lows));
+                winners.sort_unstable();
+                winners.dedup();
+                winners
+            }
+        }
+    }
+
+    //region private functions
+
+    fn high(cards: &PlayingCards) -> HandRank {
+        match SevenCard::try_from(cards) {
+            Ok(seven) => seven.hand_rank(),
+            Err(_) => HandRank::default(),
+        }
+    }
+
+    fn low(cards: &PlayingCards) -> LowRank {
+        let cards: Vec<u32> = cards.iter().map(PlayingCard::as_u32).collect();
+        LowEval::best(&cards).rank
+    }
+
+    //endregion
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stud_stud_game_tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn doors(index: &'static str) -> Vec<(usize, PlayingCard)> {
+        PlayingCards::try_from(index)
+            .unwrap()
+            .iter()
+            .copied()
+            .enumerate()
+            .collect()
+    }
+
+    fn hands(indexes: &[&'static str]) -> Vec<(usize, PlayingCards)> {
+        indexes
+            .iter()
+            .map(|index| PlayingCards::try_from(*index).unwrap())
+            .enumerate()
+            .collect()
+    }
+
+    #[test]
+    fn bring_in__stud() {
+        assert_eq!(
+            Some(1),
+            StudGame::SevenCardStud.bring_in(&doors("A♠ 2♥ K♦"))
+        );
+        assert_eq!(
+            Some(2),
+            StudGame::SevenCardStud.bring_in(&doors("A♠ 2♥ 2♣"))
+        );
+        assert_eq!(
+            Some(2),
+            StudGame::SevenCardStud.bring_in(&doors("K♠ A♥ 7♦"))
+        );
+    }
+
+    #[test]
+    fn bring_in__razz() {
+        assert_eq!(Some(2), StudGame::Razz.bring_in(&doors("A♠ 2♥ K♦")));
+        assert_eq!(Some(1), StudGame::Razz.bring_in(&doors("A♠ Q♠ Q♥")));
+        assert_eq!(Some(0), StudGame::Razz.bring_in(&doors("2♠ A♥ A♦")));
+        assert_eq!(None, StudGame::Razz.bring_in(&[]));
+    }
+
+    #[test]
+    fn winners__stud() {
+        let hands = hands(&["A♠ A♥ 2♦ 3♣ 5♠ 8♥ 9♦", "K♠ K♥ K♦ 3♦ 5♦ 8♠ 9♣"]);
+
+        assert_eq!(vec![1], StudGame::SevenCardStud.winners(&hands));
+        assert_eq!(vec![0], StudGame::Razz.winners(&hands));
+    }
+
+    #[rstest]
+    #[case(&["A♠ A♥ 2♦ 3♣ 5♠ 8♥ 9♦", "K♠ K♥ K♦ 3♦ 5♦ T♠ 9♣"], vec![0, 1])]
+    #[case(&["A♠ A♥ 2♦ 3♣ 5♠ K♥ 9♦", "K♠ K♦ K♣ 3♦ 5♦ T♠ 9♣"], vec![1])]
+    #[case(&["A♠ 2♠ 3♠ 4♠ 5♠ K♥ K♦", "K♠ K♣ Q♦ 3♦ 5♦ T♠ 9♣"], vec![0])]
+    #[case(&["A♠ 2♠ 3♥ 4♠ 6♠ K♥ Q♦", "A♥ 2♥ 3♦ 4♣ 6♦ J♠ J♣"], vec![0, 1])]
+    fn winners__stud_hi_lo(#[case] indexes: &[&'static str], #[case] expected: Vec<usize>) {
+        assert_eq!(
+            expected,
+            StudGame::SevenCardStudHiLo.winners(&hands(indexes))
+        );
+    }
+
+    #[test]
+    fn bring_in__stud_hi_lo() {
+        assert_eq!(
+            StudGame::SevenCardStud.bring_in(&doors("K♠ A♥ 7♦")),
+            StudGame::SevenCardStudHiLo.bring_in(&doors("K♠ A♥ 7♦"))
+        );
+    }
+
+    #[test]
+    fn winners__razz_pairs_count_against() {
+        let hands = hands(&["A♠ A♥ 2♦ 2♣ 3♠ 3♥ 4♦", "K♠ Q♥ J♦ T♦ 9♦ 8♠ 7♣"]);
+
+        assert_eq!(vec![1], StudGame::Razz.winners(&hands));
+    }
+
+    #[test]
+    fn winners__razz_straights_and_flushes_dont_count() {
+        let hands = hands(&["A♠ 2♠ 3♠ 4♠ 5♠ K♥ K♦", "A♥ 2♥ 3♦ 4♣ 6♦ Q♠ Q♣"]);
+
+        assert_eq!(vec![0], StudGame::Razz.winners(&hands));
+    }
+
+    #[test]
+    fn winners__tie() {
+        let hands = hands(&["A♠ 2♠ 3♠ 4♠ 5♠ K♥ K♦", "A♥ 2♥ 3♦ 4♣ 5♦ Q♠ Q♣"]);
+
+        assert_eq!(vec![0, 1], StudGame::Razz.winners(&hands));
+    }
+}
@@ -0,0 +1,364 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::seat_calc::SeatCalc;
+use crate::games::hi_lo;
+use crate::games::hi_lo::case_eval::CaseEval;
+use crate::games::hi_lo::case_evals::CaseEvals;
+use crate::games::hi_lo::seat_eval::SeatEval;
+use crate::games::stud::seat::Seat;
+use crate::games::stud::seats::Seats;
+use crate::games::stud::street::Street;
+use crate::games::stud::stud_game::StudGame;
+use crate::types::arrays::seven_card::SevenCard;
+use crate::types::arrays::Evaluable;
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_cards::PlayingCards;
+use std::fmt::{Display, Formatter};
+use wyz::FmtForward;
+
+/// A seven card stud `Table`, for any of the `StudGame` variants.
+///
+/// When a player folds, their up cards have been seen by everyone at the table, so they're
+/// kept as `dead` cards, and left out of the cards that can still be dealt when working out
+/// the `Chances` for the players that are still in the hand. Split pot games, like Stud8, have
+/// their own hi/lo `Chances`, with how often each player scoops or splits the pot.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub players: Seats,
+    pub dead: PlayingCards,
+    pub game: StudGame,
+}
+
+impl Table {
+    #[must_use]
+    pub fn new(game: StudGame) -> Table {
+        Table {
+            players: Seats::default(),
+            dead: PlayingCards::default(),
+            game,
+        }
+    }
+
+    #[must_use]
+    pub fn seat(number: usize, game: StudGame) -> Table {
+        Table {
+            players: Seats::seat(number),
+            dead: PlayingCards::default(),
+            game,
+        }
+    }
+
+    #[must_use]
+    pub fn bring_in(&self) -> Option<usize> {
+        self.players.bring_in(self.game)
+    }
+
+    /// Works out the `Chances` for each live player by dealing out every possible combination
+    /// of the cards they still have coming.
+    ///
+    /// **NOTE** The number of combinations grows very quickly the earlier the street, so this
+    /// is only practical from around sixth street on. Use `chances_sampled()` before that.
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let live: Vec<&Seat> = self.players.live().collect();
+        let mut calc = SeatCalc::primed(live.iter().map(|seat| seat.number));
+        let mut hands: Vec<(usize, PlayingCards)> = Vec::new();
+        let cases = Table::deal_out(&live, &self.remaining(), &mut hands, &mut |hands| {
+            for winner in self.game.winners(hands) {
+                calc.increment(winner);
+            }
+        });
+        calc.chances(cases)
+    }
+
+    /// Estimates the `Chances` for each live player by dealing out the rest of the hand at
+    /// random `trials` times.
+    #[must_use]
+    pub fn chances_sampled(&self, trials: usize) -> Chances {
+        let live: Vec<&Seat> = self.players.live().collect();
+        let mut calc = SeatCalc::primed(live.iter().map(|seat| seat.number));
+        let remaining = self.remaining();
+        for _ in 0..trials {
+            for winner in self.game.winners(&Table::sample_hands(&live, &remaining)) {
+                calc.increment(winner);
+            }
+        }
+        calc.chances(trials)
+    }
+
+    /// Returns the split pot `Chances` for each live player in a hi/lo game, such as Stud8,
+    /// from every possible combination of the cards they still have coming.
+    #[must_use]
+    pub fn chances_hi_lo(&self) -> hi_lo::chances::Chances {
+        self.eval_hi_lo().chances()
+    }
+
+    /// Estimates the split pot `Chances` for each live player in a hi/lo game by dealing out
+    /// the rest of the hand at random `trials` times.
+    #[must_use]
+    pub fn chances_hi_lo_sampled(&self, trials: usize) -> hi_lo::chances::Chances {
+        self.eval_hi_lo_sampled(trials).chances()
+    }
+
+    /// Returns a split pot `CaseEval` for every possible way the rest of the hand can be dealt
+    /// to the live players, or no cases at all if the `StudGame` isn't split between the high
+    /// and the low.
+    #[must_use]
+    pub fn eval_hi_lo(&self) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        if !self.game.is_hi_lo() {
+            return evals;
+        }
+        let live: Vec<&Seat> = self.players.live().collect();
+        let mut hands: Vec<(usize, PlayingCards)> = Vec::new();
+        Table::deal_out(&live, &self.remaining(), &mut hands, &mut |hands| {
+            evals.push(Table::case_eval_hi_lo(hands));
+        });
+        evals
+    }
+
+    /// Returns a split pot `CaseEval` for `trials` random deals of the rest of the hand.
+    #[must_use]
+    pub fn eval_hi_lo_sampled(&self, trials: usize) -> CaseEvals {
+        let mut evals = CaseEvals::default();
+        if !self.game.is_hi_lo() {
+            return evals;
+        }
+        let live: Vec<&Seat> = self.players.live().collect();
+        let remaining = self.remaining();
+        for _ in 0..trials {
+            evals.push(Table::case_eval_hi_lo(&Table::sample_hands(
+                &live, &remaining,
+            )));
+        }
+        evals
+    }
+
+    /// Folds the player, adding their up cards to the `dead` cards, and returning them.
+    pub fn fold_seat(&mut self, number: usize) -> PlayingCards {
+        match self.players.get(number) {
+            Some(seat) if !seat.did_fold() => {
+                let up_cards = seat.hand.up_cards();
+                seat.fold();
+                self.dead.append(&up_cards);
+                up_cards
+            }
+            _ => PlayingCards::default(),
+        }
+    }
+
+    /// Returns all the cards in the deck that aren't held by a live player or dead.
+    #[must_use]
+    pub fn remaining(&self) -> PlayingCards {
+        PlayingCards::deck_minus(&self.players.to_playing_cards().combine(&self.dead))
+    }
+
+    #[must_use]
+    pub fn street(&self) -> Option<Street> {
+        self.players.street()
+    }
+
+    //region private functions
+
+    fn case_eval_hi_lo(hands: &[(usize, PlayingCards)]) -> CaseEval {
+        let mut case_eval = CaseEval::default();
+        for (seat, cards) in hands {
+            if let Ok(seven) = SevenCard::try_from(cards) {
+                case_eval.push(SeatEval::new(*seat, seven.eval(), seven.eight_or_better()));
+            }
+        }
+        case_eval
+    }
+
+    /// Recursively deals each live `Seat` every combination of the cards they still need,
+    /// passing every complete deal on, and returning the number of deals.
+    fn deal_out<F: FnMut(&[(usize, PlayingCards)])>(
+        live: &[&Seat],
+        remaining: &PlayingCards,
+        hands: &mut Vec<(usize, PlayingCards)>,
+        on_deal: &mut F,
+    ) -> usize {
+        let Some((seat, rest)) = live.split_first() else {
+            on_deal(hands);
+            return 1;
+        };
+        let needed = Street::Seventh.cards() - seat.len();
+        let mut cases = 0;
+        for v in remaining.combinations(needed) {
+            let dealt = PlayingCards::from(v);
+            hands.push((seat.number, seat.to_playing_cards().combine(&dealt)));
+            cases += Table::deal_out(rest, &remaining.minus(&dealt), hands, on_deal);
+            hands.pop();
+        }
+        cases
+    }
+
+    /// Deals each live `Seat` the rest of their cards out of the shuffled remaining cards.
+    fn sample_hands(live: &[&Seat], remaining: &PlayingCards) -> Vec<(usize, PlayingCards)> {
+        let mut deck = remaining.shuffle();
+        live.iter()
+            .map(|seat| {
+                let needed = Street::Seventh.cards() - seat.len();
+                (
+                    seat.number,
+                    seat.to_playing_cards().combine(&deck.draw(needed)),
+                )
+            })
+            .collect()
+    }
+
+    //endregion
+}
+
+impl Display for Table {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("{}: {}", self.game, self.players)).fmt_display());
+        out.entry(&(format!("DEAD: {}", self.dead)).fmt_display());
+
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stud_table_tests {
+    use super::*;
+
+    fn razz() -> Table {
+        let mut table = Table::new(StudGame::Razz);
+        table.players.add_from_index("A♠ 2♥ 3♦ 4♣ 5♠ 6♥");
+        table.players.add_from_index("K♠ Q♥ 7♦ 8♣ 9♠ T♥");
+        table.players.add_from_index("J♠ J♥ 2♦ 2♣ 7♠ 7♥");
+        table
+    }
+
+    #[test]
+    fn fold_seat() {
+        let mut table = razz();
+
+        assert_eq!("2♦ 2♣ 7♠ 7♥", table.fold_seat(2).to_string());
+        assert_eq!("2♦ 2♣ 7♠ 7♥", table.dead.to_string());
+        assert!(table.fold_seat(2).is_empty());
+        assert!(table.fold_seat(9).is_empty());
+    }
+
+    #[test]
+    fn remaining() {
+        let mut table = razz();
+        assert_eq!(34, table.remaining().len());
+
+        // The folded player's down cards go back into the unknown cards, but not their up cards.
+        table.fold_seat(2);
+        assert_eq!(36, table.remaining().len());
+        assert!(!table.remaining().contains(&"7♠".into()));
+        assert!(table.remaining().contains(&"J♠".into()));
+    }
+
+    #[test]
+    fn chances() {
+        let mut table = razz();
+        table.fold_seat(2);
+
+        let chances = table.chances();
+
+        // Seat 0 already has a wheel.
+        assert_eq!(100.0, chances.get(0));
+        assert_eq!(0.0, chances.get(1));
+    }
+
+    #[test]
+    fn chances__dead_cards() {
+        let mut folded = Table::new(StudGame::SevenCardStud);
+        folded.players.add_from_index("A♠ A♥ K♦ Q♣ J♠ 9♥");
+        folded.players.add_from_index("T♠ T♥ T♦ 2♣ 3♠ 4♥");
+        folded.players.add_from_index("8♠ 8♥ A♦ 5♣ 6♠ 7♥");
+        folded.fold_seat(2);
+        let mut heads_up = Table::new(StudGame::SevenCardStud);
+        heads_up.players.add_from_index("A♠ A♥ K♦ Q♣ J♠ 9♥");
+        heads_up.players.add_from_index("T♠ T♥ T♦ 2♣ 3♠ 4♥");
+
+        let with_dead = folded.chances();
+        let without = heads_up.chances();
+
+        // Seeing the folded A♦ takes away one of the two aces that would give seat 0 trips.
+        assert!(with_dead.keeping_it_100());
+        assert!(with_dead.get(0) < without.get(0));
+    }
+
+    #[test]
+    fn chances_sampled() {
+        let mut table = razz();
+        table.fold_seat(2);
+
+        let chances = table.chances_sampled(100);
+
+        assert_eq!(100.0, chances.get(0));
+    }
+
+    #[test]
+    fn chances_hi_lo__seventh_street() {
+        let mut table = Table::new(StudGame::SevenCardStudHiLo);
+        table.players.add_from_index("A♠ 2♠ 3♥ 4♣ 6♦ K♥ Q♦");
+        table.players.add_from_index("K♠ K♦ K♣ 3♦ 5♦ T♠ 9♣");
+
+        let chances = table.chances_hi_lo();
+
+        assert_eq!(1, table.eval_hi_lo().len());
+        assert_eq!(100.0, chances.low.get(0));
+        assert_eq!(100.0, chances.high.get(1));
+        assert_eq!(50.0, chances.equity.get(0));
+        assert_eq!(0.0, chances.scoop.get(1));
+    }
+
+    #[test]
+    fn chances_hi_lo__dead_cards() {
+        let mut heads_up = Table::new(StudGame::SevenCardStudHiLo);
+        heads_up.players.add_from_index("A♠ 2♠ 3♥ 4♣ K♦ Q♥");
+        heads_up.players.add_from_index("K♠ K♣ T♦ T♥ 9♣ 9♦");
+        let mut folded = heads_up.clone();
+        folded.players.add_from_index("J♠ J♥ 5♦ 6♦ 7♦ 8♦");
+        folded.fold_seat(2);
+
+        // Any five, six, seven or eight makes seat 0 a low, but four of them are dead once
+        // seat 2 folds.
+        assert_eq!(40.0, heads_up.chances_hi_lo().low.get(0));
+        assert_eq!(
+            format!("{:.2}", 12.0 / 36.0 * 100.0),
+            format!("{:.2}", folded.chances_hi_lo().low.get(0))
+        );
+    }
+
+    #[test]
+    fn chances_hi_lo_sampled() {
+        let mut table = Table::new(StudGame::SevenCardStudHiLo);
+        table.players.add_from_index("A♠ 2♠ 3♥ 4♣ 6♦ K♥ Q♦");
+        table.players.add_from_index("K♠ K♦ K♣ 3♦ 5♦ T♠ 9♣");
+
+        assert_eq!(50.0, table.chances_hi_lo_sampled(10).equity.get(1));
+    }
+
+    #[test]
+    fn eval_hi_lo__not_split() {
+        assert!(razz().eval_hi_lo().is_empty());
+    }
+
+    #[test]
+    fn bring_in() {
+        let mut table = Table::new(StudGame::SevenCardStud);
+        table.players.add_from_index("A♠ A♥ K♦");
+        table.players.add_from_index("T♠ T♥ 3♦");
+        table.players.add_from_index("8♠ 8♥ 3♣");
+
+        assert_eq!(Some(2), table.bring_in());
+        assert_eq!(Some(Street::Third), table.street());
+    }
+
+    #[test]
+    fn display() {
+        let mut table = Table::new(StudGame::Razz);
+        table.players.add_from_index("A♠ 2♥ 3♦");
+
+        assert_eq!("[Razz: [Seat 0: [A♠ 2♥] 3♦], DEAD: ]", table.to_string());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::games::variant::table::Table;
+use crate::games::variant::PokerVariant;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::HandError;
+
+/// The `PokerVariant` version of `holdem::deal::Deal`, dealing the hole cards and each of the
+/// variant's streets out of its deck, burning a card before each of them.
+#[derive(Clone, Debug)]
+pub struct Deal<V: PokerVariant> {
+    pub deck: PlayingCards,
+    pub table: Table<V>,
+    burned: PlayingCards,
+}
+
+impl<V: PokerVariant> Deal<V> {
+    #[must_use]
+    pub fn new(variant: V) -> Deal<V> {
+        Deal {
+            deck: variant.deck().shuffle(),
+            table: Table::new(variant),
+            burned: PlayingCards::default(),
+        }
+    }
+
+    /// Returns a `Deal` that comes out of the deck in order, as long as it's the variant's deck.
+    #[must_use]
+    pub fn from_deck(variant: V, deck: PlayingCards) -> Option<Deal<V>> {
+        if deck.len() != variant.deck().len() || !deck.is_subset(&variant.deck()) {
+            return None;
+        }
+        let mut deal = Deal::new(variant);
+        deal.deck = deck;
+        Some(deal)
+    }
+
+    /// Removes a card from the deck, and places it in the burned collection of `PlayingCards`.
+    pub fn burn(&mut self) {
+        self.burned.insert(self.deck.draw_one());
+    }
+
+    /// Deals the hole cards to each seat, one card at a time around the table.
+    pub fn deal(&mut self, seats: usize) {
+        self.burn();
+        self.table.hands = vec![PlayingCards::default(); seats];
+        for _ in 0..self.table.variant.hole_cards() {
+            for hand in &mut self.table.hands {
+                hand.insert(self.deck.draw_one());
+            }
+        }
+    }
+
+    /// Has the player in the seat throw away the card, which is mucked with the burned cards
+    /// and is dead for the rest of the hand.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` if there's no such seat, a `HandError::Incomplete`
+    /// if the variant doesn't have discards, the board isn't where the variant has the players
+    /// discard, or the player has already made them, and a `HandError::InvalidCard` if the
+    /// player isn't holding the card.
+    pub fn discard(&mut self, seat: usize, card: PlayingCard) -> Result<(), HandError> {
+        if self.table.board.len() != self.table.variant.discards_after() {
+            return Err(HandError::Incomplete);
+        }
+        let kept = self.table.variant.hole_cards() - self.table.variant.discards();
+        let hand = self
+            .table
+            .hands
+            .get_mut(seat)
+            .ok_or(HandError::InvalidIndex)?;
+        if hand.len() <= kept {
+            return Err(HandError::Incomplete);
+        }
+        if !hand.contains(&card) {
+            return Err(HandError::InvalidCard);
+        }
+        *hand = hand.minus(&PlayingCards::from(card));
+        self.burned.insert(card);
+        self.table.dead.insert(card);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_showdown(&self) -> bool {
+        !self.table.hands.is_empty() && self.table.next_street().is_none()
+    }
+
+    /// Burns a card and deals the next street to the board, returning it, or an empty
+    /// collection of `PlayingCards` if the board is complete or the players still have to
+    /// make their discards.
+    pub fn next_street(&mut self) -> PlayingCards {
+        if self.table.board.len() == self.table.variant.discards_after()
+            && !self.table.has_discarded()
+        {
+            return PlayingCards::default();
+        }
+        match self.table.next_street() {
+            Some(street) => {
+                self.burn();
+                let cards = self.deck.draw(street);
+                self.table.board.append(&cards);
+                cards
+            }
+            None => PlayingCards::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn number_of_remaining_cards(&self) -> usize {
+        self.deck.len()
+    }
+
+    /// Returns the seats that win or tie for any of the pot, or an empty `Vec` if the board isn't
+    /// complete yet.
+    #[must_use]
+    pub fn winners(&self) -> Vec<usize> {
+        if !self.is_showdown() {
+            return Vec::new();
+        }
+        self.table
+            .shares()
+            .iter()
+            .enumerate()
+            .filter(|(_, share)| **share > 0.0)
+            .map(|(seat, _)| seat)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod variant_deal_tests {
+    use super::*;
+    use crate::games::holdem::holdem_game::HoldemGame;
+    use crate::games::omaha::omaha_game::OmahaGame;
+    use crate::games::pineapple::pineapple_game::PineappleGame;
+    use crate::games::short_deck::short_deck_game::ShortDeckGame;
+
+    const INDEX: &str = "2S AS 8H KS 8D 2D 7C 3C KD 9S 4C 5H 3H 6H 5D JS AC 6S 8S 7S JC 7H JD TS QS TC JH QD 6C 9C QC AH 4S 5C 4H 5S 4D QH 2C 2H AD 9D TH KH 7D KC 3S 6D 8C 3D 9H TD";
+
+    fn dealt<V: PokerVariant>(variant: V) -> Deal<V> {
+        let mut deal = Deal::from_deck(variant, PlayingCards::try_from(INDEX).unwrap()).unwrap();
+        deal.deal(2);
+        deal
+    }
+
+    #[test]
+    fn deal() {
+        let deal = dealt(HoldemGame);
+
+        assert_eq!(47, deal.number_of_remaining_cards());
+        assert_eq!(
+            "[PLAYERS: [Seat 0: A♠ K♠, Seat 1: 8♥ 8♦], BOARD: ]",
+            deal.table.to_string()
+        );
+    }
+
+    #[test]
+    fn deal__omaha() {
+        let deal = dealt(OmahaGame::BigO);
+
+        assert_eq!(41, deal.number_of_remaining_cards());
+        assert_eq!(5, deal.table.hands[1].len());
+    }
+
+    #[test]
+    fn next_street() {
+        let mut deal = dealt(HoldemGame);
+
+        assert_eq!("7♣ 3♣ K♦", deal.next_street().to_string());
+        assert_eq!("4♣", deal.next_street().to_string());
+        assert!(!deal.is_showdown());
+        assert!(deal.winners().is_empty());
+        assert_eq!("3♥", deal.next_street().to_string());
+        assert!(deal.is_showdown());
+        assert!(deal.next_street().is_empty());
+        assert_eq!(vec![0], deal.winners());
+    }
+
+    #[test]
+    fn discard() {
+        let mut deal = dealt(PineappleGame::default());
+
+        assert_eq!(
+            HandError::InvalidCard,
+            deal.discard(0, PlayingCard::from("8♥")).unwrap_err()
+        );
+        deal.discard(0, PlayingCard::from("2♦")).unwrap();
+
+        assert_eq!("A♠ K♠", deal.table.hands[0].to_string());
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(0, PlayingCard::from("A♠")).unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidIndex,
+            deal.discard(2, PlayingCard::from("A♠")).unwrap_err()
+        );
+        assert_eq!(
+            HandError::Incomplete,
+            dealt(HoldemGame)
+                .discard(0, PlayingCard::from("A♠"))
+                .unwrap_err()
+        );
+        assert_eq!("2♦", deal.table.dead.to_string());
+        assert!(!deal.table.remaining().contains(&PlayingCard::from("2♦")));
+    }
+
+    #[test]
+    fn discard__crazy_pineapple() {
+        let mut deal = dealt(PineappleGame::CrazyPineapple);
+
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(0, PlayingCard::from("2♦")).unwrap_err()
+        );
+        assert_eq!("K♦ 9♠ 4♣", deal.next_street().to_string());
+        deal.discard(0, PlayingCard::from("2♦")).unwrap();
+
+        assert!(deal.next_street().is_empty());
+        deal.discard(1, PlayingCard::from("7♣")).unwrap();
+
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(1, PlayingCard::from("8♥")).unwrap_err()
+        );
+        assert_eq!(1, deal.next_street().len());
+        assert_eq!(
+            HandError::Incomplete,
+            deal.discard(0, PlayingCard::from("A♠")).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_deck__wrong_deck() {
+        assert!(Deal::from_deck(ShortDeckGame::default(), PlayingCards::deck()).is_none());
+        assert!(Deal::from_deck(ShortDeckGame::default(), PlayingCards::short_deck()).is_some());
+    }
+}
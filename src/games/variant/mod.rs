@@ -0,0 +1,40 @@
+use crate::games::variant::showdown::Showdown;
+use crate::types::playing_cards::PlayingCards;
+
+pub mod deal;
+pub mod showdown;
+pub mod table;
+
+/// The rules that tell one flop style game apart from another: how many hole cards each player
+/// is dealt, how the board comes out, how the best hand is made at the showdown, and which
+/// cards are in the deck. The generic `variant::table::Table` and `variant::deal::Deal` are
+/// driven entirely by it, so a new game only has to describe itself to get dealing, equity and
+/// outs.
+pub trait PokerVariant {
+    /// The number of cards each player is dealt.
+    fn hole_cards(&self) -> usize;
+
+    /// The number of cards dealt to the board on each street, in order.
+    fn streets(&self) -> Vec<usize>;
+
+    fn showdown(&self) -> Showdown;
+
+    /// The number of hole cards each player has to throw away before the showdown.
+    fn discards(&self) -> usize {
+        0
+    }
+
+    /// The number of board cards that are dealt before the players make their discards, such as
+    /// none for Pineapple and the flop for Crazy Pineapple.
+    fn discards_after(&self) -> usize {
+        0
+    }
+
+    fn deck(&self) -> PlayingCards {
+        PlayingCards::deck()
+    }
+
+    fn board_size(&self) -> usize {
+        self.streets().iter().sum()
+    }
+}
@@ -0,0 +1,240 @@
+use crate::analysis::low::{LowEval, LowRank};
+use crate::analysis::short_deck_eval::{ShortDeckEval, ShortDeckRules};
+use crate::types::arrays::five_card::FiveCard;
+use crate::types::arrays::Evaluable;
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::omaha_hand::OmahaHand;
+use crate::types::U32Card;
+use ckc_rs::hand_rank::HandRank;
+use ckc_rs::PokerCard;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/// How the best hand is made at the showdown of a `PokerVariant`, and how the pot is split
+/// between them.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Showdown {
+    /// The best five cards out of the hole cards and the board, such as in hold'em.
+    #[default]
+    High,
+    /// The best five cards, ranked by `ShortDeckEval` under the `ShortDeckRules`.
+    ShortDeck(ShortDeckRules),
+    /// Exactly two of the hole cards with exactly three from the board.
+    Omaha,
+    /// The best ace-to-five low out of any five of the cards.
+    Low,
+    /// Half the pot to the `High` hand and half to the best eight or better low, if there is one.
+    HiLo,
+    /// The Omaha version of `HiLo`, with both halves made from exactly two hole cards.
+    OmahaHiLo,
+}
+
+impl Showdown {
+    /// Returns the fraction of the pot that goes to each of the hands, in order, once they're
+    /// played against the board. Ties split the pot, or their half of it, evenly.
+    #[must_use]
+    pub fn shares(&self, hands: &[PlayingCards], board: &PlayingCards) -> Vec<f32> {
+        match self {
+            Showdown::High => Showdown::split(
+                &hands
+                    .iter()
+                    .map(|hand| Showdown::high(&hand.combine(board)))
+                    .collect::<Vec<HandRank>>(),
+            ),
+            Showdown::ShortDeck(rules) => Showdown::split(
+                &hands
+                    .iter()
+                    .map(|hand| ShortDeckEval::best(&Showdown::u32s(&hand.combine(board)), *rules))
+                    .collect::<Vec<ShortDeckEval>>(),
+            ),
+            Showdown::Omaha => Showdown::split(
+                &hands
+                    .iter()
+                    .map(|hand| Showdown::omaha(hand).eval_with_board(board).rank)
+                    .collect::<Vec<HandRank>>(),
+            ),
+            Showdown::Low => Showdown::split(
+                &hands
+                    .iter()
+                    .map(|hand| LowEval::best(&Showdown::u32s(&hand.combine(board))).rank)
+                    .collect::<Vec<LowRank>>(),
+            ),
+            Showdown::HiLo => {
+                let (high, low): (Vec<HandRank>, Vec<LowRank>) = hands
+                    .iter()
+                    .map(|hand| {
+                        let cards = hand.combine(board);
+                        let low = LowEval::best(&Showdown::u32s(&cards)).eight_or_better();
+                        (Showdown::high(&cards), low.rank)
+                    })
+                    .unzip();
+                Showdown::split_hi_lo(&high, &low)
+            }
+            Showdown::OmahaHiLo => {
+                let (high, low): (Vec<HandRank>, Vec<LowRank>) = hands
+                    .iter()
+                    .map(|hand| {
+                        let omaha = Showdown::omaha(hand);
+                        (
+                            omaha.eval_with_board(board).rank,
+                            omaha.eval_low_with_board(board).rank,
+                        )
+                    })
+                    .unzip();
+                Showdown::split_hi_lo(&high, &low)
+            }
+        }
+    }
+
+    //region private functions
+
+    /// The best `HandRank` out of any five of the cards.
+    fn high(cards: &PlayingCards) -> HandRank {
+        if cards.len() == 7 {
+            return cards
+                .eval_7cards()
+                .map(|eval| eval.rank)
+                .unwrap_or_default();
+        }
+        Showdown::u32s(cards)
+            .into_iter()
+            .combinations(5)
+            .map(|five| FiveCard::from([five[0], five[1], five[2], five[3], five[4]]).hand_rank())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn omaha(hand: &PlayingCards) -> OmahaHand {
+        let omaha = OmahaHand::with_size(hand.len());
+        omaha.take_from_playing_cards(hand);
+        omaha
+    }
+
+    /// Splits the pot evenly between the best of the values.
+    #[allow(clippy::cast_precision_loss)]
+    fn split<T: Ord>(values: &[T]) -> Vec<f32> {
+        let Some(best) = values.iter().max() else {
+            return Vec::new();
+        };
+        let winners = values.iter().filter(|value| *value == best).count();
+        values
+            .iter()
+            .map(|value| {
+                if value == best {
+                    1.0 / winners as f32
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Splits half of the pot between the best high hands and half between the best lows, with
+    /// the highs taking it all if nobody has a low.
+    fn split_hi_lo(high: &[HandRank], low: &[LowRank]) -> Vec<f32> {
+        let highs = Showdown::split(high);
+        if low.iter().all(LowRank::is_none) {
+            return highs;
+        }
+        highs
+            .iter()
+            .zip(Showdown::split(low))
+            .map(|(high, low)| (high + low) / 2.0)
+            .collect()
+    }
+
+    fn u32s(cards: &PlayingCards) -> Vec<U32Card> {
+        cards.iter().map(PlayingCard::as_u32).collect()
+    }
+
+    //endregion
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod variant_showdown_tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn hands(indexes: &[&'static str]) -> Vec<PlayingCards> {
+        indexes
+            .iter()
+            .map(|index| PlayingCards::try_from(*index).unwrap())
+            .collect()
+    }
+
+    fn board(index: &'static str) -> PlayingCards {
+        PlayingCards::try_from(index).unwrap()
+    }
+
+    #[rstest]
+    #[case(Showdown::High, vec![0.0, 1.0])]
+    #[case(Showdown::ShortDeck(ShortDeckRules::TripsBeatStraights), vec![1.0, 0.0])]
+    #[case(Showdown::ShortDeck(ShortDeckRules::StraightsBeatTrips), vec![0.0, 1.0])]
+    fn shares__high(#[case] showdown: Showdown, #[case] expected: Vec<f32>) {
+        let hands = hands(&["K♠ K♥", "T♠ 9♥"]);
+
+        assert_eq!(expected, showdown.shares(&hands, &board("K♦ Q♣ J♦ 7♠ 6♥")));
+    }
+
+    #[test]
+    fn shares__high__chopped() {
+        let hands = hands(&["A♠ 2♥", "A♥ 3♠", "7♥ 6♣"]);
+
+        assert_eq!(
+            vec![0.5, 0.5, 0.0],
+            Showdown::High.shares(&hands, &board("A♦ A♣ K♠ Q♥ J♦"))
+        );
+    }
+
+    #[test]
+    fn shares__omaha() {
+        // Seat 0 can't play the flush in Omaha with only one heart.
+        let hands = hands(&["A♥ K♠ Q♠ J♣", "8♠ 8♣ 3♦ 5♣"]);
+        let board = board("9♥ 8♥ 7♥ 4♥ 2♦");
+
+        assert_eq!(vec![1.0, 0.0], Showdown::High.shares(&hands, &board));
+        assert_eq!(vec![0.0, 1.0], Showdown::Omaha.shares(&hands, &board));
+    }
+
+    #[test]
+    fn shares__low() {
+        let hands = hands(&["A♠ 2♥ 3♣", "K♠ K♥ K♦"]);
+
+        assert_eq!(
+            vec![1.0, 0.0],
+            Showdown::Low.shares(&hands, &board("4♦ 7♣ Q♥ J♠"))
+        );
+    }
+
+    #[test]
+    fn shares__hi_lo() {
+        let hands = hands(&["A♠ 2♥", "K♠ K♥"]);
+
+        assert_eq!(
+            vec![0.5, 0.5],
+            Showdown::HiLo.shares(&hands, &board("K♦ 7♣ 5♥ 4♠ 9♦"))
+        );
+        assert_eq!(
+            vec![0.0, 1.0],
+            Showdown::HiLo.shares(&hands, &board("K♦ T♣ 9♥ 4♠ 9♦"))
+        );
+    }
+
+    #[test]
+    fn shares__omaha_hi_lo() {
+        let hands = hands(&["A♠ 2♥ K♦ K♣", "A♦ A♣ Q♥ J♥"]);
+
+        assert_eq!(
+            vec![0.5, 0.5],
+            Showdown::OmahaHiLo.shares(&hands, &board("3♠ 4♥ 8♦ Q♠ A♥"))
+        );
+    }
+
+    #[test]
+    fn shares__empty() {
+        assert!(Showdown::High.shares(&[], &board("A♦ A♣ K♠")).is_empty());
+    }
+}
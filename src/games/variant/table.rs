@@ -0,0 +1,446 @@
+use crate::analysis::chances::Chances;
+use crate::analysis::monte_carlo::MonteCarlo;
+use crate::analysis::outs::Outs;
+use crate::analysis::sampled_chances::SampledChances;
+use crate::analysis::Evaluate;
+use crate::games::variant::PokerVariant;
+use crate::types::playing_cards::PlayingCards;
+use ckc_rs::HandError;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use wyz::FmtForward;
+
+/// A `Table` for any `PokerVariant`, holding each player's hole cards along with however much
+/// of the board has been dealt. Everything about the game, from the deck the rest of the board
+/// comes out of to how the pot is split at the showdown, comes from the variant.
+#[derive(Clone, Debug, Default)]
+pub struct Table<V: PokerVariant> {
+    pub variant: V,
+    pub hands: Vec<PlayingCards>,
+    pub board: PlayingCards,
+    /// Cards that are out of play without being on the board or in anyone's hand, such as the
+    /// ones the players have discarded.
+    pub dead: PlayingCards,
+}
+
+impl<V: PokerVariant> Table<V> {
+    #[must_use]
+    pub fn new(variant: V) -> Table<V> {
+        Table {
+            variant,
+            hands: Vec::new(),
+            board: PlayingCards::default(),
+            dead: PlayingCards::default(),
+        }
+    }
+
+    /// Takes a Card index string for each player's hole cards and one for the board, which can
+    /// be empty or dealt up to the end of any of the variant's streets.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidCard` if any of the cards are invalid or aren't in the
+    /// variant's deck, and a `HandError::DuplicateCard` if any of them are dealt twice.
+    ///
+    /// Throws a `HandError::InvalidCardCount` if a player isn't holding the variant's number of
+    /// hole cards, less any discards, or if the board stops partway through a street.
+    ///
+    /// Throws a `HandError::Incomplete` if the board has been dealt past where the players
+    /// discard and any of them are still holding all of their hole cards.
+    pub fn from_index(variant: V, hands: &[&str], board: &str) -> Result<Table<V>, HandError> {
+        let hands = hands
+            .iter()
+            .map(|index| PlayingCards::try_from(*index))
+            .collect::<Result<Vec<PlayingCards>, HandError>>()?;
+        Table::deal(variant, hands, PlayingCards::try_from(board)?)
+    }
+
+    /// Returns a `Table` with the hands and board dealt to it.
+    ///
+    /// # Errors
+    ///
+    /// Throws the same `HandErrors` as `from_index()`.
+    pub fn deal(
+        variant: V,
+        hands: Vec<PlayingCards>,
+        board: PlayingCards,
+    ) -> Result<Table<V>, HandError> {
+        let table = Table {
+            variant,
+            hands,
+            board,
+            dead: PlayingCards::default(),
+        };
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Returns each player's equity, as a percentage, over every way that the rest of the
+    /// board can come out.
+    ///
+    /// **NOTE** Like `holdem::table::Table`, this doesn't take into account any burned cards.
+    /// It's empty until every player has made their discards.
+    #[must_use]
+    pub fn chances(&self) -> Chances {
+        let remaining = self.remaining();
+        self.chances_for(
+            remaining
+                .combinations(self.to_come())
+                .map(|v| self.board.combine(&PlayingCards::from(v))),
+        )
+    }
+
+    /// Estimates each player's equity by running the rest of the board out at random for the
+    /// number of samples. The same seed always deals the same boards.
+    #[must_use]
+    pub fn chances_sampled(&self, samples: usize, seed: u64) -> SampledChances {
+        self.monte_carlo(&MonteCarlo::new(samples, seed))
+    }
+
+    /// The `MonteCarlo` version of `chances_sampled()`, which can stop as soon as the estimate
+    /// is precise enough. Like `chances()`, it's empty until every player has discarded.
+    #[must_use]
+    pub fn monte_carlo(&self, monte_carlo: &MonteCarlo) -> SampledChances {
+        if !self.has_discarded() {
+            return SampledChances::default();
+        }
+        monte_carlo.run(&self.remaining(), self.to_come(), |cards| {
+            self.variant
+                .showdown()
+                .shares(&self.hands, &self.board.combine(cards))
+                .into_iter()
+                .enumerate()
+                .collect()
+        })
+    }
+
+    #[must_use]
+    pub fn dealt(&self) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for hand in &self.hands {
+            cards.append(hand);
+        }
+        cards.combine(&self.board)
+    }
+
+    /// Returns true once every player is down to the hole cards they keep for the showdown.
+    #[must_use]
+    pub fn has_discarded(&self) -> bool {
+        let kept = self.variant.hole_cards() - self.variant.discards();
+        self.hands.iter().all(|hand| hand.len() == kept)
+    }
+
+    /// Returns, for each player, the cards that would have them winning or tied if they came
+    /// next. It's only for when the next street is a single card, like the turn and the river
+    /// in hold'em, and is empty otherwise, or if anyone still has to discard.
+    #[must_use]
+    pub fn outs(&self) -> Outs {
+        let mut outs = Outs::default();
+        if self.next_street() != Some(1) || !self.has_discarded() {
+            return outs;
+        }
+        for card in self.remaining().iter() {
+            let board = self.board.combine(&PlayingCards::from(*card));
+            for (player, share) in self
+                .variant
+                .showdown()
+                .shares(&self.hands, &board)
+                .iter()
+                .enumerate()
+            {
+                if *share > 0.0 {
+                    outs.add(player, *card);
+                }
+            }
+        }
+        outs
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> PlayingCards {
+        self.variant.deck().minus(&self.dealt()).minus(&self.dead)
+    }
+
+    /// Returns the fraction of the pot each player gets with the board as it is, which is
+    /// empty until every player has made their discards.
+    #[must_use]
+    pub fn shares(&self) -> Vec<f32> {
+        if !self.has_discarded() {
+            return Vec::new();
+        }
+        self.variant.showdown().shares(&self.hands, &self.board)
+    }
+
+    /// Returns the number of cards on the next street, or `None` once the board is complete.
+    #[must_use]
+    pub fn next_street(&self) -> Option<usize> {
+        let mut dealt = 0;
+        for street in self.variant.streets() {
+            if dealt == self.board.len() {
+                return Some(street);
+            }
+            dealt += street;
+        }
+        None
+    }
+
+    /// The number of board cards still to come.
+    #[must_use]
+    pub fn to_come(&self) -> usize {
+        self.variant.board_size().saturating_sub(self.board.len())
+    }
+
+    //region private functions
+
+    /// Adds up each player's share of the pot as the boards come, without holding on to them.
+    fn chances_for(&self, boards: impl Iterator<Item = PlayingCards>) -> Chances {
+        let mut chances = Chances::default();
+        if !self.has_discarded() {
+            return chances;
+        }
+        let mut totals = vec![0.0_f32; self.hands.len()];
+        let mut count = 0;
+        for board in boards {
+            let shares = self.variant.showdown().shares(&self.hands, &board);
+            for (total, share) in totals.iter_mut().zip(shares) {
+                *total += share;
+            }
+            count += 1;
+        }
+        for (player, total) in totals.into_iter().enumerate() {
+            chances.set(player, Evaluate::percent(1, count) * total);
+        }
+        chances
+    }
+
+    fn validate(&self) -> Result<(), HandError> {
+        let held = self.variant.hole_cards();
+        let kept = held - self.variant.discards();
+        if self
+            .hands
+            .iter()
+            .any(|hand| hand.len() != held && hand.len() != kept)
+        {
+            return Err(HandError::InvalidCardCount);
+        }
+        if self.board.len() > self.variant.board_size()
+            || (self.to_come() > 0 && self.next_street().is_none())
+        {
+            return Err(HandError::InvalidCardCount);
+        }
+        if self.board.len() > self.variant.discards_after() && !self.has_discarded() {
+            return Err(HandError::Incomplete);
+        }
+        let dealt = self.dealt();
+        if !dealt.is_subset(&self.variant.deck()) {
+            return Err(HandError::InvalidCard);
+        }
+        let count: usize = self.hands.iter().map(PlayingCards::len).sum();
+        if dealt.len() != count + self.board.len() {
+            return Err(HandError::DuplicateCard);
+        }
+        Ok(())
+    }
+
+    //endregion
+}
+
+impl<V: PokerVariant> Display for Table<V> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let players = self
+            .hands
+            .iter()
+            .enumerate()
+            .map(|(player, hand)| format!("Seat {player}: {hand}"))
+            .join(", ");
+        let mut out = fmt.debug_list();
+
+        out.entry(&(format!("PLAYERS: [{players}]")).fmt_display());
+        out.entry(&(format!("BOARD: {}", self.board)).fmt_display());
+
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod variant_table_tests {
+    use super::*;
+    use crate::analysis::short_deck_eval::ShortDeckRules;
+    use crate::games::holdem;
+    use crate::games::holdem::holdem_game::HoldemGame;
+    use crate::games::omaha::omaha_game::OmahaGame;
+    use crate::games::pineapple::pineapple_game::PineappleGame;
+    use crate::games::short_deck::short_deck_game::ShortDeckGame;
+    use crate::types::card_slot::CardSlot;
+    use crate::types::playing_card::PlayingCard;
+
+    #[test]
+    fn from_index() {
+        let table = Table::from_index(HoldemGame, &["A♠ A♥", "K♠ K♥"], "A♦ 9♣ 7♠").unwrap();
+
+        assert_eq!(Some(1), table.next_street());
+        assert_eq!(2, table.to_come());
+        assert_eq!(45, table.remaining().len());
+        assert_eq!(
+            "[PLAYERS: [Seat 0: A♠ A♥, Seat 1: K♠ K♥], BOARD: A♦ 9♣ 7♠]",
+            table.to_string()
+        );
+    }
+
+    #[test]
+    fn from_index__errors() {
+        assert_eq!(
+            HandError::InvalidCardCount,
+            Table::from_index(HoldemGame, &["A♠ A♥ 2♣", "K♠ K♥"], "").unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidCardCount,
+            Table::from_index(HoldemGame, &["A♠ A♥", "K♠ K♥"], "A♦ 9♣").unwrap_err()
+        );
+        assert_eq!(
+            HandError::DuplicateCard,
+            Table::from_index(HoldemGame, &["A♠ A♥", "A♠ K♥"], "").unwrap_err()
+        );
+        assert_eq!(
+            HandError::InvalidCard,
+            Table::from_index(ShortDeckGame::default(), &["A♠ A♥", "2♠ K♥"], "").unwrap_err()
+        );
+    }
+
+    /// The generic `Table` comes up with the same odds as the hold'em one.
+    #[test]
+    fn chances__holdem() {
+        let index = "A♠ K♠ A♥ K♥ A♦ 9♣ 7♠";
+        let holdem = holdem::table::Table::seat(2);
+        holdem.take_from_index(index);
+        let expected = holdem.chances_at_flop();
+
+        let table = Table::from_index(HoldemGame, &["A♠ A♥", "K♠ K♥"], "A♦ 9♣ 7♠").unwrap();
+        let chances = table.chances();
+
+        assert!((expected.get(0) - chances.get(0)).abs() < 0.01);
+        assert!((expected.get(1) - chances.get(1)).abs() < 0.01);
+    }
+
+    #[test]
+    fn chances__short_deck() {
+        let game = ShortDeckGame::new(ShortDeckRules::StraightsBeatTrips);
+        let table = Table::from_index(game, &["K♠ K♥", "T♠ 9♥"], "K♦ Q♣ J♦ 7♠").unwrap();
+
+        let chances = table.chances();
+
+        assert_eq!(28, table.remaining().len());
+        assert!(chances.get(1) > chances.get(0));
+        assert!(chances.keeping_it_100());
+    }
+
+    #[test]
+    fn chances__omaha_hi_lo() {
+        let table = Table::from_index(
+            OmahaGame::OmahaHiLo,
+            &["A♠ 2♥ K♦ K♣", "A♦ A♣ Q♥ J♥"],
+            "3♠ 4♥ 8♦ Q♠",
+        )
+        .unwrap();
+
+        let chances = table.chances();
+
+        assert!(chances.get(0) > 50.0);
+        assert!(chances.keeping_it_100());
+    }
+
+    #[test]
+    fn chances_sampled() {
+        let table = Table::from_index(HoldemGame, &["A♠ A♥", "7♣ 2♦"], "").unwrap();
+
+        let sampled = table.chances_sampled(200, 42);
+        let chances = sampled.chances();
+
+        assert_eq!(200, sampled.samples());
+        assert!(chances.get(0) > chances.get(1));
+        assert!(chances.keeping_it_100());
+        assert_eq!(
+            chances.get(0),
+            table.chances_sampled(200, 42).chances().get(0)
+        );
+    }
+
+    #[test]
+    fn outs() {
+        let table = Table::from_index(HoldemGame, &["A♠ A♥", "K♠ K♥"], "K♦ 9♣ 7♠ 2♥").unwrap();
+
+        let outs = table.outs();
+
+        assert_eq!(42, outs.get(1).unwrap().len());
+        assert_eq!(
+            vec![PlayingCard::from("A♦"), PlayingCard::from("A♣")],
+            outs.get_as_poker_cards(0).to_vec()
+        );
+    }
+
+    #[test]
+    fn outs__not_a_single_card_street() {
+        let table = Table::from_index(HoldemGame, &["A♠ A♥", "K♠ K♥"], "").unwrap();
+
+        assert!(table.outs().iter().next().is_none());
+    }
+
+    #[test]
+    fn shares__pineapple() {
+        let table = Table::from_index(
+            PineappleGame::default(),
+            &["A♠ A♥", "K♠ K♥"],
+            "A♦ 9♣ 7♠ 2♥ K♦",
+        )
+        .unwrap();
+
+        assert_eq!(None, table.next_street());
+        assert_eq!(vec![1.0, 0.0], table.shares());
+    }
+
+    #[test]
+    fn from_index__pineapple_not_discarded() {
+        assert_eq!(
+            HandError::Incomplete,
+            Table::from_index(
+                PineappleGame::default(),
+                &["A♠ A♥", "K♠ K♥ Q♣"],
+                "A♦ 9♣ 7♠ 2♥ K♦",
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            HandError::Incomplete,
+            Table::from_index(
+                PineappleGame::CrazyPineapple,
+                &["A♠ A♥ 2♣", "K♠ K♥ Q♣"],
+                "A♦ 9♣ 7♠ 2♥",
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn chances__crazy_pineapple() {
+        let mut table = Table::from_index(
+            PineappleGame::CrazyPineapple,
+            &["A♠ A♥ 2♣", "K♠ K♥ Q♣"],
+            "A♦ 9♣ 7♠",
+        )
+        .unwrap();
+
+        assert!(table.chances().seats().next().is_none());
+        assert!(table.shares().is_empty());
+
+        table.hands = vec![
+            PlayingCards::try_from("A♠ A♥").unwrap(),
+            PlayingCards::try_from("K♠ K♥").unwrap(),
+        ];
+        table.dead = PlayingCards::try_from("2♣ Q♣").unwrap();
+
+        assert_eq!(43, table.remaining().len());
+        assert_eq!(43 * 42 / 2, table.remaining().combinations(2).count());
+        assert!(table.chances().get(0) > 90.0);
+    }
+}
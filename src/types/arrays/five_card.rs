@@ -1,3 +1,4 @@
+use crate::analysis::deuce_to_seven::DeuceToSevenEval;
 use crate::analysis::eval::Eval;
 use crate::analysis::evals::Evals;
 use crate::analysis::Evaluate;
@@ -41,6 +42,13 @@ impl FiveCard {
         FiveCard(array)
     }
 
+    /// Returns the hand played for deuce-to-seven low, where aces are high, and straights and
+    /// flushes count against it.
+    #[must_use]
+    pub fn deuce_to_seven(&self) -> DeuceToSevenEval {
+        DeuceToSevenEval::from(*self)
+    }
+
     #[must_use]
     pub fn first(&self) -> U32Card {
         self.0[0]
@@ -179,7 +187,7 @@ impl TryFrom<&PokerCards> for FiveCard {
     }
 }
 
-impl TryFrom<&'static str> for FiveCard {
+impl TryFrom<&str> for FiveCard {
     type Error = HandError;
 
     /// Returns a valid `CactusKevHand` if the entered index string splits out into exactly
@@ -241,7 +249,7 @@ impl TryFrom<&'static str> for FiveCard {
     ///
     /// Shouldn't be able to panic. (fingers crossed)
     ///
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match PokerCards::try_from(value) {
             Ok(cards) => FiveCard::try_from(&cards),
             Err(e) => Err(e),
@@ -303,6 +311,16 @@ mod types_arrays_five_card_tests {
         assert_eq!(FiveCard::from(raw).sort(), expected);
     }
 
+    #[test]
+    fn deuce_to_seven() {
+        let hand = FiveCard::try_from("2♠ 3♥ 4♦ 5♣ A♠").unwrap();
+
+        assert_eq!(
+            "A♠ 5♣ 4♦ 3♥ 2♠ A-5-4-3-2",
+            hand.deuce_to_seven().to_string()
+        );
+    }
+
     #[test]
     fn display() {
         let hand = FiveCard::try_from("QS AS KS JS T♠").unwrap();
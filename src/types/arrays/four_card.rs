@@ -1,16 +1,15 @@
+use crate::analysis::badugi::BadugiEval;
 use crate::analysis::eval::Eval;
+use crate::analysis::low::LowEval;
 use crate::types::arrays::five_card::FiveCard;
-use crate::types::arrays::six_card::SixCard;
-use crate::types::arrays::three_card::ThreeCard;
 use crate::types::arrays::two_card::TwoCard;
-use crate::types::arrays::{Evaluable, Vectorable};
+use crate::types::arrays::{Omaha, Vectorable};
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::poker_cards::PokerCards;
 use crate::types::slots::flop::Flop;
 use crate::types::slots::omaha_hand::OmahaHand;
 use crate::types::U32Card;
-use ckc_rs::hand_rank::{HandRank, HandRankName};
 use ckc_rs::{HandError, PokerCard};
 use std::fmt;
 
@@ -27,43 +26,50 @@ impl FourCard {
     /// permutations to evaluate all `Omaha` style combinations.
     pub const PERMUTATIONS: [[u8; 2]; 6] = [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]];
 
+    /// Returns the hand played for Badugi, where the lowest set of cards with different ranks
+    /// and suits wins.
+    #[must_use]
+    pub fn badugi(&self) -> BadugiEval {
+        BadugiEval::from(*self)
+    }
+
     pub fn best_at_flop(&self, flop: &Flop) -> Eval {
-        let three = ThreeCard::from(flop);
-        let mut best_hand = FiveCard::default();
-        let mut best_rank = HandRank::default();
-        for i in 0..FourCard::PERMUTATIONS.len() {
-            let hand = FiveCard::from_2_and_3(self.permutation(i), three);
-            let (_, rank) = hand.evaluate();
-            if rank > best_rank {
-                best_rank = rank;
-                best_hand = hand;
-            }
-        }
-        Eval::new(best_hand, best_rank)
+        Omaha::best_at_flop(self, flop)
+    }
+
+    /// Returns the best hand at the turn, made up of exactly two cards from the `FourCard`
+    /// hand and three of the four cards on the board.
+    #[must_use]
+    pub fn best_at_turn(&self, board: &FourCard) -> Eval {
+        self.best_from_board(&board.to_arr())
+    }
+
+    /// Returns the best hand at the river, made up of exactly two cards from the `FourCard`
+    /// hand and three of the five cards on the board.
+    #[must_use]
+    pub fn best_at_river(&self, board: &FiveCard) -> Eval {
+        self.best_from_board(&board.to_arr())
+    }
+
+    /// Returns the best hand made up of exactly two cards from the `FourCard` hand and
+    /// exactly three from the board, which can hold anywhere from three to five cards.
+    /// Returns a default `Eval` if there aren't enough cards on the board.
+    #[must_use]
+    pub fn best_from_board(&self, board: &[U32Card]) -> Eval {
+        Omaha::best_from_board(self, board)
+    }
+
+    /// Returns the best eight or better ace-to-five low made up of exactly two cards from the
+    /// `FourCard` hand and exactly three from the board, or a default `LowEval` if the hand
+    /// can't make a qualifying low.
+    #[must_use]
+    pub fn best_low_from_board(&self, board: &[U32Card]) -> LowEval {
+        Omaha::best_low_from_board(self, board)
     }
 
     /// Calculates the straight outs for a particular flop.
     pub fn straight_outs_at_flop(&self, flop: &Flop) -> PlayingCards {
-        // This feels sick to me
-        let mut outs = PlayingCards::default();
-        let three = ThreeCard::from(flop);
-
-        for card in self.remaining().to_vec() {
-            for i in 0..FourCard::PERMUTATIONS.len() {
-                let hand = SixCard::from_1_and_2_and_3(card.as_u32(), self.permutation(i), three);
-                let evals = hand.evals();
-                for e in evals.to_vec() {
-                    if e.hand.contains(&card.as_u32())
-                        && (e.rank.name == HandRankName::Straight
-                            || e.rank.name == HandRankName::StraightFlush)
-                    {
-                        outs.insert(card);
-                    }
-                }
-            }
-        }
-
-        outs
+        Omaha::straight_outs_at_flop(self, flop)
     }
 
     //region getters
@@ -117,9 +123,14 @@ impl fmt::Display for FourCard {
     }
 }
 
-impl From<OmahaHand> for FourCard {
-    fn from(hand: OmahaHand) -> Self {
-        FourCard::from(hand.to_array())
+impl TryFrom<OmahaHand> for FourCard {
+    type Error = HandError;
+
+    /// # Errors
+    ///
+    /// Throws a `HandError::TooManyCards` if the `OmahaHand` is for five or six card Omaha.
+    fn try_from(hand: OmahaHand) -> Result<Self, Self::Error> {
+        FourCard::try_from(hand.to_vec())
     }
 }
 
@@ -148,10 +159,10 @@ impl TryFrom<&PokerCards> for FourCard {
     }
 }
 
-impl TryFrom<&'static str> for FourCard {
+impl TryFrom<&str> for FourCard {
     type Error = HandError;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match PokerCards::try_from(value) {
             Ok(cards) => FourCard::try_from(&cards),
             Err(e) => Err(e),
@@ -176,6 +187,8 @@ impl TryFrom<Vec<U32Card>> for FourCard {
     }
 }
 
+impl Omaha for FourCard {}
+
 impl Vectorable for FourCard {
     #[must_use]
     fn to_vec(&self) -> Vec<U32Card> {
@@ -189,6 +202,13 @@ mod types_arrays_four_card_tests {
     use super::*;
     use ckc_rs::hand_rank::HandRankClass;
 
+    #[test]
+    fn badugi() {
+        let four = FourCard::try_from("2♠ 2♥ A♦ K♣").unwrap();
+
+        assert_eq!("K♣ 2♥ A♦ 2♠ K-2-A", four.badugi().to_string());
+    }
+
     #[test]
     fn best_at_flop() {
         let four = FourCard::try_from("KS AH AS KH").unwrap();
@@ -200,6 +220,56 @@ mod types_arrays_four_card_tests {
         );
     }
 
+    #[test]
+    fn best_at_turn() {
+        let four = FourCard::try_from("A♠ A♥ 7♦ 2♣").unwrap();
+        let board = FourCard::try_from("K♠ Q♠ J♠ T♠").unwrap();
+
+        // Only three of the board's cards can play, so no royal flush, or even a straight.
+        let eval = four.best_at_turn(&board);
+
+        assert_eq!(HandRankClass::PairOfAces, eval.rank.class);
+        assert_eq!("A♠ A♥ K♠ Q♠ J♠", eval.hand.to_string());
+    }
+
+    #[test]
+    fn best_at_river() {
+        let four = FourCard::try_from("K♠ K♥ 7♦ 2♣").unwrap();
+        let board = FiveCard::try_from("A♥ A♦ A♣ A♠ 8♥").unwrap();
+
+        // Two cards from the hand and three from the board, so no quad aces.
+        let eval = four.best_at_river(&board);
+
+        assert_eq!(HandRankClass::AcesOverKings, eval.rank.class);
+    }
+
+    #[test]
+    fn best_from_board__not_enough_cards() {
+        let four = FourCard::try_from("A♠ K♥ 7♦ 2♣").unwrap();
+
+        assert_eq!(Eval::default(), four.best_from_board(&[]));
+    }
+
+    #[test]
+    fn best_low_from_board() {
+        let four = FourCard::try_from("A♠ 2♥ K♦ K♣").unwrap();
+        let board = FiveCard::try_from("3♠ 4♥ 8♦ Q♣ J♠").unwrap();
+
+        let low = four.best_low_from_board(&board.to_arr());
+
+        assert_eq!("8-4-3-2-A", low.rank.to_string());
+        assert_eq!("8♦ 4♥ 3♠ 2♥ A♠", low.hand.to_string());
+    }
+
+    #[test]
+    fn best_low_from_board__two_from_hand() {
+        // Four low cards on the board, but only one in the hand, is no low in Omaha.
+        let four = FourCard::try_from("A♠ K♥ K♦ Q♣").unwrap();
+        let board = FiveCard::try_from("2♠ 3♥ 4♦ 5♣ J♠").unwrap();
+
+        assert!(four.best_low_from_board(&board.to_arr()).is_none());
+    }
+
     #[test]
     fn is_double_suited() {
         assert!(FourCard::try_from("KS AH AS KH")
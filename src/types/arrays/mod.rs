@@ -1,15 +1,23 @@
 use crate::analysis::eval::Eval;
 use crate::analysis::evals::Evals;
+use crate::analysis::low::LowEval;
 use crate::types::arrays::five_card::FiveCard;
+use crate::types::arrays::six_card::SixCard;
+use crate::types::arrays::three_card::ThreeCard;
+use crate::types::arrays::two_card::TwoCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::poker_cards::PokerCards;
+use crate::types::slots::flop::Flop;
 use crate::types::U32Card;
 use cardpack::Pile;
-use ckc_rs::hand_rank::HandRank;
+use ckc_rs::hand_rank::{HandRank, HandRankName};
 use ckc_rs::{CardNumber, PokerCard};
+use itertools::Itertools;
 
 pub mod five_card;
 pub mod four_card;
+pub mod omaha_five;
+pub mod omaha_six;
 pub mod range_vector;
 pub mod seven_card;
 pub mod six_card;
@@ -34,6 +42,90 @@ pub trait Evaluable {
 
     /// Returns all possible `Evals` for the hand
     fn evals(&self) -> Evals;
+
+    /// Returns just the `HandRank` of the best `FiveCards` hand, for when the cards
+    /// that make it up don't matter, such as when comparing hands.
+    fn hand_rank(&self) -> HandRank {
+        self.evaluate().1
+    }
+}
+
+/// Omaha style starting hands, such as `FourCard`, `OmahaFive` and `OmahaSix`, where exactly
+/// two of the hole cards have to play with exactly three cards from the board.
+pub trait Omaha: Vectorable {
+    /// Returns every two card combination out of the hole cards, in order, which for a
+    /// `FourCard` hand matches `FourCard::PERMUTATIONS`.
+    fn pairs(&self) -> Vec<TwoCard> {
+        self.to_vec()
+            .into_iter()
+            .combinations(2)
+            .map(TwoCard::from)
+            .collect()
+    }
+
+    fn best_at_flop(&self, flop: &Flop) -> Eval {
+        self.best_from_board(&ThreeCard::from(flop).to_arr())
+    }
+
+    /// Returns the best hand made up of exactly two of the hole cards and exactly three from the
+    /// board, which can hold anywhere from three to five cards. Returns a default `Eval` if
+    /// there aren't enough cards on the board.
+    fn best_from_board(&self, board: &[U32Card]) -> Eval {
+        let mut best_hand = FiveCard::default();
+        let mut best_rank = HandRank::default();
+        for two in self.pairs() {
+            for three in board.iter().combinations(3) {
+                let hand =
+                    FiveCard::from([two.first(), two.second(), *three[0], *three[1], *three[2]]);
+                let (_, rank) = hand.evaluate();
+                if rank > best_rank {
+                    best_rank = rank;
+                    best_hand = hand;
+                }
+            }
+        }
+        Eval::new(best_hand, best_rank)
+    }
+
+    /// Returns the best eight or better ace-to-five low made up of exactly two of the hole cards
+    /// and exactly three from the board, or a default `LowEval` if the hand can't make a
+    /// qualifying low.
+    fn best_low_from_board(&self, board: &[U32Card]) -> LowEval {
+        let mut best = LowEval::default();
+        for two in self.pairs() {
+            for three in board.iter().combinations(3) {
+                let low =
+                    LowEval::from([two.first(), two.second(), *three[0], *three[1], *three[2]])
+                        .eight_or_better();
+                if low > best {
+                    best = low;
+                }
+            }
+        }
+        best
+    }
+
+    /// Calculates the straight outs for a particular flop.
+    fn straight_outs_at_flop(&self, flop: &Flop) -> PlayingCards {
+        let mut outs = PlayingCards::default();
+        let three = ThreeCard::from(flop);
+
+        for card in self.remaining().to_vec() {
+            for two in self.pairs() {
+                let hand = SixCard::from_1_and_2_and_3(card.as_u32(), two, three);
+                for e in hand.evals().to_vec() {
+                    if e.hand.contains(&card.as_u32())
+                        && (e.rank.name == HandRankName::Straight
+                            || e.rank.name == HandRankName::StraightFlush)
+                    {
+                        outs.insert(card);
+                    }
+                }
+            }
+        }
+
+        outs
+    }
 }
 
 pub trait Vectorable {
@@ -72,6 +164,23 @@ pub trait Vectorable {
         f.count_ones() as u8
     }
 
+    /// Returns the number of suits that at least two of the cards share, which is what makes
+    /// an Omaha hand single or double suited.
+    #[allow(clippy::cast_possible_truncation)]
+    fn suited_count(&self) -> u8 {
+        let cards = self.to_vec();
+        [0b1000, 0b0100, 0b0010, 0b0001]
+            .iter()
+            .filter(|suit| {
+                cards
+                    .iter()
+                    .filter(|card| card.get_suit_bit() == **suit)
+                    .count()
+                    > 1
+            })
+            .count() as u8
+    }
+
     fn to_pile(&self) -> Pile {
         PokerCards::from(self.to_vec()).to_pile()
     }
@@ -0,0 +1,158 @@
+use crate::types::arrays::{Omaha, Vectorable};
+use crate::types::playing_card::PlayingCard;
+use crate::types::poker_cards::PokerCards;
+use crate::types::U32Card;
+use ckc_rs::{HandError, PokerCard};
+use std::fmt;
+
+/// Five card Omaha starting hand, for PLO5 and
+/// [Big O](https://en.wikipedia.org/wiki/Omaha_hold_%27em#Big_O), where exactly two of the
+/// five cards play, which is ten different pairs instead of the six in a `FourCard` hand.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmahaFive(pub [U32Card; 5]);
+
+impl OmahaFive {
+    #[must_use]
+    pub fn is_double_suited(&self) -> bool {
+        self.suited_count() == 2
+    }
+
+    #[must_use]
+    pub fn to_arr(&self) -> [U32Card; 5] {
+        self.0
+    }
+}
+
+impl fmt::Display for OmahaFive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_poker_cards())
+    }
+}
+
+impl From<[PlayingCard; 5]> for OmahaFive {
+    fn from(array: [PlayingCard; 5]) -> Self {
+        OmahaFive(array.map(|card| card.as_u32()))
+    }
+}
+
+impl From<[U32Card; 5]> for OmahaFive {
+    fn from(value: [U32Card; 5]) -> Self {
+        OmahaFive(value)
+    }
+}
+
+impl TryFrom<&PokerCards> for OmahaFive {
+    type Error = HandError;
+
+    fn try_from(value: &PokerCards) -> Result<Self, Self::Error> {
+        OmahaFive::try_from(value.to_vec())
+    }
+}
+
+impl TryFrom<&str> for OmahaFive {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        OmahaFive::try_from(&PokerCards::try_from(value)?)
+    }
+}
+
+impl TryFrom<Vec<U32Card>> for OmahaFive {
+    type Error = HandError;
+
+    fn try_from(value: Vec<U32Card>) -> Result<Self, Self::Error> {
+        match value.len() {
+            0..=4 => Err(HandError::NotEnoughCards),
+            5 => Ok(OmahaFive(
+                value.try_into().map_err(|_| HandError::InvalidCardCount)?,
+            )),
+            _ => Err(HandError::TooManyCards),
+        }
+    }
+}
+
+impl Omaha for OmahaFive {}
+
+impl Vectorable for OmahaFive {
+    fn to_vec(&self) -> Vec<U32Card> {
+        self.0.to_vec()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_arrays_omaha_five_tests {
+    use super::*;
+    use crate::types::arrays::five_card::FiveCard;
+    use crate::types::slots::flop::Flop;
+    use ckc_rs::hand_rank::HandRankClass;
+    use rstest::rstest;
+
+    #[test]
+    fn pairs() {
+        let five = OmahaFive::try_from("A♠ K♠ Q♠ J♠ T♠").unwrap();
+
+        let pairs = five.pairs();
+
+        assert_eq!(10, pairs.len());
+        assert_eq!("A♠ K♠", pairs[0].to_string());
+        assert_eq!("J♠ T♠", pairs[9].to_string());
+    }
+
+    #[test]
+    fn best_from_board() {
+        let five = OmahaFive::try_from("K♠ K♥ 7♦ 2♣ 8♠").unwrap();
+        let board = FiveCard::try_from("A♥ A♦ A♣ A♠ 8♥").unwrap();
+
+        // Two cards from the hand and three from the board, so still no quad aces.
+        let eval = five.best_from_board(&board.to_arr());
+
+        assert_eq!(HandRankClass::AcesOverKings, eval.rank.class);
+    }
+
+    #[test]
+    fn best_low_from_board__big_o() {
+        let five = OmahaFive::try_from("A♠ K♥ K♦ Q♣ 2♥").unwrap();
+        let board = FiveCard::try_from("3♠ 4♥ 8♦ Q♦ J♠").unwrap();
+
+        // The fifth card is the one that gets there for low.
+        let low = five.best_low_from_board(&board.to_arr());
+
+        assert_eq!("8-4-3-2-A", low.rank.to_string());
+    }
+
+    #[test]
+    fn straight_outs_at_flop() {
+        let five = OmahaFive::try_from("9♠ 8♥ 2♦ 2♣ 3♠").unwrap();
+        let flop = Flop::try_from("T♦ J♣ K♥").unwrap();
+
+        // Any queen, or any seven, for a straight.
+        assert_eq!(8, five.straight_outs_at_flop(&flop).len());
+    }
+
+    #[rstest]
+    #[case("K♠ A♥ A♠ K♥ 2♠", true)]
+    #[case("K♠ A♥ A♠ K♥ 2♦", true)]
+    #[case("K♠ A♥ A♦ K♣ 2♠", false)]
+    #[case("K♠ A♠ Q♠ J♠ 2♥", false)]
+    #[case("K♠ A♠ Q♠ J♥ 2♦", false)]
+    #[case("K♠ A♥ A♦ K♣ 2♣", false)]
+    fn is_double_suited(#[case] index: &'static str, #[case] expected: bool) {
+        assert_eq!(
+            expected,
+            OmahaFive::try_from(index).unwrap().is_double_suited()
+        );
+    }
+
+    #[test]
+    fn try_from__errors() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            OmahaFive::try_from("A♠ K♥ K♦ Q♣").unwrap_err()
+        );
+        assert_eq!(
+            HandError::TooManyCards,
+            OmahaFive::try_from("A♠ K♥ K♦ Q♣ 2♥ 3♥").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::types::arrays::{Omaha, Vectorable};
+use crate::types::playing_card::PlayingCard;
+use crate::types::poker_cards::PokerCards;
+use crate::types::U32Card;
+use ckc_rs::{HandError, PokerCard};
+use std::fmt;
+
+/// Six card Omaha starting hand, for PLO6, where exactly two of the six cards play, which is
+/// fifteen different pairs.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmahaSix(pub [U32Card; 6]);
+
+impl OmahaSix {
+    #[must_use]
+    pub fn is_double_suited(&self) -> bool {
+        self.suited_count() == 2
+    }
+
+    #[must_use]
+    pub fn to_arr(&self) -> [U32Card; 6] {
+        self.0
+    }
+}
+
+impl fmt::Display for OmahaSix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_poker_cards())
+    }
+}
+
+impl From<[PlayingCard; 6]> for OmahaSix {
+    fn from(array: [PlayingCard; 6]) -> Self {
+        OmahaSix(array.map(|card| card.as_u32()))
+    }
+}
+
+impl From<[U32Card; 6]> for OmahaSix {
+    fn from(value: [U32Card; 6]) -> Self {
+        OmahaSix(value)
+    }
+}
+
+impl TryFrom<&PokerCards> for OmahaSix {
+    type Error = HandError;
+
+    fn try_from(value: &PokerCards) -> Result<Self, Self::Error> {
+        OmahaSix::try_from(value.to_vec())
+    }
+}
+
+impl TryFrom<&str> for OmahaSix {
+    type Error = HandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        OmahaSix::try_from(&PokerCards::try_from(value)?)
+    }
+}
+
+impl TryFrom<Vec<U32Card>> for OmahaSix {
+    type Error = HandError;
+
+    fn try_from(value: Vec<U32Card>) -> Result<Self, Self::Error> {
+        match value.len() {
+            0..=5 => Err(HandError::NotEnoughCards),
+            6 => Ok(OmahaSix(
+                value.try_into().map_err(|_| HandError::InvalidCardCount)?,
+            )),
+            _ => Err(HandError::TooManyCards),
+        }
+    }
+}
+
+impl Omaha for OmahaSix {}
+
+impl Vectorable for OmahaSix {
+    fn to_vec(&self) -> Vec<U32Card> {
+        self.0.to_vec()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_arrays_omaha_six_tests {
+    use super::*;
+    use crate::types::arrays::five_card::FiveCard;
+    use crate::types::slots::flop::Flop;
+    use ckc_rs::hand_rank::HandRankClass;
+    use rstest::rstest;
+
+    #[test]
+    fn pairs() {
+        let six = OmahaSix::try_from("A♠ K♠ Q♠ J♠ T♠ 9♠").unwrap();
+
+        let pairs = six.pairs();
+
+        assert_eq!(15, pairs.len());
+        assert_eq!("A♠ K♠", pairs[0].to_string());
+        assert_eq!("T♠ 9♠", pairs[14].to_string());
+    }
+
+    #[test]
+    fn best_at_flop() {
+        let six = OmahaSix::try_from("2♣ 3♦ 7♥ 8♦ A♠ K♠").unwrap();
+        let flop = Flop::try_from("T♠ J♠ Q♠").unwrap();
+
+        assert_eq!(
+            HandRankClass::RoyalFlush,
+            six.best_at_flop(&flop).rank.class
+        );
+    }
+
+    #[test]
+    fn best_from_board() {
+        let six = OmahaSix::try_from("A♠ 7♦ 7♣ 2♣ 3♦ 4♥").unwrap();
+        let board = FiveCard::try_from("K♠ Q♠ J♠ T♠ 9♠").unwrap();
+
+        // A straight flush on the board, but with only the one spade in the hand, and nothing
+        // else to go with it, the pocket sevens are the best that can be done.
+        let eval = six.best_from_board(&board.to_arr());
+
+        assert_eq!(HandRankClass::PairOfSevens, eval.rank.class);
+    }
+
+    #[test]
+    fn best_low_from_board() {
+        let six = OmahaSix::try_from("A♠ 2♥ K♦ K♣ Q♥ Q♦").unwrap();
+        let board = FiveCard::try_from("3♠ 4♥ 8♦ Q♣ J♠").unwrap();
+
+        assert_eq!(
+            "8-4-3-2-A",
+            six.best_low_from_board(&board.to_arr()).rank.to_string()
+        );
+    }
+
+    #[test]
+    fn try_from__errors() {
+        assert_eq!(
+            HandError::NotEnoughCards,
+            OmahaSix::try_from("A♠ K♥ K♦ Q♣ 2♥").unwrap_err()
+        );
+        assert_eq!(
+            HandError::TooManyCards,
+            OmahaSix::try_from("A♠ K♥ K♦ Q♣ 2♥ 3♥ 4♥").unwrap_err()
+        );
+    }
+
+    #[rstest]
+    #[case("K♠ A♥ A♠ K♥ 2♠ 3♥", true)]
+    #[case("K♠ A♥ A♠ K♥ 2♦ 3♣", true)]
+    #[case("K♠ A♠ Q♠ J♥ 2♦ 3♣", false)]
+    #[case("K♠ A♠ Q♠ J♠ 2♠ 3♥", false)]
+    #[case("K♠ A♥ Q♦ J♣ 2♠ 3♥", true)]
+    #[case("K♠ A♠ Q♥ J♥ 2♦ 3♦", false)]
+    fn is_double_suited(#[case] index: &'static str, #[case] expected: bool) {
+        assert_eq!(
+            expected,
+            OmahaSix::try_from(index).unwrap().is_double_suited()
+        );
+    }
+}
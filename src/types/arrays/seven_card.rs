@@ -1,5 +1,8 @@
 use crate::analysis::eval::Eval;
 use crate::analysis::evals::Evals;
+use crate::analysis::lookup_7card::LOOKUP_7CARD;
+use crate::analysis::low::LowEval;
+use crate::analysis::Evaluate;
 use crate::types::arrays::five_card::FiveCard;
 use crate::types::arrays::two_card::TwoCard;
 use crate::types::arrays::{Evaluable, Vectorable};
@@ -8,6 +11,7 @@ use crate::types::playing_cards::PlayingCards;
 use crate::types::poker_cards::PokerCards;
 use crate::types::U32Card;
 use ckc_rs::cards::seven::Seven;
+use ckc_rs::hand_rank::HandRank;
 use ckc_rs::{CardNumber, HandError, PokerCard};
 use core::fmt;
 use serde::{Deserialize, Serialize};
@@ -69,6 +73,20 @@ impl SevenCard {
     }
     //endregion
 
+    /// Returns the best ace-to-five low out of the seven cards, as played in Razz, where
+    /// pairs count against the hand and straights and flushes don't.
+    #[must_use]
+    pub fn low(&self) -> LowEval {
+        LowEval::best(&self.to_arr())
+    }
+
+    /// Returns the best eight or better low out of the seven cards, as played in Stud8, or no
+    /// low if the hand doesn't qualify.
+    #[must_use]
+    pub fn eight_or_better(&self) -> LowEval {
+        self.low().eight_or_better()
+    }
+
     #[must_use]
     pub fn sort(&self) -> SevenCard {
         let mut c = *self;
@@ -99,6 +117,28 @@ impl fmt::Display for SevenCard {
 }
 
 impl Evaluable for SevenCard {
+    /// Looks up the best `HandRank` in `LOOKUP_7CARD`, and returns it along with the first
+    /// five card combination that makes it, which is the same hand that a full pass through
+    /// `evals()` would return.
+    fn evaluate(&self) -> (FiveCard, HandRank) {
+        let Some(rank) = LOOKUP_7CARD.hand_rank(&self.0) else {
+            let best = self.evals().best();
+            return (best.hand, best.rank);
+        };
+        let mut subhand: [U32Card; 5] = [CardNumber::BLANK; 5];
+
+        for ids in &ckc_rs::cards::seven::Seven::FIVE_CARD_PERMUTATIONS {
+            for i in 0..5 {
+                subhand[i] = self.0[ids[i] as usize];
+            }
+            let hand = FiveCard::from(subhand);
+            if Evaluate::five_cards(hand) == rank {
+                return (hand, rank);
+            }
+        }
+        (FiveCard::default(), rank)
+    }
+
     fn evals(&self) -> Evals {
         let mut evals = Evals::default();
         let mut subhand: [U32Card; 5] = [CardNumber::BLANK; 5];
@@ -112,6 +152,12 @@ impl Evaluable for SevenCard {
         }
         evals
     }
+
+    fn hand_rank(&self) -> HandRank {
+        LOOKUP_7CARD
+            .hand_rank(&self.0)
+            .unwrap_or_else(|| self.evals().best().rank)
+    }
 }
 
 impl From<[PlayingCard; 7]> for SevenCard {
@@ -162,7 +208,7 @@ impl TryFrom<&PokerCards> for SevenCard {
     }
 }
 
-impl TryFrom<&'static str> for SevenCard {
+impl TryFrom<&str> for SevenCard {
     type Error = HandError;
 
     /// Returns a valid `CactusKevHand` if the entered index string splits out into exactly
@@ -224,7 +270,7 @@ impl TryFrom<&'static str> for SevenCard {
     ///
     /// Shouldn't be able to panic. (fingers crossed)
     ///
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match PokerCards::try_from(value) {
             Ok(cards) => SevenCard::try_from(&cards),
             Err(e) => Err(e),
@@ -254,6 +300,24 @@ mod types_arrays_seven_card_tests {
     use ckc_rs::hand_rank::HandRank;
     use ckc_rs::CardNumber;
 
+    #[test]
+    fn low() {
+        let seven = SevenCard::try_from("K♠ 2♥ 7♦ 2♣ A♠ 4♦ 4♣").unwrap();
+        let wheel = SevenCard::try_from("A♠ 2♠ 3♠ 4♠ 5♠ K♦ K♣").unwrap();
+
+        assert_eq!("K-7-4-2-A", seven.low().rank.to_string());
+        assert_eq!("5-4-3-2-A", wheel.low().rank.to_string());
+    }
+
+    #[test]
+    fn eight_or_better() {
+        let seven = SevenCard::try_from("K♠ 2♥ 7♦ 2♣ A♠ 4♦ 4♣").unwrap();
+        let eight = SevenCard::try_from("K♠ 2♥ 7♦ 8♣ A♠ 4♦ 4♣").unwrap();
+
+        assert!(seven.eight_or_better().is_none());
+        assert_eq!("8-7-4-2-A", eight.eight_or_better().rank.to_string());
+    }
+
     #[test]
     fn sort() {
         let seven = SevenCard::try_from("9H AS KS QS JS TS 2H").unwrap().sort();
@@ -277,6 +341,23 @@ mod types_arrays_seven_card_tests {
         assert_eq!("9♥ A♠ K♠ Q♠ J♠ T♠ 2♥", playing_cards.to_string());
     }
 
+    #[test]
+    fn evaluate() {
+        let seven = SevenCard::try_from("A♠ A♥ K♦ K♣ Q♠ Q♥ 2♦").unwrap();
+        let best = seven.evals().best();
+
+        assert_eq!((best.hand, best.rank), seven.evaluate());
+        assert_eq!(best, seven.eval());
+    }
+
+    #[test]
+    fn hand_rank() {
+        let seven = SevenCard::try_from("9H AS KS QS JS TS 2H").unwrap();
+
+        assert_eq!(HandRank::from(1), seven.hand_rank());
+        assert_eq!(HandRank::default(), SevenCard::default().hand_rank());
+    }
+
     #[test]
     fn from__array() {
         let expected = SevenCard([
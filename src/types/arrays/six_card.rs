@@ -131,7 +131,7 @@ impl TryFrom<&PokerCards> for SixCard {
     }
 }
 
-impl TryFrom<&'static str> for SixCard {
+impl TryFrom<&str> for SixCard {
     type Error = HandError;
 
     /// Returns a valid `CactusKevHand` if the entered index string splits out into exactly
@@ -193,7 +193,7 @@ impl TryFrom<&'static str> for SixCard {
     ///
     /// Shouldn't be able to panic. (fingers crossed)
     ///
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match PokerCards::try_from(value) {
             Ok(cards) => SixCard::try_from(&cards),
             Err(e) => Err(e),
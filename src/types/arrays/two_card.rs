@@ -3,6 +3,7 @@ use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::ranges::two_cards_set::TwoCardsSet;
 use crate::types::slots::hole_cards::HoleCards;
+use crate::types::suit_permutation::{SuitIsomorphic, SuitPermutation};
 use crate::types::U32Card;
 use ckc_rs::cards::two::Two;
 use ckc_rs::{CardNumber, HandError, PokerCard};
@@ -48,7 +49,7 @@ impl TwoCard {
     ///
     /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
     /// doesn't equal 2. (There must be two cards for each `Player`.)
-    pub fn from_index(index: &'static str) -> Result<TwoCard, HandError> {
+    pub fn from_index(index: &str) -> Result<TwoCard, HandError> {
         TwoCard::try_from(index)
     }
 
@@ -78,6 +79,31 @@ impl TwoCard {
         self.suit_count() == 1
     }
 
+    /// Returns the hand's class in range shorthand, such as `AA`, `AKs`, or `AKo`.
+    #[must_use]
+    pub fn shorthand(&self) -> String {
+        let suffix = if self.is_pocket_pair() {
+            ""
+        } else if self.is_suited() {
+            "s"
+        } else {
+            "o"
+        };
+        format!(
+            "{}{}{}",
+            self.first().get_rank_char(),
+            self.second().get_rank_char(),
+            suffix
+        )
+    }
+
+    /// Returns one of each of the 169 starting hand classes, such as `AKs`, from `AA` on down,
+    /// along with how many of the 1,326 possible hole cards are in it.
+    #[must_use]
+    pub fn starting_hand_classes() -> Vec<(TwoCard, usize)> {
+        TwoCard::classes(TwoCardsSet::every().iter().copied())
+    }
+
     #[must_use]
     pub fn iter(&self) -> Iter<'_, U32Card> {
         self.0.iter()
@@ -117,6 +143,20 @@ impl TwoCard {
     //endregion
 }
 
+impl SuitIsomorphic for TwoCard {
+    fn permute(&self, permutation: &SuitPermutation) -> Self {
+        TwoCard::new(
+            permutation.apply(PlayingCard::from(self.first())).as_u32(),
+            permutation.apply(PlayingCard::from(self.second())).as_u32(),
+        )
+        .unwrap_or(*self)
+    }
+
+    fn suit_key(&self) -> Vec<U32Card> {
+        self.0.to_vec()
+    }
+}
+
 impl Vectorable for TwoCard {
     #[must_use]
     fn to_vec(&self) -> Vec<U32Card> {
@@ -198,14 +238,14 @@ impl TryFrom<HoleCards> for TwoCard {
     }
 }
 
-impl TryFrom<&'static str> for TwoCard {
+impl TryFrom<&str> for TwoCard {
     type Error = HandError;
 
     /// # Errors
     ///
     /// Will return `CardError::InvalidCard` for an invalid index.
     #[allow(clippy::missing_panics_doc)]
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match PlayingCards::try_from(value) {
             Ok(cards) => {
                 if cards.len() == 2 {
@@ -226,6 +266,7 @@ impl TryFrom<&'static str> for TwoCard {
 #[allow(non_snake_case)]
 mod arrays_two_card_tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn new() {
@@ -266,6 +307,14 @@ mod arrays_two_card_tests {
             .is_suited());
     }
 
+    #[rstest]
+    #[case("A♠ A♥", "AA")]
+    #[case("K♦ A♦", "AKs")]
+    #[case("2♣ 7♠", "72o")]
+    fn shorthand(#[case] index: &'static str, #[case] expected: &str) {
+        assert_eq!(expected, TwoCard::try_from(index).unwrap().shorthand());
+    }
+
     #[test]
     fn every_other() {
         let hand = TwoCard::new(CardNumber::ACE_CLUBS, CardNumber::ACE_SPADES).unwrap();
@@ -275,6 +324,42 @@ mod arrays_two_card_tests {
         assert!(!every_other.contains(&hand));
     }
 
+    #[test]
+    fn starting_hand_classes() {
+        let classes = TwoCard::starting_hand_classes();
+
+        assert_eq!(169, classes.len());
+        assert_eq!(1326, classes.iter().map(|(_, count)| count).sum::<usize>());
+        assert_eq!("A♠ A♥", classes[0].0.to_string());
+        assert_eq!(
+            ("AKs".to_string(), 4),
+            (classes[1].0.shorthand(), classes[1].1)
+        );
+        assert_eq!(
+            ("AKo".to_string(), 12),
+            (classes[2].0.shorthand(), classes[2].1)
+        );
+        assert!(classes
+            .iter()
+            .all(|(two, count)| two.is_canonical() && two.multiplicity() == *count));
+    }
+
+    #[rstest]
+    #[case("7♦ 7♣", "7♠ 7♥", 6)]
+    #[case("A♦ K♦", "A♠ K♠", 4)]
+    #[case("A♣ K♦", "A♠ K♥", 12)]
+    #[case("9♥ 8♣", "9♠ 8♥", 12)]
+    fn suit_isomorphic(#[case] index: &str, #[case] canonical: &str, #[case] multiplicity: usize) {
+        let two = TwoCard::try_from(index).unwrap();
+
+        assert_eq!(canonical, two.to_canonical().to_string());
+        assert_eq!(multiplicity, two.multiplicity());
+        assert_eq!(
+            two.to_canonical(),
+            two.permute(&two.canonical_permutation())
+        );
+    }
+
     #[test]
     fn display() {
         let two = TwoCard::default();
@@ -9,7 +9,7 @@ use log::warn;
 pub trait CardSlot {
     fn take(&self, card: PlayingCard) -> bool;
 
-    fn take_from_index(&self, index: &'static str) -> bool {
+    fn take_from_index(&self, index: &str) -> bool {
         if let Ok(cards) = PlayingCards::try_from(index) {
             let mut is_ok = false;
             for card in cards.iter() {
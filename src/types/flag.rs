@@ -0,0 +1,90 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A thread safe stand in for a `Cell<bool>`, such as whether a seat has folded, that can be
+/// flipped through a shared reference while leaving whatever holds it `Sync`.
+#[derive(Default)]
+pub struct Flag(AtomicBool);
+
+impl Flag {
+    #[must_use]
+    pub fn new(value: bool) -> Flag {
+        Flag(AtomicBool::new(value))
+    }
+
+    #[must_use]
+    pub fn get(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    pub fn set(&self, value: bool) {
+        self.0.store(value, Ordering::SeqCst);
+    }
+}
+
+impl Clone for Flag {
+    fn clone(&self) -> Self {
+        Flag::new(self.get())
+    }
+}
+
+impl fmt::Debug for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Flag").field(&self.get()).finish()
+    }
+}
+
+impl Eq for Flag {}
+
+impl PartialEq for Flag {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Serialize for Flag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Flag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Flag::new(bool::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_flag_tests {
+    use super::*;
+
+    #[test]
+    fn set() {
+        let flag = Flag::default();
+        assert!(!flag.get());
+
+        flag.set(true);
+
+        assert!(flag.get());
+        assert_eq!(Flag::new(true), flag.clone());
+    }
+
+    #[test]
+    fn serde() {
+        let json = serde_json::to_string(&Flag::new(true)).unwrap();
+
+        assert_eq!("true", json);
+        assert_eq!(
+            Flag::new(true),
+            serde_json::from_str::<Flag>(&json).unwrap()
+        );
+    }
+}
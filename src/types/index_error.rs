@@ -0,0 +1,81 @@
+use ckc_rs::HandError;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when parsing a card index string, such as `"A♠ K♠ Q♦"`, with `FromStr`.
+///
+/// Unlike a bare `HandError`, an invalid card names the offending token along with its
+/// zero based position in the index, so that it can be reported back to whoever typed it in.
+#[derive(Debug, PartialEq)]
+pub enum IndexError {
+    InvalidCard { token: String, position: usize },
+    Hand(HandError),
+}
+
+impl IndexError {
+    #[must_use]
+    pub fn invalid_card(token: &str, position: usize) -> IndexError {
+        IndexError::InvalidCard {
+            token: token.to_string(),
+            position,
+        }
+    }
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::InvalidCard { token, position } => {
+                write!(f, "invalid card '{token}' at position {position}")
+            }
+            IndexError::Hand(e) => write!(f, "{e:?}"),
+        }
+    }
+}
+
+impl Error for IndexError {}
+
+impl From<HandError> for IndexError {
+    fn from(e: HandError) -> Self {
+        IndexError::Hand(e)
+    }
+}
+
+impl From<IndexError> for HandError {
+    fn from(e: IndexError) -> Self {
+        match e {
+            IndexError::InvalidCard { .. } => HandError::InvalidCard,
+            IndexError::Hand(e) => e,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_index_error_tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "invalid card 'XX' at position 2",
+            IndexError::invalid_card("XX", 2).to_string()
+        );
+        assert_eq!(
+            "NotEnoughCards",
+            IndexError::from(HandError::NotEnoughCards).to_string()
+        );
+    }
+
+    #[test]
+    fn into_hand_error() {
+        assert_eq!(
+            HandError::InvalidCard,
+            HandError::from(IndexError::invalid_card("XX", 2))
+        );
+        assert_eq!(
+            HandError::InvalidIndex,
+            HandError::from(IndexError::Hand(HandError::InvalidIndex))
+        );
+    }
+}
@@ -1,13 +1,17 @@
 pub mod arrays;
 pub mod card_slot;
+pub mod flag;
 pub mod hands;
+pub mod index_error;
 pub mod playing_card;
 pub mod playing_cards;
 pub mod poker_cards;
 pub mod poker_deck;
 pub mod ranges;
 pub mod sample;
+pub mod short_deck;
 pub mod slots;
+pub mod suit_permutation;
 
 //\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\//\\
 // Base Entities
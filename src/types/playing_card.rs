@@ -1,9 +1,11 @@
+use crate::types::index_error::IndexError;
 use crate::types::U32Card;
 use ckc_rs::{CKCNumber, CardNumber, PokerCard};
 use serde::de::Deserializer;
 use serde::ser::{Serialize, Serializer};
 use serde::Deserialize;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Deserialize, Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct PlayingCard(#[serde(deserialize_with = "deserialize_card_index")] U32Card);
@@ -119,12 +121,29 @@ impl From<&'static u32> for PlayingCard {
     }
 }
 
-impl From<&'static str> for PlayingCard {
-    fn from(value: &'static str) -> PlayingCard {
+impl From<&str> for PlayingCard {
+    fn from(value: &str) -> PlayingCard {
         PlayingCard(CKCNumber::from_index(value))
     }
 }
 
+impl FromStr for PlayingCard {
+    type Err = IndexError;
+
+    /// # Errors
+    ///
+    /// Will return `IndexError::InvalidCard` if the index isn't a valid card.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = s.trim();
+        let card = PlayingCard::from(index);
+        if card.is_blank() {
+            Err(IndexError::invalid_card(index, 0))
+        } else {
+            Ok(card)
+        }
+    }
+}
+
 /// Sieve to ensure that only valid binary card representations are passed in.
 ///
 /// Invalid `CardNumbers` will return a `Blank` `PlayingCard`.
@@ -223,6 +242,23 @@ mod holdem_playing_card_tests {
         assert_eq!("__", playing_card.to_string());
     }
 
+    #[test]
+    fn from_str() {
+        assert_eq!(PlayingCard::QUEEN_HEARTS, "QH".parse().unwrap());
+        assert_eq!(
+            PlayingCard::QUEEN_HEARTS,
+            PlayingCard::from_str(" Q♥ ").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        assert_eq!(
+            IndexError::invalid_card("JK", 0),
+            PlayingCard::from_str("JK").unwrap_err()
+        );
+    }
+
     #[test]
     fn display() {
         let card = cardpack::Standard52::card_from_index("QH");
@@ -3,21 +3,25 @@ use crate::types::arrays::five_card::FiveCard;
 use crate::types::arrays::seven_card::SevenCard;
 use crate::types::arrays::two_card::TwoCard;
 use crate::types::arrays::{Evaluable, Vectorable};
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::poker_cards::PokerCards;
 use crate::types::poker_deck::PokerDeck;
+use crate::types::short_deck::ShortDeck;
 use crate::types::U32Card;
-use crate::util::random_ordering::RandomOrdering;
 use cardpack::Pile;
 use ckc_rs::{HandError, PokerCard};
 use core::fmt;
 use indexmap::set::Iter;
 use indexmap::IndexSet;
 use itertools::{Combinations, Itertools};
+use rand::seq::SliceRandom;
 use rayon::prelude::*;
+use serde::de::Deserializer;
+use serde::ser::{Serialize, Serializer};
+use serde::Deserialize;
 use std::fmt::Formatter;
-
-const NUMBER_OF_SHUFFLES: u8 = 5;
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct PlayingCards(IndexSet<PlayingCard>);
@@ -51,6 +55,30 @@ impl PlayingCards {
         deck
     }
 
+    /// Returns the 36 cards in a `ShortDeck`.
+    #[must_use]
+    pub fn short_deck() -> PlayingCards {
+        PlayingCards(ShortDeck::iter().map(PlayingCard::from).collect())
+    }
+
+    /// Returns all the cards in a `ShortDeck` minus the ones passed in.
+    #[must_use]
+    pub fn short_deck_minus(playing_cards: &PlayingCards) -> PlayingCards {
+        PlayingCards(
+            ShortDeck::iter()
+                .map(PlayingCard::from)
+                .filter(|card| !playing_cards.contains(card))
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn short_deck_shuffled() -> PlayingCards {
+        let mut deck = PlayingCards::short_deck();
+        deck.shuffle_in_place();
+        deck
+    }
+
     pub fn append(&mut self, playing_cards: &PlayingCards) {
         for card in playing_cards.iter() {
             self.insert(*card);
@@ -155,6 +183,17 @@ impl PlayingCards {
         self.0.len()
     }
 
+    /// Returns the cards that aren't in the `PlayingCards` passed in.
+    #[must_use]
+    pub fn minus(&self, playing_cards: &PlayingCards) -> PlayingCards {
+        PlayingCards(
+            self.iter()
+                .filter(|card| !playing_cards.contains(card))
+                .copied()
+                .collect(),
+        )
+    }
+
     #[must_use]
     pub fn peak(&self) -> Option<&PlayingCard> {
         self.0.first()
@@ -171,11 +210,12 @@ impl PlayingCards {
         shuffled
     }
 
+    /// Sorting with a random comparator isn't a total order, which newer versions of
+    /// the standard library's sort will panic on, so the cards are shuffled as a `Vec`.
     pub fn shuffle_in_place(&mut self) {
-        for _ in 0..NUMBER_OF_SHUFFLES {
-            self.0
-                .sort_by(|_, _| rand::random::<RandomOrdering>().into());
-        }
+        let mut cards = self.to_vec();
+        cards.shuffle(&mut rand::thread_rng());
+        self.0 = cards.into_iter().collect();
     }
 
     #[must_use]
@@ -321,20 +361,19 @@ impl From<Vec<PlayingCard>> for PlayingCards {
     }
 }
 
-impl TryFrom<&'static str> for PlayingCards {
-    type Error = HandError;
+impl FromStr for PlayingCards {
+    type Err = IndexError;
 
     /// # Errors
     ///
-    /// Will return `CardError::InvalidCard` for an invalid index.
-    #[allow(clippy::missing_panics_doc)]
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    /// Will return `IndexError::InvalidCard` naming the first token that isn't a valid card.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut cards = PlayingCards::default();
 
-        for s in value.split_whitespace() {
-            let card = PlayingCard::from(s);
+        for (position, token) in s.split_whitespace().enumerate() {
+            let card = PlayingCard::from(token);
             if card.is_blank() {
-                return Err(HandError::InvalidCard);
+                return Err(IndexError::invalid_card(token, position));
             }
             cards.insert(card);
         }
@@ -342,6 +381,48 @@ impl TryFrom<&'static str> for PlayingCards {
     }
 }
 
+/// Serialized as the index string of the cards, the same way as each `PlayingCard` is.
+impl Serialize for PlayingCards {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayingCards {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let index = String::deserialize(deserializer)?;
+        PlayingCards::from_str(&index).map_err(serde::de::Error::custom)
+    }
+}
+
+impl TryFrom<&str> for PlayingCards {
+    type Error = HandError;
+
+    /// # Errors
+    ///
+    /// Will return `CardError::InvalidCard` for an invalid index.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(PlayingCards::from_str(value)?)
+    }
+}
+
+impl TryFrom<String> for PlayingCards {
+    type Error = HandError;
+
+    /// # Errors
+    ///
+    /// Will return `CardError::InvalidCard` for an invalid index.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PlayingCards::try_from(value.as_str())
+    }
+}
+
 impl TryFrom<TwoCard> for PlayingCards {
     type Error = HandError;
 
@@ -361,6 +442,7 @@ impl TryFrom<TwoCard> for PlayingCards {
 #[allow(non_snake_case)]
 mod playing_cards_tests {
     use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
 
     fn royal_flush() -> PlayingCards {
         PlayingCards::deck().draw(5)
@@ -509,6 +591,13 @@ mod playing_cards_tests {
         assert_eq!(5, royal_flush().len())
     }
 
+    #[test]
+    fn minus() {
+        let cards = royal_flush().minus(&PlayingCards::try_from("K♠ J♠ 2♣").unwrap());
+
+        assert_eq!("A♠ Q♠ T♠", cards.to_string());
+    }
+
     #[test]
     fn reverse() {
         let mut cards = royal_flush();
@@ -528,6 +617,16 @@ mod playing_cards_tests {
         assert_eq!("A♠ K♠ Q♠ J♠ T♠", cards.to_string());
     }
 
+    #[test]
+    fn short_deck_minus() {
+        let mut remaining = PlayingCards::short_deck_minus(&royal_flush());
+
+        assert_eq!(36, PlayingCards::short_deck().len());
+        assert_eq!(31, remaining.len());
+        assert!(remaining.is_disjoint(&royal_flush()));
+        assert_eq!("9♠ 8♠ 7♠ 6♠ A♥", remaining.draw(5).to_string());
+    }
+
     #[test]
     fn to_vec() {
         let v = vec![
@@ -565,6 +664,31 @@ mod playing_cards_tests {
         assert_eq!(royal_flush(), actual);
     }
 
+    #[test]
+    fn try_from__string() {
+        let index = String::from("A♠ K♠ Q♠ J♠ T♠");
+
+        assert_eq!(
+            royal_flush(),
+            PlayingCards::try_from(index.as_str()).unwrap()
+        );
+        assert_eq!(royal_flush(), PlayingCards::try_from(index).unwrap());
+    }
+
+    #[test]
+    fn from_str() {
+        let actual: PlayingCards = "A♠ K♠ Q♠ J♠ T♠".parse().unwrap();
+
+        assert_eq!(royal_flush(), actual);
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        let actual = PlayingCards::from_str("A♠ K♠ QX J♠ T♠");
+
+        assert_eq!(IndexError::invalid_card("QX", 2), actual.unwrap_err());
+    }
+
     #[test]
     fn try_from__two_cards() {
         let actual = PlayingCards::try_from(TwoCard::try_from("Q♠ J♠").unwrap()).unwrap();
@@ -578,4 +702,17 @@ mod playing_cards_tests {
 
         assert!(actual.is_err());
     }
+
+    #[test]
+    fn serialize() {
+        assert_tokens(
+            &PlayingCards::from_str("A♠ K♦").unwrap(),
+            &[Token::Str("A♠ K♦")],
+        );
+        assert_tokens(&PlayingCards::default(), &[Token::Str("")]);
+        assert_de_tokens_error::<PlayingCards>(
+            &[Token::Str("A♠ XX")],
+            &IndexError::invalid_card("XX", 1).to_string(),
+        );
+    }
 }
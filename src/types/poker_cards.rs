@@ -1,4 +1,5 @@
 use crate::types::arrays::Vectorable;
+use crate::types::index_error::IndexError;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::poker_deck::PokerDeck;
@@ -9,6 +10,7 @@ use ckc_rs::{CKCNumber, CardNumber, CardRank, HandError, PokerCard};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use std::fmt;
+use std::str::FromStr;
 
 pub const POSSIBLE_COMBINATIONS: usize = 7937;
 
@@ -24,36 +26,16 @@ impl PokerCards {
     /// # Errors
     ///
     /// Will return `CardError::InvalidCard` for an invalid index.
-    #[must_use]
-    pub fn from_index(index: &'static str) -> Result<PokerCards, HandError> {
-        let mut cards = PokerCards::default();
-
-        for s in index.split_whitespace() {
-            let card = CKCNumber::from_index(s);
-            if card.is_blank() {
-                return Err(HandError::InvalidCard);
-            }
-            cards.push(card);
-        }
-        Ok(cards)
+    pub fn from_index(index: &str) -> Result<PokerCards, HandError> {
+        Ok(PokerCards::from_str(index)?)
     }
 
     /// # Errors
     ///
     /// Will return `CardError::InvalidCard` for an invalid index.
-    #[must_use]
     #[allow(clippy::needless_pass_by_value)]
     pub fn from_index_string(index: String) -> Result<PokerCards, HandError> {
-        let mut cards = PokerCards::default();
-
-        for s in index.split_whitespace() {
-            let card = CKCNumber::from_index(s);
-            if card.is_blank() {
-                return Err(HandError::InvalidCard);
-            }
-            cards.push(card);
-        }
-        Ok(cards)
+        PokerCards::from_index(&index)
     }
 
     /// Appends a clone of the passed in collection of `PokerCards` to the existing one.
@@ -285,14 +267,34 @@ impl From<Vec<U32Card>> for PokerCards {
     }
 }
 
-impl TryFrom<&'static str> for PokerCards {
+impl FromStr for PokerCards {
+    type Err = IndexError;
+
+    /// # Errors
+    ///
+    /// Will return `IndexError::InvalidCard` naming the first token that isn't a valid card.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cards = PokerCards::default();
+
+        for (position, token) in s.split_whitespace().enumerate() {
+            let card = CKCNumber::from_index(token);
+            if card.is_blank() {
+                return Err(IndexError::invalid_card(token, position));
+            }
+            cards.push(card);
+        }
+        Ok(cards)
+    }
+}
+
+impl TryFrom<&str> for PokerCards {
     type Error = HandError;
 
     /// # Errors
     ///
     /// Will return `CardError::InvalidCard` for an invalid index.
     #[allow(clippy::missing_panics_doc)]
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         PokerCards::from_index(value)
     }
 }
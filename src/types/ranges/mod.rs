@@ -1,3 +1,4 @@
 pub mod chen_weighted;
 pub mod two_cards;
 pub mod two_cards_set;
+pub mod weighted_two_cards_set;
@@ -1,10 +1,12 @@
 use crate::types::arrays::two_card::TwoCard;
 use crate::types::playing_cards::PlayingCards;
 use crate::types::ranges::two_cards::TwoCards;
-use ckc_rs::PokerCard;
+use crate::types::ranges::weighted_two_cards_set::WeightedTwoCardsSet;
+use ckc_rs::{HandError, PokerCard};
 use serde::{Deserialize, Serialize};
 use std::collections::hash_set::Iter;
 use std::collections::HashSet;
+use std::fmt;
 
 /// * [Texas hold 'em starting hands](https://en.wikipedia.org/wiki/Texas_hold_%27em_starting_hands)
 /// * [Starting Hands](https://betandbeat.com/poker/strategy/preflop/starting-hands/)
@@ -23,6 +25,17 @@ impl TwoCardsSet {
         TwoCardsSet::from(PlayingCards::deck())
     }
 
+    /// Parses a range written in the standard shorthand, such as `TT+, AKs, A5s-A2s, KQo`.
+    /// See `WeightedTwoCardsSet` for the full notation. Hands with a weight of zero are
+    /// left out.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` error if the notation can't be parsed.
+    pub fn from_notation(notation: &str) -> Result<TwoCardsSet, HandError> {
+        Ok(WeightedTwoCardsSet::from_notation(notation)?.to_two_cards_set())
+    }
+
     #[must_use]
     pub fn contains(&self, cards: &TwoCard) -> bool {
         self.hands.contains(cards)
@@ -117,12 +130,33 @@ impl TwoCardsSet {
         TwoCards::from(self.clone())
     }
 
+    /// Returns the range in its most compressed shorthand, such as `TT+, AKs, A5s-A2s, KQo`.
+    #[must_use]
+    pub fn to_notation(&self) -> String {
+        WeightedTwoCardsSet::from(self).to_notation()
+    }
+
     #[must_use]
     pub fn to_vec(&self) -> Vec<TwoCard> {
         self.hands.clone().into_iter().collect()
     }
 }
 
+impl fmt::Display for TwoCardsSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_notation())
+    }
+}
+
+impl<'a> IntoIterator for &'a TwoCardsSet {
+    type Item = &'a TwoCard;
+    type IntoIter = Iter<'a, TwoCard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.hands.iter()
+    }
+}
+
 impl From<HashSet<TwoCard>> for TwoCardsSet {
     fn from(hands: HashSet<TwoCard>) -> Self {
         TwoCardsSet { hands }
@@ -138,7 +172,7 @@ impl From<PlayingCards> for TwoCardsSet {
 
         for v in deck.combinations(2) {
             range.insert(
-                TwoCard::new(v.get(0).unwrap().as_u32(), v.get(1).unwrap().as_u32()).unwrap(),
+                TwoCard::new(v.first().unwrap().as_u32(), v.get(1).unwrap().as_u32()).unwrap(),
             );
         }
         range
@@ -277,4 +311,46 @@ mod types_ranges_two_cards_set_tests {
 
         assert!(range.is_empty());
     }
+
+    #[test]
+    fn from_notation() {
+        let range = TwoCardsSet::from_notation("TT+, AKs, A5s-A2s, KQo").unwrap();
+
+        assert_eq!(30 + 4 + 16 + 12, range.len());
+        assert!(range.contains(&TwoCard::try_from("AS AC").unwrap()));
+        assert!(range.contains(&TwoCard::try_from("AH 3H").unwrap()));
+        assert!(!range.contains(&TwoCard::try_from("AH 6H").unwrap()));
+        assert!(!range.contains(&TwoCard::try_from("KH QH").unwrap()));
+    }
+
+    #[test]
+    fn from_notation__zero_weight() {
+        let range = TwoCardsSet::from_notation("QQ+, AKs:0").unwrap();
+
+        assert_eq!(18, range.len());
+    }
+
+    #[test]
+    fn from_notation__invalid() {
+        assert_eq!(
+            HandError::InvalidIndex,
+            TwoCardsSet::from_notation("TT+, AXs").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn to_notation() {
+        let range = TwoCardsSet::from_notation("KQo, A5s-A2s, TT+, AKs").unwrap();
+
+        assert_eq!("TT+, AKs, A5s-A2s, KQo", range.to_notation());
+        assert_eq!("TT+, AKs, A5s-A2s, KQo", range.to_string());
+    }
+
+    #[test]
+    fn to_notation__every() {
+        assert_eq!(
+            "22+, A2+, K2+, Q2+, J2+, T2+, 92+, 82+, 72+, 62+, 52+, 42+, 32",
+            TwoCardsSet::every().to_notation()
+        );
+    }
 }
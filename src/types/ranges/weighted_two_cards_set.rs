@@ -0,0 +1,596 @@
+use crate::types::arrays::two_card::TwoCard;
+use crate::types::ranges::two_cards_set::TwoCardsSet;
+use ckc_rs::{CKCNumber, CardRank, CardSuit, HandError, PokerCard};
+use std::collections::hash_map::Iter;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A range of `TwoCard` hands where each hand has a weight between 0 and 1, representing
+/// how often the hand is played.
+///
+/// Ranges can be written in the usual shorthand, with the entries separated by commas:
+///
+/// * `TT` - a pocket pair.
+/// * `TT+` - a pocket pair and every higher pair.
+/// * `TT-77` - every pair between the two.
+/// * `AKs`, `AKo`, `AK` - suited, offsuit, or every combination of two ranks.
+/// * `ATs+` - the lower rank going up to one under the higher rank: `ATs AJs AQs AKs`.
+/// * `A5s-A2s` - the lower rank going between the two.
+/// * `AhKh` - a specific combination of cards.
+/// * `AKs:0.5` - any of the above with a weight. Entries without one have a weight of 1.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightedTwoCardsSet {
+    pub hands: HashMap<TwoCard, f32>,
+}
+
+impl WeightedTwoCardsSet {
+    pub const RANKS: [CardRank; 13] = [
+        CardRank::ACE,
+        CardRank::KING,
+        CardRank::QUEEN,
+        CardRank::JACK,
+        CardRank::TEN,
+        CardRank::NINE,
+        CardRank::EIGHT,
+        CardRank::SEVEN,
+        CardRank::SIX,
+        CardRank::FIVE,
+        CardRank::FOUR,
+        CardRank::THREE,
+        CardRank::TWO,
+    ];
+    pub const SUITS: [CardSuit; 4] = [
+        CardSuit::SPADES,
+        CardSuit::HEARTS,
+        CardSuit::DIAMONDS,
+        CardSuit::CLUBS,
+    ];
+
+    /// Parses a range written in the standard shorthand, such as `TT+, AKs, A5s-A2s, KQo`.
+    /// When a hand is included more than once, the last weight given to it wins.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::InvalidIndex` error if an entry can't be parsed, or
+    /// has a weight that isn't between 0 and 1, and a `HandError::DuplicateCard` error
+    /// if a specific combination has the same card twice.
+    pub fn from_notation(notation: &str) -> Result<WeightedTwoCardsSet, HandError> {
+        let mut range = WeightedTwoCardsSet::default();
+        for entry in notation.split(',').map(str::trim) {
+            if entry.is_empty() {
+                continue;
+            }
+            let (entry, weight) = match entry.split_once(':') {
+                Some((entry, weight)) => {
+                    let weight: f32 = weight.trim().parse().map_err(|_| HandError::InvalidIndex)?;
+                    if !(0.0..=1.0).contains(&weight) {
+                        return Err(HandError::InvalidIndex);
+                    }
+                    (entry.trim(), weight)
+                }
+                None => (entry, 1.0),
+            };
+            for hand in WeightedTwoCardsSet::parse_entry(entry)? {
+                range.insert(hand, weight);
+            }
+        }
+        Ok(range)
+    }
+
+    #[must_use]
+    pub fn contains(&self, cards: &TwoCard) -> bool {
+        self.hands.contains_key(cards)
+    }
+
+    /// Returns the combined weight of the range, which is the number of combinations
+    /// that it would hold if every hand was played at its weight.
+    #[must_use]
+    pub fn combos(&self) -> f32 {
+        self.hands.values().sum()
+    }
+
+    pub fn insert(&mut self, cards: TwoCard, weight: f32) -> Option<f32> {
+        self.hands.insert(cards, weight)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hands.is_empty()
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, TwoCard, f32> {
+        self.hands.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hands.len()
+    }
+
+    #[must_use]
+    pub fn weight(&self, cards: &TwoCard) -> Option<f32> {
+        self.hands.get(cards).copied()
+    }
+
+    /// Returns the range in its most compressed shorthand, grouping the hands by weight
+    /// from the highest to the lowest.
+    #[must_use]
+    pub fn to_notation(&self) -> String {
+        let mut weights: Vec<f32> = Vec::new();
+        for weight in self.hands.values() {
+            if !weights.contains(weight) {
+                weights.push(*weight);
+            }
+        }
+        weights.sort_by(|a, b| b.total_cmp(a));
+
+        let mut entries: Vec<String> = Vec::new();
+        for weight in weights {
+            let hands: Vec<TwoCard> = self
+                .hands
+                .iter()
+                .filter(|(_, w)| w.total_cmp(&weight).is_eq())
+                .map(|(hand, _)| *hand)
+                .collect();
+            for entry in WeightedTwoCardsSet::compress(&TwoCardsSet::from(&hands)) {
+                if (weight - 1.0).abs() < f32::EPSILON {
+                    entries.push(entry);
+                } else {
+                    entries.push(format!("{entry}:{weight}"));
+                }
+            }
+        }
+        entries.join(", ")
+    }
+
+    /// Returns every hand with a weight above zero.
+    #[must_use]
+    pub fn to_two_cards_set(&self) -> TwoCardsSet {
+        TwoCardsSet::from(
+            self.hands
+                .iter()
+                .filter(|(_, weight)| **weight > 0.0)
+                .map(|(hand, _)| *hand)
+                .collect::<Vec<TwoCard>>()
+                .as_ref(),
+        )
+    }
+
+    //region hand classes
+
+    /// The six combinations of a pocket pair.
+    #[must_use]
+    pub fn pair(rank: CardRank) -> Vec<TwoCard> {
+        let mut hands = Vec::new();
+        for (i, first) in WeightedTwoCardsSet::SUITS.iter().enumerate() {
+            for second in WeightedTwoCardsSet::SUITS.iter().skip(i + 1) {
+                hands.push(WeightedTwoCardsSet::two_card(rank, *first, rank, *second));
+            }
+        }
+        hands
+    }
+
+    /// The four suited combinations of two different ranks.
+    #[must_use]
+    pub fn suited(high: CardRank, low: CardRank) -> Vec<TwoCard> {
+        WeightedTwoCardsSet::SUITS
+            .iter()
+            .map(|suit| WeightedTwoCardsSet::two_card(high, *suit, low, *suit))
+            .collect()
+    }
+
+    /// The twelve offsuit combinations of two different ranks.
+    #[must_use]
+    pub fn offsuit(high: CardRank, low: CardRank) -> Vec<TwoCard> {
+        let mut hands = Vec::new();
+        for first in WeightedTwoCardsSet::SUITS {
+            for second in WeightedTwoCardsSet::SUITS {
+                if first != second {
+                    hands.push(WeightedTwoCardsSet::two_card(high, first, low, second));
+                }
+            }
+        }
+        hands
+    }
+
+    fn two_card(
+        first_rank: CardRank,
+        first_suit: CardSuit,
+        second_rank: CardRank,
+        second_suit: CardSuit,
+    ) -> TwoCard {
+        TwoCard::new(
+            CKCNumber::create(first_rank, first_suit),
+            CKCNumber::create(second_rank, second_suit),
+        )
+        .unwrap_or_default()
+    }
+
+    //endregion
+
+    //region parsing
+
+    fn parse_entry(entry: &str) -> Result<Vec<TwoCard>, HandError> {
+        let chars: Vec<char> = entry.chars().collect();
+        if chars.len() == 4
+            && CardSuit::from_char(chars[1]) != CardSuit::BLANK
+            && CardSuit::from_char(chars[3]) != CardSuit::BLANK
+        {
+            let first = CKCNumber::from_index(&chars[0..2].iter().collect::<String>());
+            let second = CKCNumber::from_index(&chars[2..4].iter().collect::<String>());
+            if first.is_blank() || second.is_blank() {
+                return Err(HandError::InvalidIndex);
+            }
+            return Ok(vec![TwoCard::new(first, second)?]);
+        }
+
+        if let Some((top, bottom)) = entry.split_once('-') {
+            let (top_high, top_low, top_kind) = WeightedTwoCardsSet::parse_class(top.trim())?;
+            let (bottom_high, bottom_low, bottom_kind) =
+                WeightedTwoCardsSet::parse_class(bottom.trim())?;
+            if top_kind != bottom_kind {
+                return Err(HandError::InvalidIndex);
+            }
+            if top_kind == Kind::Pair {
+                return Ok(WeightedTwoCardsSet::between(top_high, bottom_high)
+                    .into_iter()
+                    .flat_map(WeightedTwoCardsSet::pair)
+                    .collect());
+            }
+            if top_high as u8 != bottom_high as u8 {
+                return Err(HandError::InvalidIndex);
+            }
+            return Ok(WeightedTwoCardsSet::between(top_low, bottom_low)
+                .into_iter()
+                .flat_map(|low| top_kind.hands(top_high, low))
+                .collect());
+        }
+
+        if let Some(class) = entry.strip_suffix('+') {
+            let (high, low, kind) = WeightedTwoCardsSet::parse_class(class.trim())?;
+            if kind == Kind::Pair {
+                return Ok(WeightedTwoCardsSet::between(CardRank::ACE, high)
+                    .into_iter()
+                    .flat_map(WeightedTwoCardsSet::pair)
+                    .collect());
+            }
+            let top = WeightedTwoCardsSet::RANKS[WeightedTwoCardsSet::position(high) + 1];
+            return Ok(WeightedTwoCardsSet::between(top, low)
+                .into_iter()
+                .flat_map(|low| kind.hands(high, low))
+                .collect());
+        }
+
+        let (high, low, kind) = WeightedTwoCardsSet::parse_class(entry)?;
+        Ok(kind.hands(high, low))
+    }
+
+    /// Parses a hand class such as `TT`, `AKs`, `AKo` or `AK`, returning the higher rank
+    /// first.
+    fn parse_class(class: &str) -> Result<(CardRank, CardRank, Kind), HandError> {
+        let chars: Vec<char> = class.chars().collect();
+        if chars.len() < 2 || chars.len() > 3 {
+            return Err(HandError::InvalidIndex);
+        }
+        let first = CardRank::from_char(chars[0]);
+        let second = CardRank::from_char(chars[1]);
+        if first == CardRank::BLANK || second == CardRank::BLANK {
+            return Err(HandError::InvalidIndex);
+        }
+        let (high, low) = if (first as u8) < (second as u8) {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        let kind = match (chars.get(2), high == low) {
+            (None, true) => Kind::Pair,
+            (None, false) => Kind::Any,
+            (Some('s' | 'S'), false) => Kind::Suited,
+            (Some('o' | 'O'), false) => Kind::Offsuit,
+            _ => return Err(HandError::InvalidIndex),
+        };
+        Ok((high, low, kind))
+    }
+
+    /// Every rank from the first to the second, inclusive, in either direction.
+    fn between(first: CardRank, second: CardRank) -> Vec<CardRank> {
+        let first = WeightedTwoCardsSet::position(first);
+        let second = WeightedTwoCardsSet::position(second);
+        WeightedTwoCardsSet::RANKS[first.min(second)..=first.max(second)].to_vec()
+    }
+
+    fn position(rank: CardRank) -> usize {
+        WeightedTwoCardsSet::RANKS
+            .iter()
+            .position(|r| *r == rank)
+            .unwrap_or_default()
+    }
+
+    //endregion
+
+    //region formatting
+
+    /// Breaks a set of hands down into the fewest shorthand entries that describe it.
+    /// Hand classes that are only partly in the set are listed as individual combinations.
+    fn compress(set: &TwoCardsSet) -> Vec<String> {
+        let ranks = WeightedTwoCardsSet::RANKS;
+        let has_all = |hands: &[TwoCard]| hands.iter().all(|hand| set.contains(hand));
+        let mut entries: Vec<String> = Vec::new();
+        let mut singles: Vec<TwoCard> = Vec::new();
+
+        let pairs: Vec<bool> = ranks
+            .iter()
+            .map(|rank| has_all(&WeightedTwoCardsSet::pair(*rank)))
+            .collect();
+        for (top, bottom) in WeightedTwoCardsSet::runs(&pairs) {
+            let (t, b) = (
+                WeightedTwoCardsSet::rank_char(ranks[top]),
+                WeightedTwoCardsSet::rank_char(ranks[bottom]),
+            );
+            entries.push(if top == bottom {
+                format!("{t}{t}")
+            } else if top == 0 {
+                format!("{b}{b}+")
+            } else {
+                format!("{t}{t}-{b}{b}")
+            });
+        }
+        for (i, rank) in ranks.iter().enumerate() {
+            if !pairs[i] {
+                singles.append(&mut WeightedTwoCardsSet::pair(*rank));
+            }
+        }
+
+        for (i, high) in ranks.iter().enumerate() {
+            let lows = &ranks[i + 1..];
+            let kinds: Vec<Option<Kind>> = lows
+                .iter()
+                .map(|low| {
+                    let suited = has_all(&WeightedTwoCardsSet::suited(*high, *low));
+                    let offsuit = has_all(&WeightedTwoCardsSet::offsuit(*high, *low));
+                    match (suited, offsuit) {
+                        (true, true) => Some(Kind::Any),
+                        (true, false) => Some(Kind::Suited),
+                        (false, true) => Some(Kind::Offsuit),
+                        (false, false) => None,
+                    }
+                })
+                .collect();
+
+            let h = WeightedTwoCardsSet::rank_char(*high);
+            let mut runs: Vec<(usize, String)> = Vec::new();
+            for kind in [Kind::Any, Kind::Suited, Kind::Offsuit] {
+                let included: Vec<bool> = kinds.iter().map(|k| *k == Some(kind)).collect();
+                let x = kind.suffix();
+                for (top, bottom) in WeightedTwoCardsSet::runs(&included) {
+                    let (t, b) = (
+                        WeightedTwoCardsSet::rank_char(lows[top]),
+                        WeightedTwoCardsSet::rank_char(lows[bottom]),
+                    );
+                    runs.push((
+                        top,
+                        if top == bottom {
+                            format!("{h}{t}{x}")
+                        } else if top == 0 {
+                            format!("{h}{b}{x}+")
+                        } else {
+                            format!("{h}{t}{x}-{h}{b}{x}")
+                        },
+                    ));
+                }
+            }
+            runs.sort_by_key(|(top, _)| *top);
+            entries.extend(runs.into_iter().map(|(_, entry)| entry));
+
+            for (low, kind) in lows.iter().zip(kinds) {
+                if kind != Some(Kind::Any) && kind != Some(Kind::Suited) {
+                    singles.append(&mut WeightedTwoCardsSet::suited(*high, *low));
+                }
+                if kind != Some(Kind::Any) && kind != Some(Kind::Offsuit) {
+                    singles.append(&mut WeightedTwoCardsSet::offsuit(*high, *low));
+                }
+            }
+        }
+
+        for single in singles {
+            if set.contains(&single) {
+                entries.push(format!(
+                    "{}{}{}{}",
+                    single.first().get_rank_char(),
+                    single.first().get_suit_letter().to_ascii_lowercase(),
+                    single.second().get_rank_char(),
+                    single.second().get_suit_letter().to_ascii_lowercase()
+                ));
+            }
+        }
+        entries
+    }
+
+    /// Returns the first and last index of every run of `true` values.
+    fn runs(included: &[bool]) -> Vec<(usize, usize)> {
+        let mut runs = Vec::new();
+        let mut start: Option<usize> = None;
+        for i in 0..=included.len() {
+            match (included.get(i).copied().unwrap_or(false), start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    runs.push((s, i - 1));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        runs
+    }
+
+    fn rank_char(rank: CardRank) -> char {
+        CKCNumber::create(rank, CardSuit::SPADES).get_rank_char()
+    }
+
+    //endregion
+}
+
+impl fmt::Display for WeightedTwoCardsSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_notation())
+    }
+}
+
+impl<'a> IntoIterator for &'a WeightedTwoCardsSet {
+    type Item = (&'a TwoCard, &'a f32);
+    type IntoIter = Iter<'a, TwoCard, f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.hands.iter()
+    }
+}
+
+impl From<&TwoCardsSet> for WeightedTwoCardsSet {
+    fn from(set: &TwoCardsSet) -> Self {
+        WeightedTwoCardsSet {
+            hands: set.iter().map(|hand| (*hand, 1.0)).collect(),
+        }
+    }
+}
+
+/// The kinds of hand classes that an entry can describe.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Pair,
+    Suited,
+    Offsuit,
+    Any,
+}
+
+impl Kind {
+    fn hands(self, high: CardRank, low: CardRank) -> Vec<TwoCard> {
+        match self {
+            Kind::Pair => WeightedTwoCardsSet::pair(high),
+            Kind::Suited => WeightedTwoCardsSet::suited(high, low),
+            Kind::Offsuit => WeightedTwoCardsSet::offsuit(high, low),
+            Kind::Any => {
+                let mut hands = WeightedTwoCardsSet::suited(high, low);
+                hands.append(&mut WeightedTwoCardsSet::offsuit(high, low));
+                hands
+            }
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Kind::Suited => "s",
+            Kind::Offsuit => "o",
+            Kind::Pair | Kind::Any => "",
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_ranges_weighted_two_cards_set_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("AA", 6)]
+    #[case("TT+", 30)]
+    #[case("TT-77", 24)]
+    #[case("77-TT", 24)]
+    #[case("AKs", 4)]
+    #[case("AKo", 12)]
+    #[case("AK", 16)]
+    #[case("KA", 16)]
+    #[case("ATs+", 16)]
+    #[case("A5s-A2s", 16)]
+    #[case("K9o+", 48)]
+    #[case("AhKh", 1)]
+    #[case("A♠K♠", 1)]
+    #[case("22+, A2+", 78 + 192)]
+    #[case("AKs:0.5, AKo:0.25", 16)]
+    #[case("", 0)]
+    fn from_notation(#[case] notation: &str, #[case] expected: usize) {
+        assert_eq!(
+            expected,
+            WeightedTwoCardsSet::from_notation(notation).unwrap().len()
+        );
+    }
+
+    #[rstest]
+    #[case("AKx")]
+    #[case("AAs")]
+    #[case("A")]
+    #[case("AKs-QJs")]
+    #[case("AKs-A5o")]
+    #[case("TT-A5s")]
+    #[case("AKs:1.5")]
+    #[case("AKs:half")]
+    fn from_notation__invalid(#[case] notation: &str) {
+        assert_eq!(
+            HandError::InvalidIndex,
+            WeightedTwoCardsSet::from_notation(notation).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_notation__duplicate_card() {
+        assert_eq!(
+            HandError::DuplicateCard,
+            WeightedTwoCardsSet::from_notation("AhAh").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_notation__weights() {
+        let range = WeightedTwoCardsSet::from_notation("AK:0.5, AKs").unwrap();
+
+        assert_eq!(
+            Some(1.0),
+            range.weight(&TwoCard::try_from("AS KS").unwrap())
+        );
+        assert_eq!(
+            Some(0.5),
+            range.weight(&TwoCard::try_from("AS KH").unwrap())
+        );
+        assert_eq!(None, range.weight(&TwoCard::try_from("AS QH").unwrap()));
+        assert_eq!(10.0, range.combos());
+    }
+
+    #[rstest]
+    #[case("AA")]
+    #[case("TT+")]
+    #[case("JJ-99")]
+    #[case("AKs")]
+    #[case("AK")]
+    #[case("ATs+")]
+    #[case("A5s-A2s")]
+    #[case("AhKh")]
+    #[case("TT+, AKs, A5s-A2s, KQo")]
+    #[case("AK, AQs, AJo")]
+    #[case("QQ+, AKs, AKo:0.5, 22:0.25")]
+    #[case("KK, AsAh")]
+    fn to_notation(#[case] notation: &str) {
+        let range = WeightedTwoCardsSet::from_notation(notation).unwrap();
+
+        assert_eq!(notation, range.to_notation());
+        assert_eq!(
+            range,
+            WeightedTwoCardsSet::from_notation(&range.to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_two_cards_set() {
+        let range = WeightedTwoCardsSet::from_notation("AA, KK:0.5, QQ:0").unwrap();
+
+        assert_eq!(12, range.to_two_cards_set().len());
+    }
+
+    #[test]
+    fn from__two_cards_set() {
+        let range = WeightedTwoCardsSet::from(&TwoCardsSet::every());
+
+        assert_eq!(1326, range.len());
+        assert_eq!(1326.0, range.combos());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::types::poker_cards::PokerCards;
+use crate::types::U32Card;
+use ckc_rs::CardNumber;
+use itertools::{Combinations, Itertools};
+use std::array::IntoIter;
+
+/// Represents the 36 card deck used for
+/// [short deck hold'em](https://en.wikipedia.org/wiki/Six-plus_hold_%27em), which is a
+/// `PokerDeck` with the deuces through fives taken out.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ShortDeck([U32Card; 36]);
+
+pub const SHORT_DECK: ShortDeck = ShortDeck([
+    CardNumber::ACE_SPADES,
+    CardNumber::KING_SPADES,
+    CardNumber::QUEEN_SPADES,
+    CardNumber::JACK_SPADES,
+    CardNumber::TEN_SPADES,
+    CardNumber::NINE_SPADES,
+    CardNumber::EIGHT_SPADES,
+    CardNumber::SEVEN_SPADES,
+    CardNumber::SIX_SPADES,
+    CardNumber::ACE_HEARTS,
+    CardNumber::KING_HEARTS,
+    CardNumber::QUEEN_HEARTS,
+    CardNumber::JACK_HEARTS,
+    CardNumber::TEN_HEARTS,
+    CardNumber::NINE_HEARTS,
+    CardNumber::EIGHT_HEARTS,
+    CardNumber::SEVEN_HEARTS,
+    CardNumber::SIX_HEARTS,
+    CardNumber::ACE_DIAMONDS,
+    CardNumber::KING_DIAMONDS,
+    CardNumber::QUEEN_DIAMONDS,
+    CardNumber::JACK_DIAMONDS,
+    CardNumber::TEN_DIAMONDS,
+    CardNumber::NINE_DIAMONDS,
+    CardNumber::EIGHT_DIAMONDS,
+    CardNumber::SEVEN_DIAMONDS,
+    CardNumber::SIX_DIAMONDS,
+    CardNumber::ACE_CLUBS,
+    CardNumber::KING_CLUBS,
+    CardNumber::QUEEN_CLUBS,
+    CardNumber::JACK_CLUBS,
+    CardNumber::TEN_CLUBS,
+    CardNumber::NINE_CLUBS,
+    CardNumber::EIGHT_CLUBS,
+    CardNumber::SEVEN_CLUBS,
+    CardNumber::SIX_CLUBS,
+]);
+
+impl ShortDeck {
+    #[must_use]
+    pub fn get(index: usize) -> U32Card {
+        SHORT_DECK.0[index]
+    }
+
+    pub fn iter() -> impl Iterator<Item = &'static U32Card> {
+        SHORT_DECK.0.iter()
+    }
+
+    #[must_use]
+    pub fn array_iter() -> IntoIter<U32Card, 36> {
+        SHORT_DECK.0.into_iter()
+    }
+
+    pub fn combinations(&self, k: usize) -> Combinations<IntoIter<U32Card, 36>> {
+        self.0.into_iter().combinations(k)
+    }
+
+    /// Returns true if the card is part of a short deck, so a six or higher.
+    #[must_use]
+    pub fn contains(card: U32Card) -> bool {
+        SHORT_DECK.0.contains(&card)
+    }
+
+    #[must_use]
+    pub fn len() -> usize {
+        SHORT_DECK.0.len()
+    }
+
+    #[must_use]
+    pub fn poker_cards() -> PokerCards {
+        PokerCards::from(SHORT_DECK.0.to_vec())
+    }
+
+    #[must_use]
+    pub fn poker_cards_shuffled() -> PokerCards {
+        let mut cards = ShortDeck::poker_cards();
+        cards.shuffle_in_place();
+        cards
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod short_deck_tests {
+    use super::*;
+    use crate::types::poker_deck::PokerDeck;
+    use ckc_rs::PokerCard;
+
+    #[test]
+    fn combinations() {
+        assert_eq!(630, SHORT_DECK.combinations(2).count());
+        assert_eq!(376_992, SHORT_DECK.combinations(5).count());
+    }
+
+    #[test]
+    fn contains() {
+        assert_eq!(
+            36,
+            PokerDeck::iter()
+                .filter(|card| ShortDeck::contains(**card))
+                .count()
+        );
+        assert!(ShortDeck::contains(CardNumber::SIX_CLUBS));
+        assert!(!ShortDeck::contains(CardNumber::FIVE_CLUBS));
+        assert!(ShortDeck::iter().all(|card| !card.is_blank()));
+    }
+
+    #[test]
+    fn poker_cards() {
+        let cards = ShortDeck::poker_cards();
+
+        for (i, card) in ShortDeck::iter().enumerate() {
+            assert_eq!(cards.get(i).unwrap(), card);
+        }
+        assert_eq!(cards.len(), ShortDeck::len());
+    }
+}
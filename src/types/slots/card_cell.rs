@@ -0,0 +1,157 @@
+use crate::types::playing_card::PlayingCard;
+use ckc_rs::PokerCard;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// A thread safe stand in for a `Cell<PlayingCard>`, holding the card's binary value in an
+/// `AtomicU32`. This is what lets the `CardSlots` keep taking cards through a shared reference
+/// while still being `Sync`, so that a `Table` can be handed to as many threads as want to
+/// analyze it.
+pub struct CardCell(AtomicU32);
+
+impl CardCell {
+    #[must_use]
+    pub fn new(card: PlayingCard) -> CardCell {
+        CardCell(AtomicU32::new(card.as_u32()))
+    }
+
+    #[must_use]
+    pub fn get(&self) -> PlayingCard {
+        PlayingCard::from(self.0.load(Ordering::SeqCst))
+    }
+
+    pub fn set(&self, card: PlayingCard) {
+        self.0.store(card.as_u32(), Ordering::SeqCst);
+    }
+
+    /// Places the card in the cell, but only if it's blank. The check and the swap happen as a
+    /// single step, so if two threads try to take the same empty cell only one of them gets it.
+    pub fn take(&self, card: PlayingCard) -> bool {
+        self.0
+            .compare_exchange(
+                PlayingCard::BLANK.as_u32(),
+                card.as_u32(),
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+    }
+}
+
+impl Clone for CardCell {
+    fn clone(&self) -> Self {
+        CardCell::new(self.get())
+    }
+}
+
+impl fmt::Debug for CardCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CardCell").field(&self.get()).finish()
+    }
+}
+
+impl Default for CardCell {
+    fn default() -> CardCell {
+        CardCell::new(PlayingCard::default())
+    }
+}
+
+impl Eq for CardCell {}
+
+impl PartialEq for CardCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+/// Serializes as the `PlayingCard` itself, just as a `Cell` would.
+impl Serialize for CardCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CardCell {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(CardCell::new(PlayingCard::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_slots_card_cell_tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn new() {
+        let cell = CardCell::new(PlayingCard::ACE_SPADES);
+
+        assert_eq!(PlayingCard::ACE_SPADES, cell.get());
+    }
+
+    #[test]
+    fn set() {
+        let cell = CardCell::default();
+
+        cell.set(PlayingCard::KING_HEARTS);
+
+        assert_eq!(PlayingCard::KING_HEARTS, cell.get());
+    }
+
+    #[test]
+    fn take() {
+        let cell = CardCell::default();
+
+        assert!(cell.take(PlayingCard::ACE_SPADES));
+        assert!(!cell.take(PlayingCard::KING_HEARTS));
+        assert_eq!(PlayingCard::ACE_SPADES, cell.get());
+    }
+
+    #[test]
+    fn take__threads() {
+        let cell = Arc::new(CardCell::default());
+
+        let handles: Vec<thread::JoinHandle<bool>> =
+            [PlayingCard::ACE_SPADES, PlayingCard::KING_HEARTS]
+                .into_iter()
+                .map(|card| {
+                    let cell = Arc::clone(&cell);
+                    thread::spawn(move || cell.take(card))
+                })
+                .collect();
+        let taken: usize = handles
+            .into_iter()
+            .map(|handle| usize::from(handle.join().unwrap()))
+            .sum();
+
+        assert_eq!(1, taken);
+        assert!(!cell.get().is_blank());
+    }
+
+    #[test]
+    fn default() {
+        assert!(CardCell::default().get().is_blank());
+    }
+
+    #[test]
+    fn serde() {
+        let cell = CardCell::new(PlayingCard::ACE_SPADES);
+
+        let json = serde_json::to_string(&cell).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&PlayingCard::ACE_SPADES).unwrap(),
+            json
+        );
+        assert_eq!(cell, serde_json::from_str::<CardCell>(&json).unwrap());
+    }
+}
@@ -4,22 +4,27 @@ use crate::types::arrays::five_card::FiveCard;
 use crate::types::card_slot::CardSlot;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::card_cell::CardCell;
 use crate::types::slots::hole_cards::HoleCards;
+use crate::types::suit_permutation::{SuitIsomorphic, SuitPermutation};
 use crate::types::U32Card;
 use ckc_rs::hand_rank::HandRank;
 use ckc_rs::PokerCard;
 use log::warn;
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
 use std::fmt;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-pub struct Flop(Cell<PlayingCard>, Cell<PlayingCard>, Cell<PlayingCard>);
+pub struct Flop(CardCell, CardCell, CardCell);
 
 impl Flop {
     #[must_use]
     pub fn new(first: PlayingCard, second: PlayingCard, third: PlayingCard) -> Flop {
-        Flop(Cell::new(first), Cell::new(second), Cell::new(third))
+        Flop(
+            CardCell::new(first),
+            CardCell::new(second),
+            CardCell::new(third),
+        )
     }
 
     pub fn get_first_card(&self) -> PlayingCard {
@@ -46,6 +51,17 @@ impl Flop {
         self.2.set(card);
     }
 
+    /// Returns one of each of the 1,755 flops that play differently from each other, along with
+    /// how many of the 22,100 possible flops play the same as it does.
+    #[must_use]
+    pub fn canonical_flops() -> Vec<(Flop, usize)> {
+        Flop::classes(
+            PlayingCards::deck()
+                .combinations(3)
+                .map(|cards| Flop::new(*cards[0], *cards[1], *cards[2])),
+        )
+    }
+
     pub fn to_array(&self) -> [U32Card; 3] {
         [
             self.get_first_card().as_u32(),
@@ -118,19 +134,7 @@ impl Flop {
 
 impl CardSlot for Flop {
     fn take(&self, card: PlayingCard) -> bool {
-        if self.get_first_card().is_blank() {
-            self.take_first_card(card);
-            return true;
-        }
-        if self.get_second_card().is_blank() {
-            self.take_second_card(card);
-            return true;
-        }
-        if self.get_third_card().is_blank() {
-            self.take_third_card(card);
-            return true;
-        }
-        false
+        self.0.take(card) || self.1.take(card) || self.2.take(card)
     }
 
     fn fold(&self) -> PlayingCards {
@@ -156,6 +160,24 @@ impl CardSlot for Flop {
     }
 }
 
+/// The order the cards were dealt in doesn't matter, so `7♦ 2♣ 7♣` is in the same class as
+/// `7♠ 7♥ 2♠`.
+impl SuitIsomorphic for Flop {
+    fn permute(&self, permutation: &SuitPermutation) -> Self {
+        Flop::new(
+            permutation.apply(self.get_first_card()),
+            permutation.apply(self.get_second_card()),
+            permutation.apply(self.get_third_card()),
+        )
+    }
+
+    fn suit_key(&self) -> Vec<U32Card> {
+        let mut key = self.to_array().to_vec();
+        key.sort_unstable_by(|a, b| b.cmp(a));
+        key
+    }
+}
+
 impl Default for Flop {
     fn default() -> Flop {
         Flop::new(
@@ -178,8 +200,8 @@ impl fmt::Display for Flop {
     }
 }
 
-impl From<&'static str> for Flop {
-    fn from(value: &'static str) -> Flop {
+impl From<&str> for Flop {
+    fn from(value: &str) -> Flop {
         let flop = Flop::default();
         let valid = flop.take_from_index(value);
         if !valid {
@@ -220,6 +242,33 @@ mod holdem_flop_tests {
         assert_eq!("A♠ A♦ A♥", format!("{}", flop));
     }
 
+    #[test]
+    fn canonical_flops() {
+        let flops = Flop::canonical_flops();
+
+        assert_eq!(1_755, flops.len());
+        assert_eq!(22_100, flops.iter().map(|(_, count)| count).sum::<usize>());
+        assert_eq!("A♠ A♥ A♦", flops[0].0.to_string());
+        assert_eq!(4, flops[0].1);
+        assert!(flops
+            .iter()
+            .all(|(flop, count)| flop.is_canonical() && flop.multiplicity() == *count));
+    }
+
+    #[test]
+    fn suit_isomorphic() {
+        let flop = Flop::from("7D 2C 7C");
+
+        assert_eq!("7♥ 2♠ 7♠", flop.to_canonical().to_string());
+        assert_eq!(
+            Flop::from("7S 7H 2S").suit_key(),
+            flop.to_canonical().suit_key()
+        );
+        assert_eq!(12, flop.multiplicity());
+        assert_eq!(4, Flop::from("AS KS QS").multiplicity());
+        assert_eq!(24, Flop::from("AS KH QD").multiplicity());
+    }
+
     #[test]
     fn to_array() {
         let slot = Flop::from("AS AD AH");
@@ -2,20 +2,20 @@ use crate::types::arrays::two_card::TwoCard;
 use crate::types::card_slot::CardSlot;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::card_cell::CardCell;
 use crate::types::U32Card;
 use ckc_rs::PokerCard;
 use log::warn;
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
 use std::fmt;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-pub struct HoleCards(Cell<PlayingCard>, Cell<PlayingCard>);
+pub struct HoleCards(CardCell, CardCell);
 
 impl HoleCards {
     #[must_use]
     pub fn new(first: PlayingCard, second: PlayingCard) -> HoleCards {
-        HoleCards(Cell::new(first), Cell::new(second))
+        HoleCards(CardCell::new(first), CardCell::new(second))
     }
 
     /// # Errors
@@ -25,7 +25,7 @@ impl HoleCards {
     /// Will throw a `HandError::InvalidIndex` if the number of cards passed in
     /// doesn't equal 2. (There must be two cards for each `Player`.)
     #[must_use]
-    pub fn from_index(index: &'static str) -> HoleCards {
+    pub fn from_index(index: &str) -> HoleCards {
         HoleCards::from(index)
     }
 
@@ -73,15 +73,7 @@ impl HoleCards {
 
 impl CardSlot for HoleCards {
     fn take(&self, card: PlayingCard) -> bool {
-        if self.get_first_card().is_blank() {
-            self.take_first_card(card);
-            return true;
-        }
-        if self.get_second_card().is_blank() {
-            self.take_second_card(card);
-            return true;
-        }
-        false
+        self.0.take(card) || self.1.take(card)
     }
 
     fn fold(&self) -> PlayingCards {
@@ -115,8 +107,8 @@ impl fmt::Display for HoleCards {
     }
 }
 
-impl From<&'static str> for HoleCards {
-    fn from(value: &'static str) -> HoleCards {
+impl From<&str> for HoleCards {
+    fn from(value: &str) -> HoleCards {
         let hole_cards = HoleCards::default();
         if !hole_cards.take_from_index(value) {
             warn!("Invalid index: {}", value);
@@ -1,4 +1,6 @@
+pub mod card_cell;
 pub mod flop;
 pub mod hole_cards;
 pub mod omaha_hand;
 pub mod single_card;
+pub mod stud_hand;
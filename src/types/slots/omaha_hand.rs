@@ -1,23 +1,56 @@
+use crate::analysis::eval::Eval;
+use crate::analysis::low::LowEval;
 use crate::types::arrays::four_card::FourCard;
+use crate::types::arrays::omaha_five::OmahaFive;
+use crate::types::arrays::omaha_six::OmahaSix;
+use crate::types::arrays::{Omaha, Vectorable};
 use crate::types::card_slot::CardSlot;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::card_cell::CardCell;
 use crate::types::U32Card;
-use ckc_rs::PokerCard;
+use ckc_rs::{HandError, PokerCard};
 use log::warn;
+use serde::de::{Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTupleStruct, Serializer};
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
 use std::fmt;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-pub struct OmahaHand(
-    Cell<PlayingCard>,
-    Cell<PlayingCard>,
-    Cell<PlayingCard>,
-    Cell<PlayingCard>,
-);
+/// The hole cards for a player in one of the Omaha games, which is four cards for Omaha, five
+/// for PLO5 and Big O, and six for PLO6.
+///
+/// **NOTE** It serializes the same way that the original four `Cell` `OmahaHand` did, as a tuple
+/// struct with a field for each card, so that four card hands saved before five and six card
+/// hands were supported still load.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OmahaHand(Vec<CardCell>);
+
+/// The `OmahaHand` as the array type for its size, which is what does the evaluating. It's an
+/// enum, rather than a trait object, so that there's nothing to allocate for every board that's
+/// evaluated.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OmahaHolding {
+    Four(FourCard),
+    Five(OmahaFive),
+    Six(OmahaSix),
+}
+
+impl Omaha for OmahaHolding {}
+
+impl Vectorable for OmahaHolding {
+    fn to_vec(&self) -> Vec<U32Card> {
+        match self {
+            OmahaHolding::Four(four) => four.to_vec(),
+            OmahaHolding::Five(five) => five.to_vec(),
+            OmahaHolding::Six(six) => six.to_vec(),
+        }
+    }
+}
 
 impl OmahaHand {
+    pub const MIN_CARDS: usize = 4;
+    pub const MAX_CARDS: usize = 6;
+
     #[must_use]
     pub fn new(
         first: PlayingCard,
@@ -25,111 +58,134 @@ impl OmahaHand {
         third: PlayingCard,
         forth: PlayingCard,
     ) -> OmahaHand {
-        OmahaHand(
-            Cell::new(first),
-            Cell::new(second),
-            Cell::new(third),
-            Cell::new(forth),
-        )
+        OmahaHand(vec![
+            CardCell::new(first),
+            CardCell::new(second),
+            CardCell::new(third),
+            CardCell::new(forth),
+        ])
+    }
+
+    /// Returns an empty `OmahaHand` with room for the number of cards, which is kept between
+    /// `OmahaHand::MIN_CARDS` and `OmahaHand::MAX_CARDS`.
+    #[must_use]
+    pub fn with_size(size: usize) -> OmahaHand {
+        let size = size.clamp(OmahaHand::MIN_CARDS, OmahaHand::MAX_CARDS);
+        OmahaHand(vec![CardCell::new(PlayingCard::default()); size])
     }
 
+    #[must_use]
     pub fn get_first_card(&self) -> PlayingCard {
-        self.0.get()
+        self.0[0].get()
     }
 
+    #[must_use]
     pub fn get_second_card(&self) -> PlayingCard {
-        self.1.get()
+        self.0[1].get()
     }
 
+    #[must_use]
     pub fn get_third_card(&self) -> PlayingCard {
-        self.2.get()
+        self.0[2].get()
     }
 
+    #[must_use]
     pub fn get_forth_card(&self) -> PlayingCard {
-        self.3.get()
+        self.0[3].get()
     }
 
     pub fn take_first_card(&self, card: PlayingCard) {
-        self.0.set(card);
+        self.0[0].set(card);
     }
 
     pub fn take_second_card(&self, card: PlayingCard) {
-        self.1.set(card);
+        self.0[1].set(card);
     }
 
     pub fn take_third_card(&self, card: PlayingCard) {
-        self.2.set(card);
+        self.0[2].set(card);
     }
 
     pub fn take_forth_card(&self, card: PlayingCard) {
-        self.3.set(card);
+        self.0[3].set(card);
     }
 
-    pub fn to_array(&self) -> [U32Card; 4] {
-        [
-            self.get_first_card().as_u32(),
-            self.get_second_card().as_u32(),
-            self.get_third_card().as_u32(),
-            self.get_forth_card().as_u32(),
-        ]
+    /// Returns the best hand made up of exactly two of the `OmahaHand's` cards and exactly
+    /// three from the board, which can be the flop, turn or river.
+    #[must_use]
+    pub fn eval_with_board(&self, board: &PlayingCards) -> Eval {
+        let board: Vec<U32Card> = board.iter().map(PlayingCard::as_u32).collect();
+        self.holding().best_from_board(&board)
+    }
+
+    /// Returns the best eight or better low made up of exactly two of the `OmahaHand's` cards
+    /// and exactly three from the board, or a default `LowEval` if there isn't one.
+    #[must_use]
+    pub fn eval_low_with_board(&self, board: &PlayingCards) -> LowEval {
+        let board: Vec<U32Card> = board.iter().map(PlayingCard::as_u32).collect();
+        self.holding().best_low_from_board(&board)
+    }
+
+    /// Returns the number of cards the `OmahaHand` holds once it's dealt.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the array type for the size of the hand, so that it can be evaluated.
+    #[must_use]
+    pub fn holding(&self) -> OmahaHolding {
+        match self.size() {
+            5 => OmahaHolding::Five(OmahaFive::try_from(self.to_vec()).unwrap_or_default()),
+            6 => OmahaHolding::Six(OmahaSix::try_from(self.to_vec()).unwrap_or_default()),
+            _ => OmahaHolding::Four(FourCard::try_from(self.to_vec()).unwrap_or_default()),
+        }
+    }
+
+    /// Returns the cards of a regular four card Omaha hand.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `HandError::TooManyCards` for five and six card hands, which need `holding()`.
+    pub fn to_array(&self) -> Result<[U32Card; 4], HandError> {
+        Ok(FourCard::try_from(self.to_vec())?.to_arr())
+    }
+
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<U32Card> {
+        self.0.iter().map(|card| card.get().as_u32()).collect()
     }
 }
 
 impl CardSlot for OmahaHand {
     fn take(&self, card: PlayingCard) -> bool {
-        if self.get_first_card().is_blank() {
-            self.take_first_card(card);
-            return true;
-        }
-        if self.get_second_card().is_blank() {
-            self.take_second_card(card);
-            return true;
-        }
-        if self.get_third_card().is_blank() {
-            self.take_third_card(card);
-            return true;
-        }
-        if self.get_forth_card().is_blank() {
-            self.take_forth_card(card);
-            return true;
-        }
-        false
+        self.0.iter().any(|slot| slot.take(card))
     }
 
     fn fold(&self) -> PlayingCards {
         let folded = self.to_playing_cards();
-        self.0.set(PlayingCard::default());
-        self.1.set(PlayingCard::default());
-        self.2.set(PlayingCard::default());
-        self.3.set(PlayingCard::default());
+        for slot in &self.0 {
+            slot.set(PlayingCard::default());
+        }
         folded
     }
 
     fn is_dealt(&self) -> bool {
-        !self.get_first_card().is_blank()
-            && !self.get_second_card().is_blank()
-            && !self.get_third_card().is_blank()
-            && !self.get_forth_card().is_blank()
+        self.0.iter().all(|slot| !slot.get().is_blank())
     }
 
     fn to_playing_cards(&self) -> PlayingCards {
         let mut playing_cards = PlayingCards::default();
-        playing_cards.insert(self.0.get());
-        playing_cards.insert(self.1.get());
-        playing_cards.insert(self.2.get());
-        playing_cards.insert(self.3.get());
+        for slot in &self.0 {
+            playing_cards.insert(slot.get());
+        }
         playing_cards
     }
 }
 
 impl Default for OmahaHand {
     fn default() -> OmahaHand {
-        OmahaHand::new(
-            PlayingCard::default(),
-            PlayingCard::default(),
-            PlayingCard::default(),
-            PlayingCard::default(),
-        )
+        OmahaHand::with_size(OmahaHand::MIN_CARDS)
     }
 }
 
@@ -156,9 +212,81 @@ impl From<FourCard> for OmahaHand {
     }
 }
 
-impl From<&'static str> for OmahaHand {
-    fn from(value: &'static str) -> OmahaHand {
-        let omaha = OmahaHand::default();
+impl From<OmahaFive> for OmahaHand {
+    fn from(five: OmahaFive) -> Self {
+        let hand = OmahaHand::with_size(5);
+        hand.take_from_playing_cards(&five.to_playing_cards());
+        hand
+    }
+}
+
+impl From<OmahaSix> for OmahaHand {
+    fn from(six: OmahaSix) -> Self {
+        let hand = OmahaHand::with_size(6);
+        hand.take_from_playing_cards(&six.to_playing_cards());
+        hand
+    }
+}
+
+impl Serialize for OmahaHand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple_struct("OmahaHand", self.size())?;
+        for cell in &self.0 {
+            state.serialize_field(cell)?;
+        }
+        state.end()
+    }
+}
+
+/// Takes however many cards were serialized, as long as it's a valid size for an `OmahaHand`.
+impl<'de> Deserialize<'de> for OmahaHand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OmahaHandVisitor;
+
+        impl<'de> Visitor<'de> for OmahaHandVisitor {
+            type Value = OmahaHand;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "between {} and {} cards",
+                    OmahaHand::MIN_CARDS,
+                    OmahaHand::MAX_CARDS
+                )
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<OmahaHand, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut cells = Vec::new();
+                while let Some(cell) = seq.next_element::<CardCell>()? {
+                    cells.push(cell);
+                }
+                if (OmahaHand::MIN_CARDS..=OmahaHand::MAX_CARDS).contains(&cells.len()) {
+                    Ok(OmahaHand(cells))
+                } else {
+                    Err(serde::de::Error::invalid_length(cells.len(), &self))
+                }
+            }
+        }
+
+        deserializer.deserialize_seq(OmahaHandVisitor)
+    }
+}
+
+/// The size of the `OmahaHand` goes by the number of cards in the index, so that it works for
+/// PLO5 and PLO6 as well as regular Omaha.
+impl From<&str> for OmahaHand {
+    fn from(value: &str) -> OmahaHand {
+        let size = PlayingCards::try_from(value).map_or(OmahaHand::MIN_CARDS, |cards| cards.len());
+        let omaha = OmahaHand::with_size(size);
         if !omaha.take_from_index(value) {
             warn!("Invalid index: {}", value);
         }
@@ -170,6 +298,7 @@ impl From<&'static str> for OmahaHand {
 #[allow(non_snake_case)]
 mod types_slots_omaha_hand_tests {
     use super::*;
+    use serde_test::{assert_tokens, Token};
 
     #[test]
     fn from__four_cards() {
@@ -179,4 +308,126 @@ mod types_slots_omaha_hand_tests {
 
         assert_eq!(four.to_string(), omaha.to_string());
     }
+
+    #[test]
+    fn holding() {
+        assert_eq!(
+            OmahaHolding::Four(FourCard::try_from("A♠ K♦ 4♣ 2♠").unwrap()),
+            OmahaHand::from("A♠ K♦ 4♣ 2♠").holding()
+        );
+        assert_eq!(
+            OmahaHolding::Five(OmahaFive::try_from("A♠ K♦ 4♣ 2♠ 3♥").unwrap()),
+            OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥").holding()
+        );
+        assert_eq!(
+            15,
+            OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥ 7♦").holding().pairs().len()
+        );
+    }
+
+    #[test]
+    fn to_array() {
+        assert_eq!(
+            FourCard::try_from("A♠ K♦ 4♣ 2♠").unwrap().to_arr(),
+            OmahaHand::from("A♠ K♦ 4♣ 2♠").to_array().unwrap()
+        );
+        assert_eq!(
+            HandError::TooManyCards,
+            OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥").to_array().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        assert_tokens(
+            &OmahaHand::from("A♠ K♦ 4♣ 2♠"),
+            &[
+                Token::TupleStruct {
+                    name: "OmahaHand",
+                    len: 4,
+                },
+                Token::NewtypeStruct {
+                    name: "PlayingCard",
+                },
+                Token::Str("A♠"),
+                Token::NewtypeStruct {
+                    name: "PlayingCard",
+                },
+                Token::Str("K♦"),
+                Token::NewtypeStruct {
+                    name: "PlayingCard",
+                },
+                Token::Str("4♣"),
+                Token::NewtypeStruct {
+                    name: "PlayingCard",
+                },
+                Token::Str("2♠"),
+                Token::TupleStructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serialize__six_cards() {
+        let omaha = OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥ 7♦");
+
+        let json = serde_json::to_string(&omaha).unwrap();
+
+        assert_eq!(r#"["A♠","K♦","4♣","2♠","3♥","7♦"]"#, json);
+        assert_eq!(omaha, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<OmahaHand>(r#"["A♠","K♦","4♣"]"#).is_err());
+    }
+
+    #[test]
+    fn from__index_size() {
+        assert_eq!(4, OmahaHand::from("A♠ K♦ 4♣ 2♠").size());
+        assert_eq!(5, OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥").size());
+        assert_eq!(6, OmahaHand::from("A♠ K♦ 4♣ 2♠ 3♥ 7♦").size());
+        assert_eq!(4, OmahaHand::from("A♠ K♦ 4X").size());
+    }
+
+    #[test]
+    fn take__with_size() {
+        let omaha = OmahaHand::with_size(5);
+
+        assert!(omaha.take_from_index("A♠ K♦ 4♣ 2♠"));
+        assert!(!omaha.is_dealt());
+        assert!(omaha.take(PlayingCard::from("3♥")));
+        assert!(omaha.is_dealt());
+        assert!(!omaha.take(PlayingCard::from("7♦")));
+        assert_eq!(6, OmahaHand::with_size(9).size());
+    }
+
+    #[test]
+    fn eval_with_board__six_cards() {
+        let omaha = OmahaHand::from("2♣ 3♦ 7♥ 8♦ A♠ K♠");
+
+        assert_eq!(
+            "A♠ K♠ Q♠ J♠ T♠",
+            omaha
+                .eval_with_board(&PlayingCards::try_from("T♠ J♠ Q♠").unwrap())
+                .hand
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn eval_with_board() {
+        let omaha = OmahaHand::from("A♠ A♥ 7♦ 2♣");
+
+        assert_eq!(
+            "A♠ A♥ K♠ Q♠ J♠",
+            omaha
+                .eval_with_board(&PlayingCards::try_from("K♠ Q♠ J♠").unwrap())
+                .hand
+                .to_string()
+        );
+        assert_eq!(
+            "A♠ A♥ K♠ Q♠ J♠",
+            omaha
+                .eval_with_board(&PlayingCards::try_from("K♠ Q♠ J♠ T♠ 9♠").unwrap())
+                .hand
+                .to_string()
+        );
+    }
 }
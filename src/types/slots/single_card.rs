@@ -1,14 +1,14 @@
 use crate::types::card_slot::CardSlot;
 use crate::types::playing_card::PlayingCard;
 use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::card_cell::CardCell;
 use ckc_rs::PokerCard;
 use log::warn;
 use serde::{Deserialize, Serialize};
-use std::cell::Cell;
 use std::fmt;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-pub struct SingleCard(Cell<PlayingCard>);
+pub struct SingleCard(CardCell);
 
 impl SingleCard {
     #[must_use]
@@ -25,11 +25,7 @@ impl SingleCard {
 
 impl CardSlot for SingleCard {
     fn take(&self, card: PlayingCard) -> bool {
-        if self.get().is_blank() {
-            self.0.set(card);
-            return true;
-        }
-        false
+        self.0.take(card)
     }
 
     fn fold(&self) -> PlayingCards {
@@ -51,7 +47,7 @@ impl CardSlot for SingleCard {
 
 impl Default for SingleCard {
     fn default() -> SingleCard {
-        SingleCard(Cell::new(PlayingCard::default()))
+        SingleCard(CardCell::new(PlayingCard::default()))
     }
 }
 
@@ -61,8 +57,8 @@ impl fmt::Display for SingleCard {
     }
 }
 
-impl From<&'static str> for SingleCard {
-    fn from(value: &'static str) -> SingleCard {
+impl From<&str> for SingleCard {
+    fn from(value: &str) -> SingleCard {
         let slot = SingleCard::default();
         let valid = slot.take_from_index(value);
         if !valid {
@@ -0,0 +1,178 @@
+use crate::types::card_slot::CardSlot;
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::slots::card_cell::CardCell;
+use ckc_rs::PokerCard;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The seven cards of a [stud](https://en.wikipedia.org/wiki/Seven-card_stud) hand, in the order
+/// they're dealt: two down cards and the door card on third street, an up card on each of
+/// fourth, fifth and sixth streets, and the last card down on seventh street.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct StudHand([CardCell; 7]);
+
+impl StudHand {
+    /// The slots that are dealt face down.
+    pub const DOWN: [usize; 3] = [0, 1, 6];
+    pub const DOOR: usize = 2;
+
+    #[must_use]
+    pub fn get(&self, slot: usize) -> PlayingCard {
+        match self.0.get(slot) {
+            Some(card) => card.get(),
+            None => PlayingCard::default(),
+        }
+    }
+
+    /// Returns the door card, which is the first up card, dealt on third street.
+    #[must_use]
+    pub fn door_card(&self) -> PlayingCard {
+        self.get(StudHand::DOOR)
+    }
+
+    #[must_use]
+    pub fn is_up(slot: usize) -> bool {
+        slot < 7 && !StudHand::DOWN.contains(&slot)
+    }
+
+    /// Returns the cards that have been dealt face down.
+    #[must_use]
+    pub fn down_cards(&self) -> PlayingCards {
+        self.cards_where(|slot| !StudHand::is_up(slot))
+    }
+
+    /// Returns the cards that have been dealt face up, and that everyone at the table can see.
+    #[must_use]
+    pub fn up_cards(&self) -> PlayingCards {
+        self.cards_where(StudHand::is_up)
+    }
+
+    //region private functions
+
+    fn cards_where(&self, f: fn(usize) -> bool) -> PlayingCards {
+        let mut cards = PlayingCards::default();
+        for (slot, card) in self.0.iter().enumerate() {
+            if f(slot) && !card.get().is_blank() {
+                cards.insert(card.get());
+            }
+        }
+        cards
+    }
+
+    //endregion
+}
+
+impl CardSlot for StudHand {
+    fn take(&self, card: PlayingCard) -> bool {
+        self.0.iter().any(|slot| slot.take(card))
+    }
+
+    fn fold(&self) -> PlayingCards {
+        let folded = self.to_playing_cards();
+        for slot in &self.0 {
+            slot.set(PlayingCard::default());
+        }
+        folded
+    }
+
+    fn is_dealt(&self) -> bool {
+        self.0.iter().all(|slot| !slot.get().is_blank())
+    }
+
+    fn to_playing_cards(&self) -> PlayingCards {
+        self.cards_where(|_| true)
+    }
+}
+
+/// Down cards are shown in brackets, so `[A♠ 2♥] 3♦ 4♣ 5♠ 6♥ [7♦]`.
+impl fmt::Display for StudHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: Vec<String> = self
+            .0
+            .iter()
+            .map(CardCell::get)
+            .take_while(|card| !card.is_blank())
+            .map(|card| card.to_string())
+            .collect();
+
+        let mut parts: Vec<String> = Vec::new();
+        if !cards.is_empty() {
+            parts.push(format!("[{}]", cards[..cards.len().min(2)].join(" ")));
+        }
+        parts.extend(cards.iter().skip(2).take(4).cloned());
+        if let Some(last) = cards.get(6) {
+            parts.push(format!("[{last}]"));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+impl From<&str> for StudHand {
+    fn from(value: &str) -> StudHand {
+        let hand = StudHand::default();
+        if !hand.take_from_index(value) {
+            warn!("Invalid index: {value}");
+        }
+        hand
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_slots_stud_hand_tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let hand = StudHand::default();
+
+        for card in PlayingCards::try_from("A♠ 2♥ 3♦ 4♣ 5♠ 6♥ 7♦")
+            .unwrap()
+            .iter()
+        {
+            assert!(hand.take(*card));
+        }
+
+        assert!(hand.is_dealt());
+        assert!(!hand.take(PlayingCard::from("8♣")));
+    }
+
+    #[test]
+    fn up_and_down_cards() {
+        let hand = StudHand::from("A♠ 2♥ 3♦ 4♣ 5♠ 6♥ 7♦");
+
+        assert_eq!("3♦", hand.door_card().to_string());
+        assert_eq!("A♠ 2♥ 7♦", hand.down_cards().to_string());
+        assert_eq!("3♦ 4♣ 5♠ 6♥", hand.up_cards().to_string());
+    }
+
+    #[test]
+    fn up_and_down_cards__fourth_street() {
+        let hand = StudHand::from("A♠ 2♥ 3♦ 4♣");
+
+        assert_eq!("A♠ 2♥", hand.down_cards().to_string());
+        assert_eq!("3♦ 4♣", hand.up_cards().to_string());
+        assert_eq!(4, hand.len());
+    }
+
+    #[test]
+    fn fold() {
+        let hand = StudHand::from("A♠ 2♥ 3♦");
+
+        assert_eq!("A♠ 2♥ 3♦", hand.fold().to_string());
+        assert!(hand.is_blank());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "[A♠ 2♥] 3♦ 4♣ 5♠ 6♥ [7♦]",
+            StudHand::from("A♠ 2♥ 3♦ 4♣ 5♠ 6♥ 7♦").to_string()
+        );
+        assert_eq!("[A♠ 2♥] 3♦", StudHand::from("A♠ 2♥ 3♦").to_string());
+        assert_eq!("[A♠]", StudHand::from("A♠").to_string());
+        assert_eq!("", StudHand::default().to_string());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::types::playing_card::PlayingCard;
+use crate::types::playing_cards::PlayingCards;
+use crate::types::U32Card;
+use ckc_rs::{CardNumber, PokerCard};
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashSet};
+
+/// One of the 24 ways of relabelling the four suits. Since no suit is better than any other,
+/// cards that are the same once the suits are relabelled play exactly the same, so `A♠ A♥`
+/// against `7♦ 7♣` has the same odds as `A♦ A♣` against `7♠ 7♥`.
+///
+/// The suits are numbered by the position of their bit in the card, from clubs at 0 up to
+/// spades at 3, and each one maps to the suit that it becomes.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SuitPermutation([u32; 4]);
+
+impl SuitPermutation {
+    pub const IDENTITY: SuitPermutation = SuitPermutation([0, 1, 2, 3]);
+
+    /// Returns every way of relabelling the suits, starting with the `IDENTITY`.
+    #[must_use]
+    pub fn all() -> Vec<SuitPermutation> {
+        (0..4)
+            .permutations(4)
+            .map(|suits| SuitPermutation([suits[0], suits[1], suits[2], suits[3]]))
+            .collect()
+    }
+
+    /// Returns the card with its suit relabelled. Blank cards stay blank.
+    #[must_use]
+    pub fn apply(&self, card: PlayingCard) -> PlayingCard {
+        if card.is_blank() {
+            return card;
+        }
+        let suit = card.get_suit_bit().trailing_zeros() as usize;
+        PlayingCard::from(
+            (card.as_u32() & !CardNumber::SUIT_FILTER)
+                | (1 << (self.0[suit] + CardNumber::SUIT_SHIFT)),
+        )
+    }
+
+    #[must_use]
+    pub fn apply_to_cards(&self, cards: &PlayingCards) -> PlayingCards {
+        PlayingCards::from(
+            cards
+                .iter()
+                .map(|card| self.apply(*card))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl Default for SuitPermutation {
+    fn default() -> SuitPermutation {
+        SuitPermutation::IDENTITY
+    }
+}
+
+/// Anything made up of cards that plays the same no matter how its suits are relabelled, so
+/// that it can be swapped for the one representative of its class, such as when working out
+/// the odds of `A♠ A♥` against `7♦ 7♣` once for all twelve of its variations.
+pub trait SuitIsomorphic: Sized {
+    /// Returns a copy with the suits of every one of the cards relabelled.
+    #[must_use]
+    fn permute(&self, permutation: &SuitPermutation) -> Self;
+
+    /// The cards in an order where any two that play the same are equal, such as a flop with
+    /// its cards sorted. The representative of a class is the one with the largest key.
+    fn suit_key(&self) -> Vec<U32Card>;
+
+    /// Returns the relabelling of the suits that turns this into the representative of its
+    /// class, favoring the `IDENTITY` if it already is one.
+    fn canonical_permutation(&self) -> SuitPermutation {
+        let mut best = (SuitPermutation::IDENTITY, self.suit_key());
+        for permutation in SuitPermutation::all() {
+            let key = self.permute(&permutation).suit_key();
+            if key > best.1 {
+                best = (permutation, key);
+            }
+        }
+        best.0
+    }
+
+    #[must_use]
+    fn to_canonical(&self) -> Self {
+        self.permute(&self.canonical_permutation())
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.canonical_permutation() == SuitPermutation::IDENTITY
+    }
+
+    /// How many distinct variations, including this one, there are of its class, which is 24
+    /// at the most. `A♠ A♥` has 6, `A♠ K♠` 4 and `A♠ K♥` 12.
+    fn multiplicity(&self) -> usize {
+        SuitPermutation::all()
+            .iter()
+            .map(|permutation| self.permute(permutation).suit_key())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Boils the items down to the representative of each of their classes along with how many
+    /// of the items were in it, from the largest representative to the smallest. Passing in
+    /// every one of something, such as all 1,326 hole cards, returns each class with its
+    /// multiplicity.
+    fn classes<I: IntoIterator<Item = Self>>(items: I) -> Vec<(Self, usize)> {
+        let mut classes: BTreeMap<Vec<U32Card>, (Self, usize)> = BTreeMap::new();
+        for item in items {
+            let canonical = item.to_canonical();
+            classes
+                .entry(canonical.suit_key())
+                .or_insert((canonical, 0))
+                .1 += 1;
+        }
+        classes.into_values().rev().collect()
+    }
+}
+
+/// The cards as a set, so that their order doesn't matter.
+impl SuitIsomorphic for PlayingCards {
+    fn permute(&self, permutation: &SuitPermutation) -> Self {
+        permutation.apply_to_cards(self)
+    }
+
+    fn suit_key(&self) -> Vec<U32Card> {
+        let mut key: Vec<U32Card> = self.iter().map(PokerCard::as_u32).collect();
+        key.sort_unstable_by(|a, b| b.cmp(a));
+        key
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types_suit_permutation_tests {
+    use super::*;
+
+    #[test]
+    fn all() {
+        let all = SuitPermutation::all();
+
+        assert_eq!(24, all.len());
+        assert_eq!(SuitPermutation::IDENTITY, all[0]);
+        assert_eq!(24, all.iter().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn apply() {
+        // Clubs to spades, and spades to clubs.
+        let swap = SuitPermutation([3, 1, 2, 0]);
+
+        assert_eq!(PlayingCard::from("A♣"), swap.apply(PlayingCard::from("A♠")));
+        assert_eq!(PlayingCard::from("7♠"), swap.apply(PlayingCard::from("7♣")));
+        assert_eq!(PlayingCard::from("K♥"), swap.apply(PlayingCard::from("K♥")));
+        assert_eq!(PlayingCard::BLANK, swap.apply(PlayingCard::BLANK));
+    }
+
+    #[test]
+    fn suit_isomorphic__playing_cards() {
+        let cards = PlayingCards::try_from("A♠ K♥ Q♦").unwrap();
+
+        let canonical = cards.to_canonical();
+
+        assert_eq!("A♠ K♥ Q♦", canonical.to_string());
+        assert!(cards.is_canonical());
+        assert_eq!(24, cards.multiplicity());
+        assert_eq!(
+            canonical,
+            PlayingCards::try_from("A♣ K♦ Q♥").unwrap().to_canonical()
+        );
+        assert_eq!(
+            4,
+            PlayingCards::try_from("A♣ K♣ Q♣").unwrap().multiplicity()
+        );
+    }
+
+    #[test]
+    fn apply_to_cards() {
+        let cards = PlayingCards::try_from("A♠ K♥ Q♦ J♣").unwrap();
+
+        for permutation in SuitPermutation::all() {
+            let permuted = permutation.apply_to_cards(&cards);
+            assert_eq!(4, permuted.len());
+            assert_eq!(
+                cards.to_string().replace(['♠', '♥', '♦', '♣'], ""),
+                permuted.to_string().replace(['♠', '♥', '♦', '♣'], "")
+            );
+        }
+    }
+}
@@ -2,6 +2,10 @@ pub mod random_ordering;
 pub mod uci;
 
 /// How to convert a String into a static str.
+///
+/// This leaks the String, and is no longer needed now that card indexes can be parsed from
+/// any `&str` with `FromStr` or `TryFrom`.
+#[deprecated(note = "leaks memory; parse the index with `FromStr` or `TryFrom<&str>` instead")]
 #[must_use]
 pub fn str_from_string(value: String) -> &'static str {
     Box::leak(value.into_boxed_str())